@@ -1,37 +1,124 @@
-use crate::config::preferences::{load_preferences, save_preferences};
+pub mod network;
+
+use crate::compose::detect::{self, ComposeInfo};
+use crate::compose::restart;
+use crate::config::audit::{self, AuditEntry};
+use crate::config::preferences::{
+    apply_runtime_selection, auto_select_runtime, load_preferences, save_preferences,
+};
+use crate::config::schema::preferences_schema;
+use crate::container::batch::{self, BatchResult};
+use crate::container::exec::{self, ExecOptions, ExecResult};
+use crate::container::follow;
+use crate::container::inspect::{self, ContainerLogConfig, ResourceLimits};
+use crate::container::lifecycle;
+use crate::container::list::{self, ContainerBrief, HealthSummary, LabelSelector};
+use crate::container::logs::{self, LogExport, LogOptions};
+use crate::container::ports::{self, PortBinding};
+use crate::container::run::{self, RunOptions};
+use crate::container::stats::{self, ContainerStats, DEFAULT_STATS_INTERVAL, STATS_HISTORY};
+use crate::image::build::{self, BuildLineHandler, BuildOptions, BuildProgress};
+use crate::image::history::{self, HistoryLayer};
+use crate::image::import;
+use crate::image::prune::{self, PruneImagesOptions, PruneImagesResult};
+use crate::image::push::{self, PushOptions};
+use crate::image::reference::{self, ImageReference};
+use crate::image::tag;
+use crate::platform::info::platform_info;
 use crate::polling::PollingService;
+use crate::process::cancel::CancellationToken;
+use crate::process::tracker::PROCESS_TRACKER;
+use crate::runtime::buildkit;
+use crate::runtime::capabilities::{
+    self, probe_capabilities, refresh_capabilities, CapabilityCache,
+};
 use crate::runtime::detector::RuntimeDetector;
-use crate::types::{DetectionResult, RuntimePreferences};
-use std::sync::Arc;
+use crate::runtime::diagnostics::{self, RuntimeDiagnostics};
+use crate::runtime::events;
+use crate::runtime::info;
+use crate::runtime::inspect::{self as raw_inspect, ResourceKind};
+use crate::runtime::system::{self, DiskUsage};
+use crate::types::{
+    DetectionResult, Feature, PlatformInfo, Runtime, RuntimePreferences, RuntimeType,
+};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tauri::{AppHandle, Emitter, Window};
 
 // Global detector instance
 lazy_static::lazy_static! {
-    static ref DETECTOR: Arc<RuntimeDetector> = Arc::new(RuntimeDetector::new(60, 500));
-    static ref POLLING_SERVICE: Arc<PollingService> = Arc::new(PollingService::new(5));
+    static ref DETECTOR: Arc<RuntimeDetector> = Arc::new({
+        let prefs = load_preferences().unwrap_or_default();
+        RuntimeDetector::from_preferences(prefs.detection_cache_ttl, &prefs)
+    });
+    static ref POLLING_SERVICE: Arc<PollingService> = Arc::new({
+        let prefs = load_preferences().unwrap_or_default();
+        PollingService::new(5).with_crash_loop_config(
+            prefs.crash_loop_threshold,
+            Duration::from_secs(prefs.crash_loop_window),
+        )
+    });
+    static ref CAPABILITIES: Arc<CapabilityCache> = Arc::new(CapabilityCache::new());
+    static ref DETECTION_TOKEN: Mutex<Option<CancellationToken>> = Mutex::new(None);
+    static ref STATS_STREAMS: Mutex<HashMap<String, CancellationToken>> = Mutex::new(HashMap::new());
+    static ref BUILDS: Mutex<HashMap<String, CancellationToken>> = Mutex::new(HashMap::new());
 }
 
 // Initialize detector (called from main.rs)
 pub fn init_detector() {
     // Force initialization of lazy_static
-    let _detector = &*DETECTOR;
+    let detector = &*DETECTOR;
+
+    // Restore the previous session's detection results, if still fresh
+    if load_preferences()
+        .unwrap_or_default()
+        .persist_detection_cache
+    {
+        if let Err(e) = detector.cache().load_from_disk() {
+            eprintln!("Failed to load detection cache: {}", e);
+        }
+    }
 }
 
-#[tauri::command]
-pub async fn save_window_size(_window: Window, _width: f64, _height: f64) -> Result<(), String> {
-    let prefs = load_preferences().map_err(|e| e.to_string())?;
+/// Saves the detection cache when `persist_detection_cache` is enabled
+///
+/// Skipped when nothing was probed or cleared since the last save.
+fn persist_detection_cache() {
+    if !DETECTOR.cache().has_unsaved_changes() {
+        return;
+    }
+    if !load_preferences()
+        .unwrap_or_default()
+        .persist_detection_cache
+    {
+        return;
+    }
+    if let Err(e) = DETECTOR.cache().persist_to_disk() {
+        eprintln!("Failed to persist detection cache: {}", e);
+    }
+}
 
-    // Store window dimensions (we'll add these fields to RuntimePreferences later)
-    // For now, just save the preferences to ensure the mechanism works
-    save_preferences(&prefs).map_err(|e| e.to_string())?;
+/// Runs `detect_all` and saves whatever it added to the cache
+async fn detect_all_runtimes() -> DetectionResult {
+    let result = DETECTOR.detect_all().await;
+    persist_detection_cache();
+    result
+}
 
-    Ok(())
+#[tauri::command]
+pub async fn save_window_size(_window: Window, width: f64, height: f64) -> Result<(), String> {
+    let mut prefs = load_preferences().map_err(|e| e.to_string())?;
+    prefs.window_width = width;
+    prefs.window_height = height;
+    save_preferences(&prefs).map_err(|e| e.to_string())
 }
 
 #[tauri::command]
 pub async fn get_window_size() -> Result<(f64, f64), String> {
-    // Default window size from tauri.conf.json
-    Ok((1200.0, 800.0))
+    // Falls back to the tauri.conf.json size when nothing was saved yet
+    let prefs = load_preferences().map_err(|e| e.to_string())?;
+    Ok((prefs.window_width, prefs.window_height))
 }
 
 #[tauri::command]
@@ -40,24 +127,67 @@ pub async fn detect_runtimes(app: AppHandle) -> Result<DetectionResult, String>
     app.emit("detection-started", ())
         .map_err(|e| e.to_string())?;
 
+    // Register a fresh cancellation token for this detection
+    let token = CancellationToken::new();
+    if let Ok(mut current) = DETECTION_TOKEN.lock() {
+        *current = Some(token.clone());
+    }
+
     // Run detection
-    let all_runtimes = DETECTOR.detect_all().await;
-
-    // Create detection result
-    let result = DetectionResult {
-        runtimes: all_runtimes,
-        detected_at: chrono::Utc::now(),
-        duration: 0, // Combined duration handled by detector
-        errors: vec![],
+    let detected = DETECTOR.detect_all_cancellable(&token).await;
+
+    if let Ok(mut current) = DETECTION_TOKEN.lock() {
+        // Leave the token of an overlapping, newer detection in place
+        if current.as_ref().is_some_and(|t| t.same_token(&token)) {
+            *current = None;
+        }
+    }
+
+    let Some(result) = detected else {
+        app.emit("detection-cancelled", ())
+            .map_err(|e| e.to_string())?;
+        return Err("Detection cancelled".to_string());
     };
+    persist_detection_cache();
 
-    // Emit detection completed event with runtimes
+    // Emit detection completed event with runtimes and any probe errors
     app.emit("detection-completed", &result)
         .map_err(|e| e.to_string())?;
 
     Ok(result)
 }
 
+#[tauri::command]
+pub async fn cancel_detection() -> Result<bool, String> {
+    let token = DETECTION_TOKEN.lock().map_err(|e| e.to_string())?.take();
+
+    match token {
+        Some(token) => {
+            token.cancel();
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// Stops detection and every tracked child process in one go
+///
+/// Emits `operation-cancelled` with the tracking handle of each killed
+/// process; a running detection reports `detection-cancelled` itself.
+/// Returns the number of operations that were cancelled.
+#[tauri::command]
+pub async fn cancel_all_operations(app: AppHandle) -> Result<usize, String> {
+    let detection_cancelled = cancel_detection().await?;
+    let handles = PROCESS_TRACKER.cancel_all();
+
+    for handle in &handles {
+        app.emit("operation-cancelled", handle)
+            .map_err(|e| e.to_string())?;
+    }
+
+    Ok(handles.len() + usize::from(detection_cancelled))
+}
+
 #[tauri::command]
 pub async fn get_runtime_preferences() -> Result<RuntimePreferences, String> {
     load_preferences().map_err(|e| e.to_string())
@@ -65,32 +195,100 @@ pub async fn get_runtime_preferences() -> Result<RuntimePreferences, String> {
 
 #[tauri::command]
 pub async fn set_runtime_preferences(prefs: RuntimePreferences) -> Result<(), String> {
+    save_preferences(&prefs).map_err(|e| e.to_string())?;
+
+    // Results cached under the old minimums carry stale version warnings
+    if DETECTOR.update_minimums(&prefs) {
+        persist_detection_cache();
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_preferred_type(runtime_type: RuntimeType) -> Result<(), String> {
+    let mut prefs = load_preferences().map_err(|e| e.to_string())?;
+    prefs.preferred_type = Some(runtime_type);
     save_preferences(&prefs).map_err(|e| e.to_string())
 }
 
+/// Returns the runtime auto-selection would pick, honoring `preferred_type`
+#[tauri::command]
+pub async fn get_auto_selected_runtime() -> Result<Option<Runtime>, String> {
+    let runtimes = detect_all_runtimes().await.runtimes;
+    let prefs = load_preferences().map_err(|e| e.to_string())?;
+    Ok(auto_select_runtime(&prefs, &runtimes).cloned())
+}
+
+#[tauri::command]
+pub fn get_preferences_schema() -> String {
+    preferences_schema()
+}
+
+/// Most recent audit log entries, newest first (100 unless `limit` is given)
+#[tauri::command]
+pub fn get_audit_log(limit: Option<usize>) -> Result<Vec<AuditEntry>, String> {
+    let path = audit::get_audit_log_path().map_err(|e| e.to_string())?;
+    audit::read_entries(&path, limit.unwrap_or(100)).map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn select_runtime(app: AppHandle, runtime_id: String) -> Result<(), String> {
+    // Only persist IDs of runtimes the detector actually knows about
+    let runtimes = detect_all_runtimes().await.runtimes;
     let mut prefs = load_preferences().map_err(|e| e.to_string())?;
-    prefs.selected_runtime_id = Some(runtime_id.clone());
+    apply_runtime_selection(&mut prefs, &runtimes, &runtime_id)?;
     save_preferences(&prefs).map_err(|e| e.to_string())?;
 
     // Emit runtime selected event
-    app.emit("runtime-selected", runtime_id)
+    app.emit("runtime-selected", runtime_id.clone())
         .map_err(|e| e.to_string())?;
 
+    // Re-probe capabilities for the newly selected runtime (cached per runtime ID)
+    if let Some(runtime) = runtimes.iter().find(|r| r.id == runtime_id) {
+        refresh_capabilities(&CAPABILITIES, runtime, probe_capabilities, |caps| {
+            app.emit("runtime-capabilities", caps)
+                .map_err(|e| e.to_string())
+        })
+        .await?;
+    }
+
     Ok(())
 }
 
 #[tauri::command]
 pub async fn clear_detection_cache() -> Result<(), String> {
     DETECTOR.clear_all_caches();
+    CAPABILITIES.clear_all();
+    persist_detection_cache();
     Ok(())
 }
 
+/// Clears every cache, re-detects runtimes and hands them to the poller
+///
+/// Emits `refresh-complete` with the fresh `DetectionResult`.
+#[tauri::command]
+pub async fn refresh_all(app: AppHandle) -> Result<DetectionResult, String> {
+    CAPABILITIES.clear_all();
+    let result = DETECTOR.refresh().await;
+    persist_detection_cache();
+
+    // The polling loop re-reads the runtime list on every tick, so swapping
+    // it is enough; only start the loop if it isn't running yet
+    POLLING_SERVICE.set_runtimes(result.runtimes.clone()).await;
+    if !POLLING_SERVICE.is_running().await {
+        POLLING_SERVICE.start(app.clone()).await?;
+    }
+
+    app.emit("refresh-complete", &result)
+        .map_err(|e| e.to_string())?;
+
+    Ok(result)
+}
+
 #[tauri::command]
 pub async fn start_status_polling(app: AppHandle) -> Result<(), String> {
     // Get current runtimes from detector
-    let runtimes = DETECTOR.detect_all().await;
+    let runtimes = detect_all_runtimes().await.runtimes;
 
     // Update polling service with runtimes
     POLLING_SERVICE.set_runtimes(runtimes).await;
@@ -105,7 +303,479 @@ pub async fn stop_status_polling() -> Result<(), String> {
     Ok(())
 }
 
+/// Watches daemon reload/init events until cancelled
+///
+/// Every event is emitted as `daemon-event` and clears the runtime's polling
+/// backoff so its status is re-checked on the next tick.
+#[tauri::command]
+pub async fn watch_daemon_events(app: AppHandle, runtime: Runtime) -> Result<(), String> {
+    events::watch_daemon_events(app, &runtime, |event| {
+        POLLING_SERVICE.reset_backoff_blocking(&event.runtime_id);
+    })
+    .await
+}
+
+#[tauri::command]
+pub async fn runtime_info(runtime: Runtime) -> Result<serde_json::Value, String> {
+    info::runtime_info(&runtime).await
+}
+
+#[tauri::command]
+pub async fn system_disk_usage_command(runtime: Runtime) -> Result<DiskUsage, String> {
+    system::disk_usage(&runtime).await
+}
+
+#[tauri::command]
+pub async fn runtime_supports(runtime: Runtime, feature: Feature) -> bool {
+    let caps = CAPABILITIES
+        .get_or_probe(&runtime, probe_capabilities)
+        .await;
+    capabilities::runtime_supports(&runtime, Some(&caps), feature)
+}
+
+#[tauri::command]
+pub async fn buildkit_enabled(runtime: Runtime) -> Option<bool> {
+    buildkit::buildkit_enabled(&runtime).await
+}
+
+#[tauri::command]
+pub async fn verify_runtime(runtime: Runtime) -> RuntimeDiagnostics {
+    diagnostics::verify_runtime(&runtime).await
+}
+
+#[tauri::command]
+pub async fn get_insecure_registries(runtime: Runtime) -> Result<Vec<String>, String> {
+    info::get_insecure_registries(&runtime).await
+}
+
+#[tauri::command]
+pub async fn get_registry_mirrors(runtime: Runtime) -> Result<Vec<String>, String> {
+    info::get_registry_mirrors(&runtime).await
+}
+
+#[tauri::command]
+pub async fn get_runtime_warnings(runtime: Runtime) -> Result<Vec<String>, String> {
+    info::get_runtime_warnings(&runtime).await
+}
+
+#[tauri::command]
+pub async fn container_exists(runtime: Runtime, container_id: String) -> bool {
+    raw_inspect::resource_exists(&runtime, ResourceKind::Container, &container_id).await
+}
+
+#[tauri::command]
+pub async fn detect_compose(runtime: Runtime) -> Option<ComposeInfo> {
+    detect::detect_compose(&runtime).await
+}
+
+#[tauri::command]
+pub async fn get_resource_limits(
+    runtime: Runtime,
+    container_id: String,
+) -> Result<ResourceLimits, String> {
+    inspect::get_resource_limits(&runtime, &container_id).await
+}
+
+#[tauri::command]
+pub async fn container_env_overrides(
+    runtime: Runtime,
+    container_id: String,
+) -> Result<Vec<(String, String)>, String> {
+    inspect::container_env_overrides(&runtime, &container_id).await
+}
+
+#[tauri::command]
+pub async fn get_log_config(
+    runtime: Runtime,
+    container_id: String,
+) -> Result<ContainerLogConfig, String> {
+    inspect::get_log_config(&runtime, &container_id).await
+}
+
+#[tauri::command]
+pub async fn export_logs(
+    runtime: Runtime,
+    container_id: String,
+    output_path: String,
+    options: LogOptions,
+) -> Result<LogExport, String> {
+    logs::export_logs(&runtime, &container_id, &output_path, &options).await
+}
+
+#[tauri::command]
+pub async fn exec_container_command(
+    runtime: Runtime,
+    container_id: String,
+    options: ExecOptions,
+) -> Result<ExecResult, String> {
+    exec::exec_container(&runtime, &container_id, &options).await
+}
+
+/// Creates and starts a container, returning its ID
+///
+/// Only detached runs are accepted: an attached run would hold the command
+/// open until the container exits.
+#[tauri::command]
+pub async fn run_container_command(
+    runtime: Runtime,
+    options: RunOptions,
+) -> Result<String, String> {
+    if !options.detached {
+        return Err("Containers started from the UI must run detached".to_string());
+    }
+    run::create_container(&runtime, &options).await
+}
+
+#[tauri::command]
+pub async fn rename_container_command(
+    runtime: Runtime,
+    container_id: String,
+    new_name: String,
+) -> Result<(), String> {
+    lifecycle::rename_container(&runtime, &container_id, &new_name).await
+}
+
+/// Follows the logs of several containers, emitting `multi-log-line` events
+///
+/// Returns the group ID to pass to `stop_multi_container_logs`.
+#[tauri::command]
+pub async fn multi_container_logs(
+    app: AppHandle,
+    runtime: Runtime,
+    ids: Vec<String>,
+    tail: Option<u32>,
+) -> Result<String, String> {
+    follow::start_log_group(
+        &runtime,
+        &ids,
+        tail,
+        Arc::new(move |line| {
+            if let Err(e) = app.emit("multi-log-line", &line) {
+                eprintln!("Failed to emit log line: {}", e);
+            }
+        }),
+    )
+}
+
+#[tauri::command]
+pub async fn stop_multi_container_logs(group_id: String) -> usize {
+    follow::stop_log_group(&group_id)
+}
+
+#[tauri::command]
+pub async fn get_port_mappings(
+    runtime: Runtime,
+    container_id: String,
+) -> Result<Vec<PortBinding>, String> {
+    ports::get_port_mappings(&runtime, &container_id).await
+}
+
+#[tauri::command]
+pub async fn stats_command(
+    runtime: Runtime,
+    container_id: String,
+) -> Result<ContainerStats, String> {
+    stats::get_stats(&runtime, &container_id).await
+}
+
+/// Streams `container-stats` events for a container until stopped
+///
+/// Starting a stream for a container that already has one replaces it.
+/// The container's sample history is dropped once its stream ends.
+#[tauri::command]
+pub async fn stream_container_stats(
+    app: AppHandle,
+    runtime: Runtime,
+    container_id: String,
+    interval_secs: Option<u64>,
+) -> Result<(), String> {
+    let token = CancellationToken::new();
+    if let Ok(mut streams) = STATS_STREAMS.lock() {
+        if let Some(previous) = streams.insert(container_id.clone(), token.clone()) {
+            previous.cancel();
+        }
+    }
+
+    let interval = interval_secs
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_STATS_INTERVAL);
+    let result = stats::stream_stats(app, &runtime, &container_id, interval, &token).await;
+
+    if let Ok(mut streams) = STATS_STREAMS.lock() {
+        // Leave a replacement stream's token in place
+        if streams
+            .get(&container_id)
+            .is_some_and(|t| t.same_token(&token))
+        {
+            streams.remove(&container_id);
+            STATS_HISTORY.evict(&container_id);
+        }
+    }
+
+    result
+}
+
+#[tauri::command]
+pub fn get_stats_history(container_id: String) -> Vec<ContainerStats> {
+    STATS_HISTORY.get(&container_id)
+}
+
+#[tauri::command]
+pub async fn stop_container_stats(container_id: String) -> Result<bool, String> {
+    let token = STATS_STREAMS
+        .lock()
+        .map_err(|e| e.to_string())?
+        .remove(&container_id);
+
+    match token {
+        Some(token) => {
+            token.cancel();
+            STATS_HISTORY.evict(&container_id);
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+#[tauri::command]
+pub async fn inspect_raw(
+    runtime: Runtime,
+    kind: ResourceKind,
+    id: String,
+) -> Result<serde_json::Value, String> {
+    raw_inspect::inspect_raw(&runtime, kind, &id).await
+}
+
+#[tauri::command]
+pub async fn list_containers_raw(runtime: Runtime, format: String) -> Result<Vec<String>, String> {
+    list::list_containers_raw(&runtime, &format).await
+}
+
+#[tauri::command]
+pub async fn list_containers_brief(
+    runtime: Runtime,
+    all: bool,
+) -> Result<Vec<ContainerBrief>, String> {
+    list::list_containers_brief(&runtime, all).await
+}
+
+#[tauri::command]
+pub async fn containers_health_summary(runtime: Runtime) -> Result<HealthSummary, String> {
+    list::containers_health_summary(&runtime).await
+}
+
+#[tauri::command]
+pub async fn pause_all_containers(
+    runtime: Runtime,
+    label: Option<LabelSelector>,
+) -> Result<BatchResult, String> {
+    batch::pause_all_containers(&runtime, label.as_ref()).await
+}
+
+#[tauri::command]
+pub async fn unpause_all_containers(
+    runtime: Runtime,
+    label: Option<LabelSelector>,
+) -> Result<BatchResult, String> {
+    batch::unpause_all_containers(&runtime, label.as_ref()).await
+}
+
+#[tauri::command]
+pub async fn kill_all_containers(
+    runtime: Runtime,
+    label: Option<LabelSelector>,
+    signal: Option<String>,
+) -> Result<BatchResult, String> {
+    let result = batch::kill_all_containers(&runtime, label.as_ref(), signal.as_deref()).await;
+    let entry = match &result {
+        Ok(batch) => AuditEntry::new(
+            &runtime.id,
+            "kill_all_containers",
+            batch.succeeded.clone(),
+            &result,
+        )
+        .with_failures(
+            batch
+                .failed
+                .iter()
+                .map(|note| (note.id.clone(), note.message.clone()))
+                .collect(),
+        ),
+        Err(_) => AuditEntry::new(&runtime.id, "kill_all_containers", Vec::new(), &result),
+    };
+    audit::record_entry(&entry);
+    result
+}
+
+#[tauri::command]
+pub async fn containers_by_label(
+    runtime: Runtime,
+    key: String,
+    value: Option<String>,
+) -> Result<Vec<String>, String> {
+    list::containers_by_label(&runtime, &LabelSelector { key, value }).await
+}
+
+#[tauri::command]
+pub async fn image_exists(runtime: Runtime, reference: String) -> bool {
+    raw_inspect::resource_exists(&runtime, ResourceKind::Image, &reference).await
+}
+
+#[tauri::command]
+pub async fn image_history_command(
+    runtime: Runtime,
+    image_id: String,
+) -> Result<Vec<HistoryLayer>, String> {
+    history::get_history(&runtime, &image_id).await
+}
+
+#[tauri::command]
+pub async fn tag_image_command(
+    runtime: Runtime,
+    source: String,
+    target_repo: String,
+    target_tag: String,
+) -> Result<(), String> {
+    tag::tag_image(&runtime, &source, &target_repo, &target_tag).await
+}
+
+/// Pushes an image, emitting `image-push-progress` events
+#[tauri::command]
+pub async fn push_image(
+    app: AppHandle,
+    runtime: Runtime,
+    options: PushOptions,
+) -> Result<String, String> {
+    push::push_image(&runtime, options, app).await
+}
+
+#[tauri::command]
+pub async fn import_image(
+    runtime: Runtime,
+    source_path: String,
+    reference: String,
+) -> Result<String, String> {
+    import::import_image(&runtime, &source_path, &reference).await
+}
+
+/// Builds an image, emitting `image-build-progress` events per output line
+///
+/// Cancellable via `cancel_build` with the same `build_id`.
+#[tauri::command]
+pub async fn build_image(
+    app: AppHandle,
+    runtime: Runtime,
+    build_id: String,
+    options: BuildOptions,
+) -> Result<String, String> {
+    let token = CancellationToken::new();
+    if let Ok(mut builds) = BUILDS.lock() {
+        if let Some(previous) = builds.insert(build_id.clone(), token.clone()) {
+            previous.cancel();
+        }
+    }
+
+    let progress_id = build_id.clone();
+    let on_line: BuildLineHandler = Arc::new(move |line: &str| {
+        let progress = BuildProgress {
+            build_id: progress_id.clone(),
+            line: line.to_string(),
+        };
+        if let Err(e) = app.emit("image-build-progress", &progress) {
+            eprintln!("Failed to emit build progress: {}", e);
+        }
+    });
+    let result = build::build_image(&runtime, &options, &token, on_line).await;
+
+    if let Ok(mut builds) = BUILDS.lock() {
+        // Leave a newer build's token in place
+        if builds.get(&build_id).is_some_and(|t| t.same_token(&token)) {
+            builds.remove(&build_id);
+        }
+    }
+
+    result
+}
+
+#[tauri::command]
+pub async fn cancel_build(build_id: String) -> Result<bool, String> {
+    let token = BUILDS.lock().map_err(|e| e.to_string())?.remove(&build_id);
+
+    match token {
+        Some(token) => {
+            token.cancel();
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+#[tauri::command]
+pub async fn prune_images(
+    runtime: Runtime,
+    options: Option<PruneImagesOptions>,
+) -> Result<PruneImagesResult, String> {
+    let result = prune::prune_images(&runtime, &options.unwrap_or_default()).await;
+    audit::record(&runtime, "prune_images", pruned_targets(&result), &result);
+    result
+}
+
+#[tauri::command]
+pub async fn prune_images_older_than(
+    runtime: Runtime,
+    age_secs: u64,
+) -> Result<PruneImagesResult, String> {
+    let result = prune::prune_images_older_than(&runtime, Duration::from_secs(age_secs)).await;
+    audit::record(
+        &runtime,
+        "prune_images_older_than",
+        pruned_targets(&result),
+        &result,
+    );
+    result
+}
+
+/// Deleted image IDs of a prune, for the audit log
+fn pruned_targets(result: &Result<PruneImagesResult, String>) -> Vec<String> {
+    result
+        .as_ref()
+        .map(|pruned| pruned.deleted.clone())
+        .unwrap_or_default()
+}
+
+#[tauri::command]
+pub fn parse_image_reference(reference: String) -> Result<ImageReference, String> {
+    reference::parse_image_reference(&reference)
+}
+
+#[tauri::command]
+pub async fn compose_restart(
+    app: AppHandle,
+    runtime: Runtime,
+    project_dir: String,
+    file: Option<String>,
+    service: Option<String>,
+) -> Result<(), String> {
+    restart::compose_restart(app, &runtime, &project_dir, file, service).await
+}
+
+#[tauri::command]
+pub async fn network_exists(runtime: Runtime, name: String) -> bool {
+    raw_inspect::resource_exists(&runtime, ResourceKind::Network, &name).await
+}
+
+#[tauri::command]
+pub async fn volume_exists(runtime: Runtime, name: String) -> bool {
+    raw_inspect::resource_exists(&runtime, ResourceKind::Volume, &name).await
+}
+
+#[tauri::command]
+pub fn get_platform_info() -> PlatformInfo {
+    platform_info()
+}
+
+/// Compatibility shim for callers that only need the OS string
 #[tauri::command]
 pub fn get_platform() -> String {
-    std::env::consts::OS.to_string()
+    platform_info().os
 }
@@ -0,0 +1,59 @@
+use crate::config::audit;
+use crate::network::inspect;
+use crate::network::lifecycle::{self, ConnectOptions, CreateNetworkOptions};
+use crate::network::list::{self, Network};
+use crate::types::Runtime;
+
+#[tauri::command]
+pub async fn list_networks(runtime: Runtime) -> Result<Vec<Network>, String> {
+    list::list_networks(&runtime).await
+}
+
+#[tauri::command]
+pub async fn inspect_network(runtime: Runtime, id: String) -> Result<Network, String> {
+    inspect::inspect_network(&runtime, &id).await
+}
+
+#[tauri::command]
+pub async fn create_network(
+    runtime: Runtime,
+    options: CreateNetworkOptions,
+) -> Result<String, String> {
+    lifecycle::create_network(&runtime, &options).await
+}
+
+#[tauri::command]
+pub async fn remove_network(runtime: Runtime, id: String) -> Result<(), String> {
+    let result = lifecycle::remove_network(&runtime, &id).await;
+    audit::record(&runtime, "remove_network", vec![id], &result);
+    result
+}
+
+#[tauri::command]
+pub async fn prune_networks(runtime: Runtime) -> Result<Vec<String>, String> {
+    let result = lifecycle::prune_networks(&runtime).await;
+    let targets = result.clone().unwrap_or_default();
+    audit::record(&runtime, "prune_networks", targets, &result);
+    result
+}
+
+#[tauri::command]
+pub async fn network_connect_command(
+    runtime: Runtime,
+    network_id: String,
+    container_id: String,
+    options: Option<ConnectOptions>,
+) -> Result<(), String> {
+    let options = options.unwrap_or_default();
+    lifecycle::connect_container(&runtime, &network_id, &container_id, &options).await
+}
+
+#[tauri::command]
+pub async fn network_disconnect_command(
+    runtime: Runtime,
+    network_id: String,
+    container_id: String,
+    force: bool,
+) -> Result<(), String> {
+    lifecycle::disconnect_container(&runtime, &network_id, &container_id, force).await
+}
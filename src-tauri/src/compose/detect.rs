@@ -0,0 +1,142 @@
+//! Compose availability and version detection
+//!
+//! Compose ships either as a CLI plugin (`docker compose`, v2) or as the
+//! standalone `docker-compose` binary (v1, or v2 installed standalone).
+//! The plugin is probed first because it targets the same runtime binary.
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::Duration;
+use tokio::time::timeout;
+
+use crate::runtime::version::parse_version;
+use crate::types::{Runtime, RuntimeType, Version};
+
+/// Maximum time to wait for a compose version probe (3 seconds)
+const PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Detected Compose installation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComposeInfo {
+    pub version: Version,
+    /// `true` for the `<runtime> compose` plugin, `false` for a standalone binary
+    #[serde(rename = "isPlugin")]
+    pub is_plugin: bool,
+}
+
+/// Runs a command with a timeout and returns its stdout on success
+async fn run_probe(program: PathBuf, args: &'static [&'static str]) -> Option<String> {
+    let result = timeout(PROBE_TIMEOUT, async {
+        tokio::task::spawn_blocking(move || Command::new(&program).args(args).output()).await
+    })
+    .await;
+
+    match result {
+        Ok(Ok(Ok(output))) if output.status.success() => {
+            Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Parses the output of `docker compose version --format json`
+///
+/// Accepts the JSON form (`{"version":"v2.24.5"}`) and falls back to the
+/// text form (`Docker Compose version v2.24.5`) for plugins that ignore
+/// `--format`.
+pub fn parse_plugin_version(output: &str) -> Option<Version> {
+    if let Ok(value) = serde_json::from_str::<serde_json::Value>(output) {
+        if let Some(version) = value["version"].as_str() {
+            return parse_version(version).ok();
+        }
+    }
+    parse_version(output).ok()
+}
+
+/// Parses the output of `docker-compose --version`
+///
+/// Handles v1 (`docker-compose version 1.29.2, build 5becea4c`) and
+/// standalone v2 (`Docker Compose version v2.24.5`).
+pub fn parse_standalone_version(output: &str) -> Option<Version> {
+    parse_version(output).ok()
+}
+
+/// Name of the standalone compose binary for a runtime type
+///
+/// nerdctl ships `nerdctl compose` built in and has no standalone binary.
+fn standalone_binary(runtime_type: &RuntimeType) -> Option<&'static str> {
+    match runtime_type {
+        RuntimeType::Docker => Some("docker-compose"),
+        RuntimeType::Podman => Some("podman-compose"),
+        RuntimeType::Nerdctl => None,
+    }
+}
+
+/// Detects Compose for a runtime
+///
+/// Probes `<runtime> compose version --format json` first, then the
+/// standalone binary found in PATH.
+///
+/// # Arguments
+/// * `runtime` - The runtime to probe
+///
+/// # Returns
+/// - `Some(ComposeInfo)` if either form of Compose responded with a version
+/// - `None` if Compose is not available
+pub async fn detect_compose(runtime: &Runtime) -> Option<ComposeInfo> {
+    let plugin_output = run_probe(
+        PathBuf::from(&runtime.path),
+        &["compose", "version", "--format", "json"],
+    )
+    .await;
+
+    if let Some(version) = plugin_output.as_deref().and_then(parse_plugin_version) {
+        return Some(ComposeInfo {
+            version,
+            is_plugin: true,
+        });
+    }
+
+    let standalone = which::which(standalone_binary(&runtime.runtime_type)?).ok()?;
+    let output = run_probe(standalone, &["--version"]).await?;
+
+    parse_standalone_version(&output).map(|version| ComposeInfo {
+        version,
+        is_plugin: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_plugin_version_json() {
+        let version = parse_plugin_version(r#"{"version":"v2.24.5"}"#).unwrap();
+        assert_eq!(version.major, 2);
+        assert_eq!(version.minor, 24);
+        assert_eq!(version.patch, 5);
+    }
+
+    #[test]
+    fn test_parse_plugin_version_text() {
+        let version = parse_plugin_version("Docker Compose version v2.20.2-desktop.1").unwrap();
+        assert_eq!(version.full, "2.20.2");
+    }
+
+    #[test]
+    fn test_parse_standalone_v1() {
+        let version =
+            parse_standalone_version("docker-compose version 1.29.2, build 5becea4c").unwrap();
+        assert_eq!(version.major, 1);
+        assert_eq!(version.minor, 29);
+        assert_eq!(version.patch, 2);
+    }
+
+    #[test]
+    fn test_parse_invalid_output() {
+        assert!(parse_plugin_version(r#"{"other":"value"}"#).is_none());
+        assert!(parse_standalone_version("command not found").is_none());
+    }
+}
@@ -0,0 +1,4 @@
+// Docker Compose integration
+
+pub mod detect;
+pub mod restart;
@@ -0,0 +1,145 @@
+//! Compose project and service restarts
+//!
+//! Runs `<runtime> compose [-f <file>] restart [service]` inside the project
+//! directory and streams the CLI's progress lines as `compose-progress`
+//! events while the restart is in flight.
+
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+use std::process::Stdio;
+use tauri::{AppHandle, Emitter};
+
+use crate::process::tracker::PROCESS_TRACKER;
+use crate::runtime::command::runtime_command;
+use crate::types::Runtime;
+
+/// Progress line emitted while a compose operation runs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComposeProgress {
+    #[serde(rename = "projectDir")]
+    pub project_dir: String,
+    pub line: String,
+}
+
+/// Builds the argument vector for `compose restart`
+///
+/// # Arguments
+/// * `file` - Optional compose file passed with `-f`
+/// * `service` - Service to restart; `None` restarts the whole project
+pub fn build_restart_args(file: Option<&str>, service: Option<&str>) -> Vec<String> {
+    let mut args = vec!["compose".to_string()];
+
+    if let Some(file) = file {
+        args.push("-f".to_string());
+        args.push(file.to_string());
+    }
+
+    args.push("restart".to_string());
+
+    if let Some(service) = service {
+        args.push(service.to_string());
+    }
+
+    args
+}
+
+/// Restarts a compose project, or a single service within it
+///
+/// Compose writes its progress to stderr; every line is forwarded as a
+/// `compose-progress` event. The child is registered with the process
+/// tracker so it is killed if the app exits mid-restart.
+///
+/// # Arguments
+/// * `app` - App handle used to emit progress events
+/// * `runtime` - Runtime whose `compose` plugin is used
+/// * `project_dir` - Directory containing the compose project
+/// * `file` - Optional compose file, relative to `project_dir` or absolute
+/// * `service` - Service to restart; `None` restarts every service
+///
+/// # Returns
+/// - `Ok(())` when compose exits successfully
+/// - `Err(String)` with the last stderr line on failure
+pub async fn compose_restart(
+    app: AppHandle,
+    runtime: &Runtime,
+    project_dir: &str,
+    file: Option<String>,
+    service: Option<String>,
+) -> Result<(), String> {
+    let dir = PathBuf::from(project_dir);
+    if !dir.is_dir() {
+        return Err(format!("Project directory not found: {}", project_dir));
+    }
+
+    let args = build_restart_args(file.as_deref(), service.as_deref());
+    let runtime = runtime.clone();
+    let project_dir = project_dir.to_string();
+
+    tokio::task::spawn_blocking(move || {
+        let mut child = runtime_command(&runtime)
+            .args(&args)
+            .current_dir(&dir)
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("Failed to run compose restart: {}", e))?;
+
+        let stderr = child.stderr.take();
+        let handle = PROCESS_TRACKER.track(child);
+
+        let mut last_line = String::new();
+        if let Some(stderr) = stderr {
+            for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                let line = line.trim().to_string();
+                if line.is_empty() {
+                    continue;
+                }
+
+                let progress = ComposeProgress {
+                    project_dir: project_dir.clone(),
+                    line: line.clone(),
+                };
+                if let Err(e) = app.emit("compose-progress", &progress) {
+                    eprintln!("Failed to emit compose progress: {}", e);
+                }
+                last_line = line;
+            }
+        }
+
+        match PROCESS_TRACKER.release(handle) {
+            Some(status) if status.success() => Ok(()),
+            Some(_) => Err(format!("Compose restart failed: {}", last_line)),
+            None => Err("Compose restart was cancelled".to_string()),
+        }
+    })
+    .await
+    .map_err(|e| format!("Compose restart task failed: {}", e))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restart_args_whole_project() {
+        let args = build_restart_args(None, None);
+        assert_eq!(args, vec!["compose", "restart"]);
+    }
+
+    #[test]
+    fn test_restart_args_single_service() {
+        let args = build_restart_args(Some("compose.prod.yml"), Some("web"));
+        assert_eq!(
+            args,
+            vec!["compose", "-f", "compose.prod.yml", "restart", "web"]
+        );
+    }
+
+    #[test]
+    fn test_restart_args_service_is_last() {
+        let args = build_restart_args(None, Some("db"));
+        assert_eq!(args.last().map(String::as_str), Some("db"));
+        assert_eq!(args.iter().filter(|a| *a == "db").count(), 1);
+    }
+}
@@ -0,0 +1,281 @@
+//! Append-only audit log of destructive operations
+//!
+//! Removals, prunes and kills are recorded one JSON object per line in
+//! `audit.jsonl` next to `config.json`, only while the `audit_log_enabled`
+//! preference is on. Entries are never rewritten; a failure to write one is
+//! reported on stderr and never fails the operation itself.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::config::preferences::{get_config_dir, load_preferences};
+use crate::types::{Runtime, RuntimePreferences};
+
+/// Outcome of an audited operation
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditResult {
+    Success,
+    /// A batch operation that failed for some of its targets
+    Partial,
+    Failure,
+}
+
+/// One line of the audit log
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub timestamp: DateTime<Utc>,
+    #[serde(rename = "runtimeId")]
+    pub runtime_id: String,
+    /// Command name, e.g. `remove_network` or `prune_images`
+    pub operation: String,
+    /// IDs or names the operation acted on
+    pub targets: Vec<String>,
+    /// IDs or names the operation failed for
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failed: Vec<String>,
+    pub result: AuditResult,
+    /// CLI error message when `result` is `partial` or `failure`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl AuditEntry {
+    /// Creates an entry stamped with the current time
+    pub fn new<T>(
+        runtime_id: &str,
+        operation: &str,
+        targets: Vec<String>,
+        result: &Result<T, String>,
+    ) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            runtime_id: runtime_id.to_string(),
+            operation: operation.to_string(),
+            targets,
+            failed: Vec::new(),
+            result: if result.is_ok() {
+                AuditResult::Success
+            } else {
+                AuditResult::Failure
+            },
+            error: result.as_ref().err().cloned(),
+        }
+    }
+
+    /// Adds per-target failures of a batch operation
+    ///
+    /// The result becomes `partial` when other targets succeeded and
+    /// `failure` when none did; the messages are joined into `error`.
+    ///
+    /// # Arguments
+    /// * `failures` - `(target, message)` pairs
+    pub fn with_failures(mut self, failures: Vec<(String, String)>) -> Self {
+        if failures.is_empty() {
+            return self;
+        }
+
+        self.result = if self.targets.is_empty() {
+            AuditResult::Failure
+        } else {
+            AuditResult::Partial
+        };
+        self.error = Some(
+            failures
+                .iter()
+                .map(|(target, message)| format!("{}: {}", target, message))
+                .collect::<Vec<_>>()
+                .join("; "),
+        );
+        self.failed = failures.into_iter().map(|(target, _)| target).collect();
+        self
+    }
+}
+
+/// Get the full path to the audit log
+pub fn get_audit_log_path() -> Result<PathBuf, Box<dyn Error>> {
+    Ok(get_config_dir()?.join("audit.jsonl"))
+}
+
+/// Appends one entry to the log at `path`, creating the file if needed
+pub fn append_entry(path: &Path, entry: &AuditEntry) -> Result<(), Box<dyn Error>> {
+    let mut line = serde_json::to_string(entry)?;
+    line.push('\n');
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    file.write_all(line.as_bytes())?;
+    Ok(())
+}
+
+/// Reads the most recent entries from the log at `path`
+///
+/// Lines that don't parse (e.g. a write cut short by a crash) are skipped.
+///
+/// # Returns
+/// Up to `limit` entries, newest first; empty if the log doesn't exist
+pub fn read_entries(path: &Path, limit: usize) -> Result<Vec<AuditEntry>, Box<dyn Error>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let contents = fs::read_to_string(path)?;
+    Ok(contents
+        .lines()
+        .rev()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .take(limit)
+        .collect())
+}
+
+/// Appends `entry` to the log at `path` if `prefs` enable auditing
+///
+/// # Returns
+/// Whether the entry was written
+pub fn record_to(
+    prefs: &RuntimePreferences,
+    path: &Path,
+    entry: &AuditEntry,
+) -> Result<bool, Box<dyn Error>> {
+    if !prefs.audit_log_enabled {
+        return Ok(false);
+    }
+    append_entry(path, entry)?;
+    Ok(true)
+}
+
+/// Records a prepared entry in the audit log if auditing is enabled
+pub fn record_entry(entry: &AuditEntry) {
+    let prefs = load_preferences().unwrap_or_default();
+    if !prefs.audit_log_enabled {
+        return;
+    }
+
+    if let Err(e) = get_audit_log_path().and_then(|path| record_to(&prefs, &path, entry)) {
+        eprintln!("Failed to write audit log entry: {}", e);
+    }
+}
+
+/// Records an operation in the audit log if auditing is enabled
+///
+/// # Arguments
+/// * `runtime` - The runtime the operation ran against
+/// * `operation` - Command name
+/// * `targets` - IDs or names acted on
+/// * `result` - The operation's result, passed through untouched
+pub fn record<T>(
+    runtime: &Runtime,
+    operation: &str,
+    targets: Vec<String>,
+    result: &Result<T, String>,
+) {
+    record_entry(&AuditEntry::new(&runtime.id, operation, targets, result));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remove_appends_entry_and_reads_back() {
+        let path =
+            std::env::temp_dir().join(format!("harbor-master-audit-{}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let removed: Result<(), String> = Ok(());
+        let entry = AuditEntry::new(
+            "docker-/usr/bin/docker",
+            "remove_network",
+            vec!["frontend".to_string()],
+            &removed,
+        );
+        append_entry(&path, &entry).unwrap();
+
+        let failed: Result<(), String> = Err("network backend has active endpoints".to_string());
+        let second = AuditEntry::new(
+            "docker-/usr/bin/docker",
+            "remove_network",
+            vec!["backend".to_string()],
+            &failed,
+        );
+        append_entry(&path, &second).unwrap();
+
+        let contents = fs::read_to_string(&path).unwrap();
+        let line: serde_json::Value =
+            serde_json::from_str(contents.lines().next().unwrap()).unwrap();
+        assert_eq!(line["runtimeId"], "docker-/usr/bin/docker");
+        assert_eq!(line["operation"], "remove_network");
+        assert_eq!(line["targets"], serde_json::json!(["frontend"]));
+        assert_eq!(line["result"], "success");
+        assert!(line["timestamp"].is_string());
+        assert!(line.get("error").is_none());
+
+        let entries = read_entries(&path, 10).unwrap();
+        assert_eq!(entries, vec![second.clone(), entry]);
+        assert_eq!(read_entries(&path, 1).unwrap(), vec![second]);
+
+        fs::remove_file(&path).unwrap();
+        assert!(read_entries(&path, 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_record_to_respects_audit_log_enabled() {
+        let path = std::env::temp_dir().join(format!(
+            "harbor-master-audit-gate-{}.jsonl",
+            std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+
+        let killed: Result<(), String> = Ok(());
+        let entry = AuditEntry::new(
+            "docker-/usr/bin/docker",
+            "kill_all_containers",
+            vec!["a1b2c3".to_string()],
+            &killed,
+        );
+
+        let disabled = RuntimePreferences::default();
+        assert!(!record_to(&disabled, &path, &entry).unwrap());
+        assert!(!path.exists());
+
+        let enabled = RuntimePreferences {
+            audit_log_enabled: true,
+            ..RuntimePreferences::default()
+        };
+        assert!(record_to(&enabled, &path, &entry).unwrap());
+        assert_eq!(read_entries(&path, 10).unwrap(), vec![entry]);
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_with_failures_marks_partial_or_failure() {
+        let ok: Result<(), String> = Ok(());
+        let failures = vec![("d4e5f6".to_string(), "container is paused".to_string())];
+
+        let partial = AuditEntry::new(
+            "docker",
+            "kill_all_containers",
+            vec!["a1b2c3".to_string()],
+            &ok,
+        )
+        .with_failures(failures.clone());
+        assert_eq!(partial.result, AuditResult::Partial);
+        assert_eq!(partial.failed, vec!["d4e5f6"]);
+        assert_eq!(
+            partial.error.as_deref(),
+            Some("d4e5f6: container is paused")
+        );
+
+        let failed = AuditEntry::new("docker", "kill_all_containers", Vec::new(), &ok)
+            .with_failures(failures);
+        assert_eq!(failed.result, AuditResult::Failure);
+
+        let clean = AuditEntry::new("docker", "kill_all_containers", Vec::new(), &ok)
+            .with_failures(Vec::new());
+        assert_eq!(clean.result, AuditResult::Success);
+    }
+}
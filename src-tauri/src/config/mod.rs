@@ -1,4 +1,6 @@
 // Configuration management
 // To be implemented in later phases
 
+pub mod audit;
 pub mod preferences;
+pub mod schema;
@@ -1,11 +1,36 @@
-use crate::types::RuntimePreferences;
+use crate::types::{Runtime, RuntimePreferences, RuntimeStatus};
 use serde_json;
 use std::error::Error;
+use std::ffi::OsString;
 use std::fs;
 use std::path::PathBuf;
 
+/// Environment variable that relocates the config directory, e.g. for portable installs
+pub const CONFIG_DIR_ENV: &str = "HARBORMASTER_CONFIG_DIR";
+
 /// Get the config directory path based on platform
+///
+/// `HARBORMASTER_CONFIG_DIR`, when set and non-empty, overrides the platform default.
 pub fn get_config_dir() -> Result<PathBuf, Box<dyn Error>> {
+    let config_dir = config_dir_from(std::env::var_os(CONFIG_DIR_ENV))?;
+
+    // Create directory if it doesn't exist
+    if !config_dir.exists() {
+        fs::create_dir_all(&config_dir)?;
+    }
+
+    Ok(config_dir)
+}
+
+/// Resolve the config directory from an optional override without touching the filesystem
+///
+/// # Arguments
+/// * `override_dir` - Value of `HARBORMASTER_CONFIG_DIR`; empty values are ignored
+fn config_dir_from(override_dir: Option<OsString>) -> Result<PathBuf, Box<dyn Error>> {
+    if let Some(dir) = override_dir.filter(|dir| !dir.is_empty()) {
+        return Ok(PathBuf::from(dir));
+    }
+
     let config_dir = if cfg!(target_os = "windows") {
         // Windows: %APPDATA%\harbormaster
         let appdata = std::env::var("APPDATA")?;
@@ -23,11 +48,6 @@ pub fn get_config_dir() -> Result<PathBuf, Box<dyn Error>> {
         PathBuf::from(home).join(".config").join("harbormaster")
     };
 
-    // Create directory if it doesn't exist
-    if !config_dir.exists() {
-        fs::create_dir_all(&config_dir)?;
-    }
-
     Ok(config_dir)
 }
 
@@ -61,9 +81,99 @@ pub fn save_preferences(prefs: &RuntimePreferences) -> Result<(), Box<dyn Error>
     Ok(())
 }
 
+/// Records a runtime selection after checking it against detected runtimes
+///
+/// # Arguments
+/// * `prefs` - Preferences to update; left untouched on error
+/// * `runtimes` - Currently detected runtimes
+/// * `runtime_id` - ID chosen by the user
+///
+/// # Returns
+/// - `Ok(())` if the ID belongs to a detected runtime
+/// - `Err(String)` for an unknown ID
+pub fn apply_runtime_selection(
+    prefs: &mut RuntimePreferences,
+    runtimes: &[Runtime],
+    runtime_id: &str,
+) -> Result<(), String> {
+    if !runtimes.iter().any(|runtime| runtime.id == runtime_id) {
+        return Err(format!("Unknown runtime: {}", runtime_id));
+    }
+    prefs.selected_runtime_id = Some(runtime_id.to_string());
+    Ok(())
+}
+
+/// Finds the runtime a saved ID refers to
+///
+/// Docker IDs now carry the daemon target (`docker-<path>-<context>`), so an
+/// ID saved before that change may not match exactly. Such an ID falls back
+/// to the only runtime whose ID extends it; when several do, it is treated
+/// as stale rather than guessing.
+///
+/// # Returns
+/// - `Some(&Runtime)` for an exact or unambiguous legacy match
+/// - `None` otherwise
+pub fn find_saved_runtime<'a>(runtimes: &'a [Runtime], saved_id: &str) -> Option<&'a Runtime> {
+    if let Some(runtime) = runtimes.iter().find(|runtime| runtime.id == saved_id) {
+        return Some(runtime);
+    }
+
+    let prefix = format!("{}-", saved_id);
+    let mut candidates = runtimes
+        .iter()
+        .filter(|runtime| runtime.id.starts_with(&prefix));
+    match (candidates.next(), candidates.next()) {
+        (Some(runtime), None) => Some(runtime),
+        _ => None,
+    }
+}
+
+/// Picks the runtime to select automatically
+///
+/// In order: the previously selected runtime if it still exists; when
+/// `auto_select_running` is on, a running runtime, breaking ties with
+/// `preferred_type`; any runtime of `preferred_type`; the first detected runtime.
+///
+/// # Returns
+/// - `Some(&Runtime)` to select
+/// - `None` if no runtimes were detected
+pub fn auto_select_runtime<'a>(
+    prefs: &RuntimePreferences,
+    runtimes: &'a [Runtime],
+) -> Option<&'a Runtime> {
+    let previous = prefs
+        .selected_runtime_id
+        .as_deref()
+        .and_then(|id| find_saved_runtime(runtimes, id));
+    if previous.is_some() {
+        return previous;
+    }
+
+    let is_preferred =
+        |runtime: &&Runtime| prefs.preferred_type.as_ref() == Some(&runtime.runtime_type);
+
+    if prefs.auto_select_running {
+        let running: Vec<&Runtime> = runtimes
+            .iter()
+            .filter(|runtime| runtime.status == RuntimeStatus::Running)
+            .collect();
+        if let Some(runtime) = running
+            .iter()
+            .copied()
+            .find(is_preferred)
+            .or(running.first().copied())
+        {
+            return Some(runtime);
+        }
+    }
+
+    runtimes.iter().find(is_preferred).or(runtimes.first())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::RuntimeType;
 
     #[test]
     fn test_config_dir_not_empty() {
@@ -71,6 +181,19 @@ mod tests {
         assert!(!dir.as_os_str().is_empty());
     }
 
+    #[test]
+    fn test_config_dir_override() {
+        let override_dir = PathBuf::from("/tmp/harbor-master-portable");
+        let default_dir = config_dir_from(None).unwrap();
+
+        assert_eq!(
+            config_dir_from(Some(override_dir.clone().into_os_string())).unwrap(),
+            override_dir
+        );
+        assert_eq!(config_dir_from(Some(OsString::new())).unwrap(), default_dir);
+        assert_ne!(default_dir, override_dir);
+    }
+
     #[test]
     fn test_default_preferences() {
         let prefs = RuntimePreferences::default();
@@ -78,4 +201,150 @@ mod tests {
         assert_eq!(prefs.detection_cache_ttl, 60);
         assert_eq!(prefs.status_poll_interval, 5);
     }
+
+    #[test]
+    fn test_snake_case_and_camel_case_load_equal() {
+        let snake_case: RuntimePreferences = serde_json::from_str(
+            r#"{
+                "selected_runtime_id": "podman-/usr/bin/podman",
+                "auto_select_running": false,
+                "preferred_type": "podman",
+                "detection_cache_ttl": 120,
+                "status_poll_interval": 10
+            }"#,
+        )
+        .unwrap();
+        let camel_case: RuntimePreferences = serde_json::from_str(
+            r#"{
+                "selectedRuntimeId": "podman-/usr/bin/podman",
+                "autoSelectRunning": false,
+                "preferredType": "podman",
+                "detectionCacheTTL": 120,
+                "statusPollInterval": 10
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(snake_case, camel_case);
+        assert_eq!(snake_case.detection_cache_ttl, 120);
+        // Config files from before the window size was persisted still load
+        assert_eq!(snake_case.window_width, 1200.0);
+        assert_eq!(snake_case.window_height, 800.0);
+        assert_eq!(snake_case.preferred_type, Some(RuntimeType::Podman));
+    }
+
+    #[test]
+    fn test_serialization_emits_camel_case() {
+        let prefs = RuntimePreferences {
+            selected_runtime_id: Some("docker-/usr/bin/docker".to_string()),
+            ..RuntimePreferences::default()
+        };
+        let serialized = serde_json::to_value(&prefs).unwrap();
+
+        for key in serialized.as_object().unwrap().keys() {
+            assert!(
+                !key.contains('_'),
+                "Serialized key is not camelCase: {}",
+                key
+            );
+        }
+
+        // A round trip through the serialized form is lossless
+        let reloaded: RuntimePreferences = serde_json::from_value(serialized).unwrap();
+        assert_eq!(reloaded, prefs);
+    }
+
+    #[test]
+    fn test_apply_runtime_selection() {
+        let runtimes = vec![Runtime::for_test("/usr/bin/docker").with_id("docker-/usr/bin/docker")];
+        let mut prefs = RuntimePreferences::default();
+
+        apply_runtime_selection(&mut prefs, &runtimes, "docker-/usr/bin/docker").unwrap();
+        assert_eq!(
+            prefs.selected_runtime_id.as_deref(),
+            Some("docker-/usr/bin/docker")
+        );
+
+        let before = serde_json::to_string(&prefs).unwrap();
+        assert!(apply_runtime_selection(&mut prefs, &runtimes, "garbage").is_err());
+        assert_eq!(serde_json::to_string(&prefs).unwrap(), before);
+    }
+
+    #[test]
+    fn test_auto_select_prefers_preferred_type_among_running() {
+        let runtimes = vec![
+            Runtime::for_test("/usr/bin/docker").with_id("docker-/usr/bin/docker"),
+            Runtime::for_test("/usr/bin/docker")
+                .with_id("podman-/usr/bin/podman")
+                .with_type(RuntimeType::Podman),
+        ];
+        let mut prefs = RuntimePreferences {
+            preferred_type: Some(RuntimeType::Podman),
+            ..RuntimePreferences::default()
+        };
+
+        let selected = auto_select_runtime(&prefs, &runtimes).unwrap();
+        assert_eq!(selected.runtime_type, RuntimeType::Podman);
+
+        // A previous selection still wins over the preference
+        prefs.selected_runtime_id = Some("docker-/usr/bin/docker".to_string());
+        let selected = auto_select_runtime(&prefs, &runtimes).unwrap();
+        assert_eq!(selected.id, "docker-/usr/bin/docker");
+    }
+
+    #[test]
+    fn test_auto_select_running_beats_preferred_type() {
+        let mut podman = Runtime::for_test("/usr/bin/docker")
+            .with_id("podman-/usr/bin/podman")
+            .with_type(RuntimeType::Podman);
+        podman.status = RuntimeStatus::Stopped;
+        let runtimes = vec![
+            podman,
+            Runtime::for_test("/usr/bin/docker").with_id("docker-/usr/bin/docker"),
+        ];
+        let mut prefs = RuntimePreferences {
+            preferred_type: Some(RuntimeType::Podman),
+            ..RuntimePreferences::default()
+        };
+
+        let selected = auto_select_runtime(&prefs, &runtimes).unwrap();
+        assert_eq!(selected.runtime_type, RuntimeType::Docker);
+
+        // With auto-select off only the preference applies
+        prefs.auto_select_running = false;
+        let selected = auto_select_runtime(&prefs, &runtimes).unwrap();
+        assert_eq!(selected.runtime_type, RuntimeType::Podman);
+
+        assert!(auto_select_runtime(&prefs, &[]).is_none());
+    }
+
+    #[test]
+    fn test_find_saved_runtime_falls_back_for_legacy_ids() {
+        let runtimes = vec![
+            Runtime::for_test("/usr/bin/docker").with_id("docker-/usr/bin/docker-staging"),
+            Runtime::for_test("/usr/bin/docker")
+                .with_id("podman-/usr/bin/podman")
+                .with_type(RuntimeType::Podman),
+        ];
+
+        assert_eq!(
+            find_saved_runtime(&runtimes, "podman-/usr/bin/podman")
+                .unwrap()
+                .id,
+            "podman-/usr/bin/podman"
+        );
+        assert_eq!(
+            find_saved_runtime(&runtimes, "docker-/usr/bin/docker")
+                .unwrap()
+                .id,
+            "docker-/usr/bin/docker-staging"
+        );
+        assert!(find_saved_runtime(&runtimes, "docker-/opt/docker").is_none());
+
+        let ambiguous = vec![
+            Runtime::for_test("/usr/bin/docker").with_id("docker-/usr/bin/docker-staging"),
+            Runtime::for_test("/usr/bin/docker").with_id("docker-/usr/bin/docker-prod"),
+        ];
+        assert!(find_saved_runtime(&ambiguous, "docker-/usr/bin/docker").is_none());
+    }
 }
@@ -0,0 +1,133 @@
+//! JSON Schema for the persisted preferences file
+//!
+//! The schema mirrors the serialized (camelCase) shape of `RuntimePreferences`
+//! so the frontend can validate settings before saving them and editors can
+//! show field descriptions.
+
+use serde_json::json;
+
+/// Builds the JSON Schema describing `RuntimePreferences`
+///
+/// # Returns
+/// Pretty-printed JSON Schema (draft-07) as a string
+pub fn preferences_schema() -> String {
+    let schema = json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "RuntimePreferences",
+        "description": "HarborMaster runtime preferences",
+        "type": "object",
+        "properties": {
+            "selectedRuntimeId": {
+                "description": "ID of the runtime selected by the user",
+                "type": "string"
+            },
+            "autoSelectRunning": {
+                "description": "Automatically select a running runtime when none is selected",
+                "type": "boolean"
+            },
+            "preferredType": {
+                "description": "Runtime type preferred during auto-selection",
+                "type": "string",
+                "enum": ["docker", "podman", "nerdctl"]
+            },
+            "detectionCacheTTL": {
+                "description": "Time-to-live for cached detection results in seconds",
+                "type": "integer",
+                "minimum": 0
+            },
+            "statusPollInterval": {
+                "description": "Interval between runtime status checks in seconds",
+                "type": "integer",
+                "minimum": 0
+            },
+            "detectionTimeoutMs": {
+                "description": "Maximum time for a single runtime probe in milliseconds",
+                "type": "integer",
+                "minimum": 0,
+                "default": 500
+            },
+            "crashLoopThreshold": {
+                "description": "Container restarts within the crash-loop window that count as a crash loop",
+                "type": "integer",
+                "minimum": 0,
+                "default": 3
+            },
+            "crashLoopWindow": {
+                "description": "Window for counting container restarts in seconds",
+                "type": "integer",
+                "minimum": 0,
+                "default": 300
+            },
+            "windowWidth": {
+                "description": "Last main window width in logical pixels",
+                "type": "number",
+                "minimum": 0,
+                "default": 1200
+            },
+            "windowHeight": {
+                "description": "Last main window height in logical pixels",
+                "type": "number",
+                "minimum": 0,
+                "default": 800
+            },
+            "minDockerVersion": {
+                "description": "Docker versions below this warn as unsupported (default 20.10.0)",
+                "type": "string",
+                "pattern": "^\\d+\\.\\d+\\.\\d+$"
+            },
+            "minPodmanVersion": {
+                "description": "Podman versions below this warn as unsupported (default 3.0.0)",
+                "type": "string",
+                "pattern": "^\\d+\\.\\d+\\.\\d+$"
+            },
+            "auditLogEnabled": {
+                "description": "Record removals, prunes and kills in audit.jsonl",
+                "type": "boolean",
+                "default": false
+            },
+            "persistDetectionCache": {
+                "description": "Save detection results so cold starts can skip the scan",
+                "type": "boolean",
+                "default": true
+            }
+        },
+        "required": ["autoSelectRunning", "detectionCacheTTL", "statusPollInterval"]
+    });
+
+    serde_json::to_string_pretty(&schema).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::RuntimePreferences;
+
+    #[test]
+    fn test_schema_includes_status_poll_interval() {
+        let schema: serde_json::Value = serde_json::from_str(&preferences_schema()).unwrap();
+        assert_eq!(
+            schema["properties"]["statusPollInterval"]["type"],
+            "integer"
+        );
+    }
+
+    #[test]
+    fn test_schema_covers_serialized_fields() {
+        let schema: serde_json::Value = serde_json::from_str(&preferences_schema()).unwrap();
+        let prefs = RuntimePreferences {
+            selected_runtime_id: Some("docker-/usr/bin/docker".to_string()),
+            min_docker_version: Some("24.0.0".to_string()),
+            min_podman_version: Some("4.0.0".to_string()),
+            ..RuntimePreferences::default()
+        };
+        let serialized = serde_json::to_value(&prefs).unwrap();
+
+        for key in serialized.as_object().unwrap().keys() {
+            assert!(
+                schema["properties"].get(key).is_some(),
+                "Schema is missing property: {}",
+                key
+            );
+        }
+    }
+}
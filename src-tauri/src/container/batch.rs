@@ -0,0 +1,337 @@
+//! Batch operations across all containers
+//!
+//! Pausing, unpausing or killing "everything" lists every container with its state,
+//! applies the operation to those in the right state, and records a per-ID
+//! note for the rest instead of failing the whole batch.
+
+use serde::{Deserialize, Serialize};
+
+use crate::container::list::{label_filter_args, LabelSelector};
+use crate::runtime::command::runtime_command;
+use crate::types::Runtime;
+
+/// Operation applied by a batch command
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BatchOperation {
+    Pause,
+    Unpause,
+    /// Immediate kill with a signal, no graceful stop period
+    Kill,
+}
+
+impl BatchOperation {
+    /// CLI subcommand for the operation
+    fn command(self) -> &'static str {
+        match self {
+            BatchOperation::Pause => "pause",
+            BatchOperation::Unpause => "unpause",
+            BatchOperation::Kill => "kill",
+        }
+    }
+
+    /// Container state the operation applies to
+    fn required_state(self) -> &'static str {
+        match self {
+            BatchOperation::Pause | BatchOperation::Kill => "running",
+            BatchOperation::Unpause => "paused",
+        }
+    }
+}
+
+/// Per-container note for a skipped or failed batch entry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchNote {
+    pub id: String,
+    pub message: String,
+}
+
+/// Outcome of a batch operation
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BatchResult {
+    /// IDs the operation was applied to
+    pub succeeded: Vec<String>,
+    /// IDs where the CLI reported an error
+    pub failed: Vec<BatchNote>,
+    /// IDs left alone because their state doesn't allow the operation
+    pub skipped: Vec<BatchNote>,
+}
+
+/// Parses `ps -a --format '{{.ID}}\t{{.State}}'` output into `(id, state)` pairs
+fn parse_container_states(output: &str) -> Vec<(String, String)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (id, state) = line.trim().split_once('\t')?;
+            Some((id.trim().to_string(), state.trim().to_lowercase()))
+        })
+        .filter(|(id, _)| !id.is_empty())
+        .collect()
+}
+
+/// Splits containers into targets and skipped entries for an operation
+///
+/// # Arguments
+/// * `containers` - `(id, state)` pairs for every container
+/// * `operation` - The operation about to be applied
+///
+/// # Returns
+/// IDs to operate on, and a note for every container in another state
+fn plan_batch(
+    containers: &[(String, String)],
+    operation: BatchOperation,
+) -> (Vec<String>, Vec<BatchNote>) {
+    let mut targets = Vec::new();
+    let mut skipped = Vec::new();
+
+    for (id, state) in containers {
+        if state == operation.required_state() {
+            targets.push(id.clone());
+        } else {
+            skipped.push(BatchNote {
+                id: id.clone(),
+                message: format!(
+                    "Cannot {} container in state '{}'",
+                    operation.command(),
+                    state
+                ),
+            });
+        }
+    }
+
+    (targets, skipped)
+}
+
+/// Builds the `ps` arguments listing containers with their state
+fn build_state_list_args(label: Option<&LabelSelector>) -> Result<Vec<String>, String> {
+    let mut args: Vec<String> = ["ps", "-a", "--format", "{{.ID}}\t{{.State}}"]
+        .iter()
+        .map(|arg| arg.to_string())
+        .collect();
+    if let Some(selector) = label {
+        args.extend(label_filter_args(selector)?);
+    }
+    Ok(args)
+}
+
+/// Lists every container (or every container matching `label`) with its state
+async fn list_container_states(
+    runtime: &Runtime,
+    label: Option<&LabelSelector>,
+) -> Result<Vec<(String, String)>, String> {
+    let runtime = runtime.clone();
+    let args = build_state_list_args(label)?;
+
+    let output =
+        tokio::task::spawn_blocking(move || runtime_command(&runtime).args(&args).output())
+            .await
+            .map_err(|e| format!("List task failed: {}", e))?
+            .map_err(|e| format!("Failed to execute ps: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("ps failed: {}", stderr.trim()));
+    }
+
+    Ok(parse_container_states(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
+}
+
+/// Builds the CLI arguments for applying an operation to one container
+///
+/// # Arguments
+/// * `operation` - The operation to apply
+/// * `extra_args` - Flags placed between the subcommand and the ID
+/// * `container_id` - Container ID
+fn build_operation_args(
+    operation: BatchOperation,
+    extra_args: &[String],
+    container_id: &str,
+) -> Vec<String> {
+    let mut args = vec![operation.command().to_string()];
+    args.extend(extra_args.iter().cloned());
+    args.push(container_id.to_string());
+    args
+}
+
+/// Applies an operation to a single container
+async fn apply_operation(
+    runtime: &Runtime,
+    operation: BatchOperation,
+    extra_args: &[String],
+    container_id: &str,
+) -> Result<(), String> {
+    let runtime = runtime.clone();
+    let args = build_operation_args(operation, extra_args, container_id);
+
+    let output =
+        tokio::task::spawn_blocking(move || runtime_command(&runtime).args(&args).output())
+            .await
+            .map_err(|e| format!("{} task failed: {}", operation.command(), e))?
+            .map_err(|e| format!("Failed to execute {}: {}", operation.command(), e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(stderr.trim().to_string());
+    }
+    Ok(())
+}
+
+/// Applies an operation to every container in the matching state
+///
+/// # Arguments
+/// * `runtime` - The runtime to operate on
+/// * `operation` - The operation to apply
+/// * `label` - Only consider containers matching this selector when set
+/// * `extra_args` - Flags passed to every invocation, e.g. `--signal KILL`
+///
+/// # Returns
+/// - `Ok(BatchResult)` with per-ID outcomes
+/// - `Err(String)` only if the containers could not be listed
+pub async fn run_batch(
+    runtime: &Runtime,
+    operation: BatchOperation,
+    label: Option<&LabelSelector>,
+    extra_args: &[String],
+) -> Result<BatchResult, String> {
+    let containers = list_container_states(runtime, label).await?;
+    let (targets, skipped) = plan_batch(&containers, operation);
+
+    let mut result = BatchResult {
+        skipped,
+        ..BatchResult::default()
+    };
+
+    for id in targets {
+        match apply_operation(runtime, operation, extra_args, &id).await {
+            Ok(()) => result.succeeded.push(id),
+            Err(message) => result.failed.push(BatchNote { id, message }),
+        }
+    }
+
+    Ok(result)
+}
+
+/// Pauses every running container, optionally only those matching `label`
+pub async fn pause_all_containers(
+    runtime: &Runtime,
+    label: Option<&LabelSelector>,
+) -> Result<BatchResult, String> {
+    run_batch(runtime, BatchOperation::Pause, label, &[]).await
+}
+
+/// Unpauses every paused container, optionally only those matching `label`
+pub async fn unpause_all_containers(
+    runtime: &Runtime,
+    label: Option<&LabelSelector>,
+) -> Result<BatchResult, String> {
+    run_batch(runtime, BatchOperation::Unpause, label, &[]).await
+}
+
+/// Validates a signal name or number such as `KILL`, `SIGTERM` or `9`
+fn validate_signal(signal: &str) -> Result<(), String> {
+    if signal.is_empty() || !signal.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(format!("Invalid signal: {}", signal));
+    }
+    Ok(())
+}
+
+/// Kills every running container immediately
+///
+/// Unlike a stop there is no graceful period: each container receives
+/// `signal` (default `KILL`) right away.
+///
+/// # Arguments
+/// * `runtime` - The runtime to operate on
+/// * `label` - Only kill containers matching this selector when set
+/// * `signal` - Signal name or number, `KILL` when `None`
+pub async fn kill_all_containers(
+    runtime: &Runtime,
+    label: Option<&LabelSelector>,
+    signal: Option<&str>,
+) -> Result<BatchResult, String> {
+    let signal = signal.unwrap_or("KILL");
+    validate_signal(signal)?;
+
+    let extra_args = ["--signal".to_string(), signal.to_string()];
+    run_batch(runtime, BatchOperation::Kill, label, &extra_args).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MIXED_STATES: &str = "a1\trunning\nb2\tpaused\nc3\texited\nd4\tRunning\ne5\tcreated\n";
+
+    #[test]
+    fn test_parse_container_states() {
+        let states = parse_container_states(MIXED_STATES);
+        assert_eq!(states.len(), 5);
+        assert_eq!(states[3], ("d4".to_string(), "running".to_string()));
+        assert!(parse_container_states("garbage\n\n").is_empty());
+    }
+
+    #[test]
+    fn test_plan_pause_mixed_states() {
+        let states = parse_container_states(MIXED_STATES);
+        let (targets, skipped) = plan_batch(&states, BatchOperation::Pause);
+
+        assert_eq!(targets, vec!["a1", "d4"]);
+        let skipped_ids: Vec<&str> = skipped.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(skipped_ids, vec!["b2", "c3", "e5"]);
+        assert_eq!(
+            skipped[1].message,
+            "Cannot pause container in state 'exited'"
+        );
+    }
+
+    #[test]
+    fn test_plan_unpause_mixed_states() {
+        let states = parse_container_states(MIXED_STATES);
+        let (targets, skipped) = plan_batch(&states, BatchOperation::Unpause);
+
+        assert_eq!(targets, vec!["b2"]);
+        assert_eq!(skipped.len(), 4);
+    }
+
+    #[test]
+    fn test_plan_kill_running_only() {
+        let states = parse_container_states(MIXED_STATES);
+        let (targets, skipped) = plan_batch(&states, BatchOperation::Kill);
+
+        assert_eq!(targets, vec!["a1", "d4"]);
+        assert_eq!(
+            skipped[0].message,
+            "Cannot kill container in state 'paused'"
+        );
+
+        let extra_args = ["--signal".to_string(), "KILL".to_string()];
+        let args: Vec<Vec<String>> = targets
+            .iter()
+            .map(|id| build_operation_args(BatchOperation::Kill, &extra_args, id))
+            .collect();
+        assert_eq!(args[0], vec!["kill", "--signal", "KILL", "a1"]);
+        assert_eq!(args[1], vec!["kill", "--signal", "KILL", "d4"]);
+    }
+
+    #[test]
+    fn test_build_state_list_args_with_label() {
+        assert_eq!(build_state_list_args(None).unwrap().len(), 4);
+
+        let selector = LabelSelector {
+            key: "app".to_string(),
+            value: Some("web".to_string()),
+        };
+        let args = build_state_list_args(Some(&selector)).unwrap();
+        assert_eq!(&args[4..], ["--filter", "label=app=web"]);
+    }
+
+    #[test]
+    fn test_validate_signal() {
+        assert!(validate_signal("KILL").is_ok());
+        assert!(validate_signal("SIGTERM").is_ok());
+        assert!(validate_signal("9").is_ok());
+        assert!(validate_signal("").is_err());
+        assert!(validate_signal("KILL; rm").is_err());
+    }
+}
@@ -0,0 +1,209 @@
+//! One-off commands in running containers
+//!
+//! Runs `docker exec` non-interactively and captures its output. stdin is
+//! never attached, so `--interactive` is never passed; `tty` only adds
+//! `--tty`, which makes the runtime merge the command's stderr into stdout.
+
+use serde::{Deserialize, Serialize};
+use std::process::Stdio;
+
+use crate::runtime::command::runtime_command;
+use crate::runtime::inspect::validate_container_id;
+use crate::types::Runtime;
+
+/// Options for a one-off exec
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExecOptions {
+    /// Command and its arguments, e.g. `["ls", "-la"]`
+    pub cmd: Vec<String>,
+    /// `KEY=VALUE` entries passed with `--env`
+    #[serde(default)]
+    pub env: Vec<String>,
+    #[serde(default, rename = "workingDir")]
+    pub working_dir: Option<String>,
+    #[serde(default)]
+    pub user: Option<String>,
+    /// Allocate a pseudo-TTY; stderr then arrives on stdout
+    #[serde(default)]
+    pub tty: bool,
+}
+
+/// Captured output of an exec
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExecResult {
+    /// Exit code of the command, `-1` if it was killed by a signal
+    #[serde(rename = "exitCode")]
+    pub exit_code: i32,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// Builds the `exec` arguments for a container
+///
+/// # Returns
+/// - `Ok(Vec<String>)` with flags before the container ID and the command after it
+/// - `Err(String)` if the container ID is invalid, the command is empty or an
+///   env entry isn't `KEY=VALUE`
+pub fn build_exec_args(container_id: &str, options: &ExecOptions) -> Result<Vec<String>, String> {
+    validate_container_id(container_id)?;
+    if options.cmd.is_empty() {
+        return Err("Exec command is empty".to_string());
+    }
+
+    let mut args = vec!["exec".to_string()];
+    if options.tty {
+        args.push("--tty".to_string());
+    }
+    for entry in &options.env {
+        if entry.split_once('=').is_none_or(|(key, _)| key.is_empty()) {
+            return Err(format!("Invalid environment entry: {}", entry));
+        }
+        args.push("--env".to_string());
+        args.push(entry.clone());
+    }
+    if let Some(dir) = &options.working_dir {
+        args.push("--workdir".to_string());
+        args.push(dir.clone());
+    }
+    if let Some(user) = &options.user {
+        args.push("--user".to_string());
+        args.push(user.clone());
+    }
+
+    args.push(container_id.to_string());
+    args.extend(options.cmd.iter().cloned());
+    Ok(args)
+}
+
+/// Runs a command in a running container and captures its output
+///
+/// A non-zero exit is not an error: the code is returned in `ExecResult`
+/// together with whatever the command wrote, even when stderr is non-empty.
+///
+/// # Arguments
+/// * `runtime` - The runtime to use
+/// * `container_id` - Container ID or name
+/// * `options` - Command, environment, working directory, user and TTY mode
+///
+/// # Returns
+/// - `Ok(ExecResult)` once the command has finished
+/// - `Err(String)` if the options are invalid or the CLI could not be started
+pub async fn exec_container(
+    runtime: &Runtime,
+    container_id: &str,
+    options: &ExecOptions,
+) -> Result<ExecResult, String> {
+    let runtime = runtime.clone();
+    let args = build_exec_args(container_id, options)?;
+
+    let output = tokio::task::spawn_blocking(move || {
+        runtime_command(&runtime)
+            .args(&args)
+            .stdin(Stdio::null())
+            .output()
+    })
+    .await
+    .map_err(|e| format!("Exec task failed: {}", e))?
+    .map_err(|e| format!("Failed to execute exec: {}", e))?;
+
+    Ok(ExecResult {
+        exit_code: output.status.code().unwrap_or(-1),
+        stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+        stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(cmd: &[&str]) -> ExecOptions {
+        ExecOptions {
+            cmd: cmd.iter().map(|s| s.to_string()).collect(),
+            ..ExecOptions::default()
+        }
+    }
+
+    #[test]
+    fn test_build_exec_args_minimal() {
+        let args = build_exec_args("web", &options(&["ls", "-la"])).unwrap();
+        assert_eq!(args, vec!["exec", "web", "ls", "-la"]);
+    }
+
+    #[test]
+    fn test_build_exec_args_all_options() {
+        let opts = ExecOptions {
+            env: vec!["FOO=bar".to_string(), "EMPTY=".to_string()],
+            working_dir: Some("/app".to_string()),
+            user: Some("1000:1000".to_string()),
+            tty: true,
+            ..options(&["sh", "-c", "env"])
+        };
+        let args = build_exec_args("web", &opts).unwrap();
+        assert_eq!(
+            args,
+            vec![
+                "exec",
+                "--tty",
+                "--env",
+                "FOO=bar",
+                "--env",
+                "EMPTY=",
+                "--workdir",
+                "/app",
+                "--user",
+                "1000:1000",
+                "web",
+                "sh",
+                "-c",
+                "env"
+            ]
+        );
+        assert!(!args.contains(&"--interactive".to_string()));
+    }
+
+    #[test]
+    fn test_build_exec_args_rejects_invalid() {
+        assert!(build_exec_args("web", &options(&[])).is_err());
+
+        let bad_env = ExecOptions {
+            env: vec!["NOEQUALS".to_string()],
+            ..options(&["ls"])
+        };
+        assert!(build_exec_args("web", &bad_env).is_err());
+
+        // A flag-like ID would otherwise turn into an exec option
+        assert!(build_exec_args("--privileged", &options(&["web", "sh"])).is_err());
+        assert!(build_exec_args("", &options(&["ls"])).is_err());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_exec_container_propagates_exit_code_with_stderr() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // A fake runtime that writes to both streams and exits non-zero
+        let script =
+            std::env::temp_dir().join(format!("harbor-master-exec-{}.sh", std::process::id()));
+        std::fs::write(&script, "#!/bin/sh\necho out\necho err >&2\nexit 3\n").unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let runtime = Runtime::for_test(script.to_str().unwrap());
+        let result = exec_container(&runtime, "web", &options(&["true"]))
+            .await
+            .unwrap();
+        std::fs::remove_file(&script).ok();
+
+        assert_eq!(result.exit_code, 3);
+        assert_eq!(result.stdout, "out\n");
+        assert_eq!(result.stderr, "err\n");
+    }
+
+    #[tokio::test]
+    async fn test_exec_container_missing_runtime() {
+        let runtime = Runtime::for_test("/nonexistent/docker");
+        assert!(exec_container(&runtime, "web", &options(&["ls"]))
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,256 @@
+//! Merged log following for a group of containers
+//!
+//! Each container gets its own `logs --follow` child and reader thread; lines
+//! are handed to a shared callback as they arrive, so the UI receives one
+//! interleaved stream tagged with the container ID. Overlong lines are cut at
+//! `MAX_LINE_BYTES` so a container writing without newlines can't make its
+//! reader buffer without bound.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::BufReader;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::process::lines::{read_bounded_line, MAX_LINE_BYTES};
+use crate::process::tracker::PROCESS_TRACKER;
+use crate::runtime::command::runtime_command;
+use crate::runtime::inspect::validate_container_id;
+use crate::types::Runtime;
+
+lazy_static::lazy_static! {
+    /// Tracker handles of the followers in each running group
+    static ref LOG_GROUPS: Mutex<HashMap<String, Vec<u64>>> = Mutex::new(HashMap::new());
+}
+
+static NEXT_GROUP: AtomicU64 = AtomicU64::new(1);
+
+/// Called for every line of every container in a group
+pub type LineHandler = Arc<dyn Fn(MultiLogLine) + Send + Sync>;
+
+/// Payload of the `multi-log-line` event
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MultiLogLine {
+    #[serde(rename = "containerId")]
+    pub container_id: String,
+    /// Line without its trailing newline
+    pub line: String,
+}
+
+/// Builds the `logs --follow` arguments for one container
+fn build_follow_args(container_id: &str, tail: Option<u32>) -> Result<Vec<String>, String> {
+    validate_container_id(container_id)?;
+    Ok(vec![
+        "logs".to_string(),
+        "--follow".to_string(),
+        "--tail".to_string(),
+        tail.unwrap_or(0).to_string(),
+        container_id.to_string(),
+    ])
+}
+
+/// Releases a follower that exited and drops its group once the last one is gone
+fn release_follower(group_id: &str, handle: u64) {
+    PROCESS_TRACKER.release(handle);
+    if let Ok(mut groups) = LOG_GROUPS.lock() {
+        if let Some(handles) = groups.get_mut(group_id) {
+            handles.retain(|h| *h != handle);
+            if handles.is_empty() {
+                groups.remove(group_id);
+            }
+        }
+    }
+}
+
+/// Spawns a follower for one container and registers it with its group
+fn spawn_follower(
+    runtime: &Runtime,
+    group_id: &str,
+    container_id: &str,
+    tail: Option<u32>,
+    on_line: LineHandler,
+) -> Result<(), String> {
+    let args = build_follow_args(container_id, tail)?;
+    let (reader, writer) = std::io::pipe().map_err(|e| format!("Failed to create pipe: {}", e))?;
+    let stderr_writer = writer
+        .try_clone()
+        .map_err(|e| format!("Failed to create pipe: {}", e))?;
+
+    // The `Command` (and its pipe ends) is dropped at the end of this
+    // statement, so the reader sees EOF once the child exits
+    let child = runtime_command(runtime)
+        .args(args)
+        .stdout(writer)
+        .stderr(stderr_writer)
+        .spawn()
+        .map_err(|e| format!("Failed to follow logs of {}: {}", container_id, e))?;
+    let handle = PROCESS_TRACKER.track(child);
+
+    // Registered before the reader starts so its release always finds the group
+    if let Ok(mut groups) = LOG_GROUPS.lock() {
+        groups.entry(group_id.to_string()).or_default().push(handle);
+    }
+
+    let group_id = group_id.to_string();
+    let container_id = container_id.to_string();
+    std::thread::spawn(move || {
+        let mut reader = BufReader::new(reader);
+        let mut buf = Vec::new();
+        while let Ok(true) = read_bounded_line(&mut reader, &mut buf, MAX_LINE_BYTES) {
+            let line = String::from_utf8_lossy(&buf);
+            on_line(MultiLogLine {
+                container_id: container_id.clone(),
+                line: line.trim_end_matches(['\n', '\r']).to_string(),
+            });
+        }
+        release_follower(&group_id, handle);
+    });
+
+    Ok(())
+}
+
+/// Starts following the logs of several containers at once
+///
+/// # Arguments
+/// * `runtime` - The runtime to use
+/// * `container_ids` - Containers to follow
+/// * `tail` - Lines of history per container before following (default 0)
+/// * `on_line` - Receives every line, from reader threads
+///
+/// # Returns
+/// - `Ok(String)` with the group ID to pass to `stop_log_group`
+/// - `Err(String)` if no IDs were given or a follower couldn't start; followers
+///   already started are stopped again
+pub fn start_log_group(
+    runtime: &Runtime,
+    container_ids: &[String],
+    tail: Option<u32>,
+    on_line: LineHandler,
+) -> Result<String, String> {
+    if container_ids.is_empty() {
+        return Err("No containers to follow".to_string());
+    }
+
+    let group_id = format!("logs-{}", NEXT_GROUP.fetch_add(1, Ordering::SeqCst));
+    for container_id in container_ids {
+        if let Err(e) = spawn_follower(runtime, &group_id, container_id, tail, Arc::clone(&on_line))
+        {
+            stop_log_group(&group_id);
+            return Err(e);
+        }
+    }
+
+    Ok(group_id)
+}
+
+/// Stops every follower of a group
+///
+/// # Returns
+/// Number of followers that were still running and got killed
+pub fn stop_log_group(group_id: &str) -> usize {
+    let handles = LOG_GROUPS
+        .lock()
+        .ok()
+        .and_then(|mut groups| groups.remove(group_id))
+        .unwrap_or_default();
+
+    handles
+        .into_iter()
+        .filter(|handle| PROCESS_TRACKER.kill(*handle))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multi_log_line_payload() {
+        let line = MultiLogLine {
+            container_id: "web".to_string(),
+            line: "GET / 200".to_string(),
+        };
+        assert_eq!(
+            serde_json::to_value(&line).unwrap(),
+            serde_json::json!({"containerId": "web", "line": "GET / 200"})
+        );
+    }
+
+    #[test]
+    fn test_build_follow_args() {
+        assert_eq!(
+            build_follow_args("web", Some(20)).unwrap(),
+            vec!["logs", "--follow", "--tail", "20", "web"]
+        );
+        assert_eq!(build_follow_args("web", None).unwrap()[3], "0");
+        assert!(build_follow_args("--since=0", None).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_stop_log_group_kills_every_follower() {
+        use std::os::unix::fs::PermissionsExt;
+        use std::time::{Duration, Instant};
+
+        // A fake runtime that prints the followed ID and then blocks like `logs --follow`
+        let script =
+            std::env::temp_dir().join(format!("harbor-master-follow-{}.sh", std::process::id()));
+        std::fs::write(
+            &script,
+            "#!/bin/sh\necho \"hello from $5\"\nexec sleep 30\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let runtime = Runtime::for_test(&script.to_string_lossy());
+
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&received);
+        let ids = vec!["web".to_string(), "db".to_string()];
+        let group = start_log_group(
+            &runtime,
+            &ids,
+            None,
+            Arc::new(move |line| sink.lock().unwrap().push(line)),
+        )
+        .unwrap();
+
+        let started = Instant::now();
+        while received.lock().unwrap().len() < 2 && started.elapsed() < Duration::from_secs(5) {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        assert_eq!(stop_log_group(&group), 2);
+        assert_eq!(stop_log_group(&group), 0);
+        std::fs::remove_file(&script).ok();
+
+        let mut lines: Vec<String> = received
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|l| format!("{}: {}", l.container_id, l.line))
+            .collect();
+        lines.sort();
+        assert_eq!(lines, vec!["db: hello from db", "web: hello from web"]);
+    }
+
+    #[test]
+    fn test_group_dropped_when_followers_exit() {
+        use std::time::{Duration, Instant};
+
+        // `true` exits straight away, like a follower whose container stopped
+        let runtime = Runtime::for_test("true");
+        let ids = vec!["web".to_string(), "db".to_string()];
+        let group = start_log_group(&runtime, &ids, None, Arc::new(|_| {})).unwrap();
+
+        let started = Instant::now();
+        while LOG_GROUPS.lock().unwrap().contains_key(&group)
+            && started.elapsed() < Duration::from_secs(5)
+        {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        assert!(!LOG_GROUPS.lock().unwrap().contains_key(&group));
+        assert_eq!(stop_log_group(&group), 0);
+    }
+}
@@ -0,0 +1,493 @@
+//! Container inspection helpers
+//!
+//! This module wraps `docker inspect` for containers and deserializes the
+//! subset of the inspect document each caller needs. Existence checks go
+//! through `runtime::inspect::resource_exists` instead.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::process::args::{run_chunked, MAX_ARGS_BYTES};
+use crate::runtime::command::runtime_command;
+use crate::types::Runtime;
+
+/// Subset of a container inspect document
+#[derive(Debug, Deserialize)]
+struct ContainerInspect {
+    #[serde(rename = "HostConfig", default)]
+    host_config: HostConfig,
+    /// ID of the image the container was created from
+    #[serde(rename = "Image", default)]
+    image: String,
+    #[serde(rename = "Config", default)]
+    config: InspectConfig,
+}
+
+/// `Config` section shared by container and image inspect documents
+#[derive(Debug, Default, Deserialize)]
+struct InspectConfig {
+    /// `KEY=VALUE` entries; Podman prints `null` when empty
+    #[serde(rename = "Env", default)]
+    env: Option<Vec<String>>,
+}
+
+/// Subset of an image inspect document
+#[derive(Debug, Deserialize)]
+struct ImageInspect {
+    #[serde(rename = "Config", default)]
+    config: InspectConfig,
+}
+
+/// Resource and logging fields of `HostConfig`
+///
+/// Docker reports unset limits as `0`; absent fields default to the same.
+#[derive(Debug, Default, Deserialize)]
+struct HostConfig {
+    #[serde(rename = "LogConfig", default)]
+    log_config: Option<InspectLogConfig>,
+    #[serde(rename = "Memory", default)]
+    memory: i64,
+    #[serde(rename = "MemorySwap", default)]
+    memory_swap: i64,
+    #[serde(rename = "NanoCpus", default)]
+    nano_cpus: i64,
+    #[serde(rename = "CpuShares", default)]
+    cpu_shares: i64,
+}
+
+/// `HostConfig.LogConfig` as printed by inspect
+///
+/// Podman prints `"Config": null` when no options are set.
+#[derive(Debug, Deserialize)]
+struct InspectLogConfig {
+    #[serde(rename = "Type", default)]
+    driver: String,
+    #[serde(rename = "Config", default)]
+    options: Option<HashMap<String, String>>,
+}
+
+/// Logging driver configured for a container
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContainerLogConfig {
+    /// Driver name, e.g. `json-file`, `journald` or `none`
+    #[serde(rename = "logDriver")]
+    pub log_driver: Option<String>,
+    /// Driver options such as `max-size`
+    #[serde(rename = "logOptions")]
+    pub log_options: HashMap<String, String>,
+    /// Message for the log viewer when logs can't be read (see `log_driver_warning`)
+    pub warning: Option<String>,
+}
+
+/// Resource limits currently applied to a container
+///
+/// Every field is `None` when the limit is unset. `memory_swap` is `-1`
+/// when swap is unlimited.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResourceLimits {
+    /// Memory limit in bytes
+    pub memory: Option<i64>,
+    /// Combined memory + swap limit in bytes
+    #[serde(rename = "memorySwap")]
+    pub memory_swap: Option<i64>,
+    /// CPU quota in units of 1e-9 CPUs
+    #[serde(rename = "nanoCpus")]
+    pub nano_cpus: Option<i64>,
+    /// Relative CPU weight
+    #[serde(rename = "cpuShares")]
+    pub cpu_shares: Option<i64>,
+}
+
+/// Maps Docker's `0` (unset) to `None`
+fn non_zero(value: i64) -> Option<i64> {
+    if value == 0 {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+/// Runs `inspect --type container <id>` and returns the single inspect object
+///
+/// # Returns
+/// - `Ok(String)` containing the raw JSON array printed by inspect
+/// - `Err(String)` with the CLI's stderr (e.g. no such container)
+async fn inspect_container_output(runtime: &Runtime, container_id: &str) -> Result<String, String> {
+    let runtime = runtime.clone();
+    let container_id = container_id.to_string();
+
+    let output = tokio::task::spawn_blocking(move || {
+        runtime_command(&runtime)
+            .args(["inspect", "--type", "container", &container_id])
+            .output()
+    })
+    .await
+    .map_err(|e| format!("Inspect task failed: {}", e))?
+    .map_err(|e| format!("Failed to execute inspect: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(stderr.trim().to_string());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Parses the first object of an inspect JSON array
+fn parse_inspect(output: &str) -> Result<ContainerInspect, String> {
+    let mut inspected: Vec<ContainerInspect> = serde_json::from_str(output.trim())
+        .map_err(|e| format!("Failed to parse inspect output: {}", e))?;
+
+    if inspected.is_empty() {
+        return Err("Inspect returned no containers".to_string());
+    }
+    Ok(inspected.remove(0))
+}
+
+/// Extracts resource limits from `inspect` output
+///
+/// # Arguments
+/// * `output` - JSON array printed by `inspect --type container <id>`
+pub fn parse_resource_limits(output: &str) -> Result<ResourceLimits, String> {
+    let host_config = parse_inspect(output)?.host_config;
+
+    Ok(ResourceLimits {
+        memory: non_zero(host_config.memory),
+        memory_swap: non_zero(host_config.memory_swap),
+        nano_cpus: non_zero(host_config.nano_cpus),
+        cpu_shares: non_zero(host_config.cpu_shares),
+    })
+}
+
+/// Extracts the logging configuration from `inspect` output
+///
+/// # Arguments
+/// * `output` - JSON array printed by `inspect --type container <id>`
+pub fn parse_log_config(output: &str) -> Result<ContainerLogConfig, String> {
+    let log_config = parse_inspect(output)?.host_config.log_config;
+
+    let mut config = match log_config {
+        Some(config) => ContainerLogConfig {
+            log_driver: Some(config.driver).filter(|d| !d.is_empty()),
+            log_options: config.options.unwrap_or_default(),
+            warning: None,
+        },
+        None => ContainerLogConfig {
+            log_driver: None,
+            log_options: HashMap::new(),
+            warning: None,
+        },
+    };
+    config.warning = log_driver_warning(&config);
+
+    Ok(config)
+}
+
+/// Explains why the log viewer will be empty for a container, if it will be
+///
+/// # Returns
+/// A user-facing message when the `none` driver is configured (so `logs`
+/// has nothing to read), `None` otherwise
+pub fn log_driver_warning(config: &ContainerLogConfig) -> Option<String> {
+    match config.log_driver.as_deref() {
+        Some("none") => Some(
+            "This container uses the 'none' logging driver, so no logs are available".to_string(),
+        ),
+        _ => None,
+    }
+}
+
+/// Reads the logging driver and options configured for a container
+///
+/// # Arguments
+/// * `runtime` - The runtime to query
+/// * `container_id` - Container ID or name
+///
+/// # Returns
+/// - `Ok(ContainerLogConfig)` with the driver and its options
+/// - `Err(String)` if the container can't be inspected
+pub async fn get_log_config(
+    runtime: &Runtime,
+    container_id: &str,
+) -> Result<ContainerLogConfig, String> {
+    let output = inspect_container_output(runtime, container_id).await?;
+    parse_log_config(&output)
+}
+
+/// Reads the resource limits currently applied to a container
+///
+/// # Arguments
+/// * `runtime` - The runtime to query
+/// * `container_id` - Container ID or name
+///
+/// # Returns
+/// - `Ok(ResourceLimits)` with unset limits as `None`
+/// - `Err(String)` if the container can't be inspected
+pub async fn get_resource_limits(
+    runtime: &Runtime,
+    container_id: &str,
+) -> Result<ResourceLimits, String> {
+    let output = inspect_container_output(runtime, container_id).await?;
+    parse_resource_limits(&output)
+}
+
+/// Splits a `KEY=VALUE` entry; a bare `KEY` has an empty value
+fn split_env(entry: &str) -> (String, String) {
+    match entry.split_once('=') {
+        Some((key, value)) => (key.to_string(), value.to_string()),
+        None => (entry.to_string(), String::new()),
+    }
+}
+
+/// Computes the environment variables a container adds or overrides
+///
+/// # Arguments
+/// * `container_env` - `Config.Env` of the container
+/// * `image_env` - `Config.Env` of its image
+///
+/// # Returns
+/// `(key, value)` pairs, in container order, whose key is missing from the
+/// image or whose value differs from the image default
+pub fn compute_env_overrides(
+    container_env: &[String],
+    image_env: &[String],
+) -> Vec<(String, String)> {
+    let defaults: HashMap<String, String> = image_env.iter().map(|e| split_env(e)).collect();
+
+    container_env
+        .iter()
+        .map(|entry| split_env(entry))
+        .filter(|(key, value)| defaults.get(key) != Some(value))
+        .collect()
+}
+
+/// Runs `image inspect <id>` and returns the image's default environment
+async fn image_env(runtime: &Runtime, image_id: &str) -> Result<Vec<String>, String> {
+    let runtime = runtime.clone();
+    let image_id = image_id.to_string();
+
+    let output = tokio::task::spawn_blocking(move || {
+        runtime_command(&runtime)
+            .args(["image", "inspect", &image_id])
+            .output()
+    })
+    .await
+    .map_err(|e| format!("Inspect task failed: {}", e))?
+    .map_err(|e| format!("Failed to execute inspect: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(stderr.trim().to_string());
+    }
+
+    let inspected: Vec<ImageInspect> = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("Failed to parse image inspect output: {}", e))?;
+    Ok(inspected
+        .into_iter()
+        .next()
+        .and_then(|image| image.config.env)
+        .unwrap_or_default())
+}
+
+/// Lists the environment variables a container sets beyond its image defaults
+///
+/// Inspects the container and then the image it was created from.
+///
+/// # Arguments
+/// * `runtime` - The runtime to query
+/// * `container_id` - Container ID or name
+///
+/// # Returns
+/// - `Ok(Vec<(String, String)>)` of added or overridden variables
+/// - `Err(String)` if either inspect fails
+pub async fn container_env_overrides(
+    runtime: &Runtime,
+    container_id: &str,
+) -> Result<Vec<(String, String)>, String> {
+    let container = parse_inspect(&inspect_container_output(runtime, container_id).await?)?;
+    let image_env = image_env(runtime, &container.image).await?;
+
+    Ok(compute_env_overrides(
+        &container.config.env.unwrap_or_default(),
+        &image_env,
+    ))
+}
+
+/// Parses `inspect --format '{{.Id}}\t{{.RestartCount}}'` lines
+pub fn parse_restart_counts(output: &str) -> HashMap<String, u32> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (id, count) = line.trim().split_once('\t')?;
+            Some((id.trim().to_string(), count.trim().parse().ok()?))
+        })
+        .collect()
+}
+
+/// Reads the restart count of every container
+///
+/// # Returns
+/// - `Ok(HashMap)` of full container ID to `RestartCount` (empty without containers)
+/// - `Err(String)` if listing or inspecting fails
+pub async fn get_restart_counts(runtime: &Runtime) -> Result<HashMap<String, u32>, String> {
+    let runtime = runtime.clone();
+
+    let output = tokio::task::spawn_blocking(move || {
+        let run = |args: &[&str], ids: &[String]| -> Result<String, String> {
+            let output = runtime_command(&runtime)
+                .args(args)
+                .args(ids)
+                .output()
+                .map_err(|e| format!("Failed to execute {}: {}", args[0], e))?;
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                return Err(stderr.trim().to_string());
+            }
+            Ok(String::from_utf8_lossy(&output.stdout).to_string())
+        };
+
+        let listed = run(&["ps", "-a", "-q", "--no-trunc"], &[])?;
+        let ids: Vec<String> = listed.split_whitespace().map(String::from).collect();
+
+        // One inspect per chunk keeps hosts with many containers under argv limits
+        run_chunked(&ids, MAX_ARGS_BYTES, |chunk| {
+            run(
+                &[
+                    "inspect",
+                    "--type",
+                    "container",
+                    "--format",
+                    "{{.Id}}\t{{.RestartCount}}",
+                ],
+                chunk,
+            )
+        })
+    })
+    .await
+    .map_err(|e| format!("Inspect task failed: {}", e))??;
+
+    Ok(parse_restart_counts(&output))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INSPECT_FIXTURE: &str = r#"[
+        {
+            "Id": "4f66ad9a0b2e",
+            "Name": "/web",
+            "HostConfig": {
+                "CpuShares": 0,
+                "Memory": 536870912,
+                "NanoCpus": 1500000000,
+                "MemorySwap": -1,
+                "LogConfig": {
+                    "Type": "json-file",
+                    "Config": {"max-size": "10m", "max-file": "3"}
+                },
+                "RestartPolicy": {"Name": "no", "MaximumRetryCount": 0}
+            }
+        }
+    ]"#;
+
+    #[test]
+    fn test_parse_resource_limits_fixture() {
+        let limits = parse_resource_limits(INSPECT_FIXTURE).unwrap();
+        assert_eq!(limits.memory, Some(536_870_912));
+        assert_eq!(limits.memory_swap, Some(-1));
+        assert_eq!(limits.nano_cpus, Some(1_500_000_000));
+        assert_eq!(limits.cpu_shares, None);
+    }
+
+    #[test]
+    fn test_parse_resource_limits_unset() {
+        let limits = parse_resource_limits(r#"[{"Id": "abc", "HostConfig": {}}]"#).unwrap();
+        assert_eq!(
+            limits,
+            ResourceLimits {
+                memory: None,
+                memory_swap: None,
+                nano_cpus: None,
+                cpu_shares: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_log_config_json_file() {
+        let config = parse_log_config(INSPECT_FIXTURE).unwrap();
+        assert_eq!(config.log_driver.as_deref(), Some("json-file"));
+        assert_eq!(
+            config.log_options.get("max-size").map(String::as_str),
+            Some("10m")
+        );
+        assert_eq!(config.log_options.len(), 2);
+        assert!(config.warning.is_none());
+    }
+
+    #[test]
+    fn test_log_driver_warning_none_driver() {
+        let config = parse_log_config(
+            r#"[{"HostConfig": {"LogConfig": {"Type": "none", "Config": null}}}]"#,
+        )
+        .unwrap();
+        assert!(config.log_options.is_empty());
+        assert!(config.warning.is_some());
+        assert_eq!(config.warning, log_driver_warning(&config));
+    }
+
+    #[test]
+    fn test_parse_resource_limits_empty_array() {
+        assert!(parse_resource_limits("[]").is_err());
+        assert!(parse_resource_limits("not json").is_err());
+    }
+
+    #[test]
+    fn test_parse_restart_counts() {
+        let counts = parse_restart_counts("abc123\t0\ndef456\t7\nbroken line\n");
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts["def456"], 7);
+    }
+
+    #[test]
+    fn test_compute_env_overrides() {
+        let image_env: Vec<String> = ["PATH=/usr/local/bin:/usr/bin", "LANG=C.UTF-8", "MODE=dev"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let container_env: Vec<String> = [
+            "PATH=/usr/local/bin:/usr/bin",
+            "LANG=C.UTF-8",
+            "MODE=prod",
+            "DATABASE_URL=postgres://db/app",
+            "EMPTY=",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+
+        assert_eq!(
+            compute_env_overrides(&container_env, &image_env),
+            vec![
+                ("MODE".to_string(), "prod".to_string()),
+                ("DATABASE_URL".to_string(), "postgres://db/app".to_string()),
+                ("EMPTY".to_string(), String::new()),
+            ]
+        );
+        assert!(compute_env_overrides(&image_env, &image_env).is_empty());
+    }
+
+    #[test]
+    fn test_parse_inspect_env_and_image() {
+        let inspected = parse_inspect(
+            r#"[{"Image": "sha256:abc", "Config": {"Env": ["MODE=prod"]}, "HostConfig": {}}]"#,
+        )
+        .unwrap();
+        assert_eq!(inspected.image, "sha256:abc");
+        assert_eq!(inspected.config.env, Some(vec!["MODE=prod".to_string()]));
+
+        let podman = parse_inspect(r#"[{"Config": {"Env": null}}]"#).unwrap();
+        assert_eq!(podman.config.env, None);
+    }
+}
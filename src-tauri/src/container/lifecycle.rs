@@ -0,0 +1,111 @@
+//! Container lifecycle operations
+//!
+//! Names are checked against Docker's naming rule before spawning the CLI,
+//! since the runtime's own rejection message is cryptic.
+
+use regex::Regex;
+
+use crate::runtime::command::runtime_command;
+use crate::runtime::inspect::validate_container_id;
+use crate::types::Runtime;
+
+lazy_static::lazy_static! {
+    static ref CONTAINER_NAME_RE: Regex = Regex::new(r"^[a-zA-Z0-9][a-zA-Z0-9_.-]+$").unwrap();
+}
+
+/// Validates a container name against `[a-zA-Z0-9][a-zA-Z0-9_.-]+`
+///
+/// # Returns
+/// - `Ok(())` if the runtime will accept the name
+/// - `Err(String)` explaining the rule otherwise
+pub fn validate_container_name(name: &str) -> Result<(), String> {
+    if CONTAINER_NAME_RE.is_match(name) {
+        return Ok(());
+    }
+    Err(format!(
+        "Invalid container name '{}': names must be at least 2 characters, start with a letter or digit, and contain only letters, digits, '_', '.' or '-'",
+        name
+    ))
+}
+
+/// Renames a container
+///
+/// # Arguments
+/// * `runtime` - The runtime to use
+/// * `container_id` - Container ID or current name
+/// * `new_name` - The new name, validated before the CLI runs
+///
+/// # Returns
+/// - `Ok(())` once renamed
+/// - `Err(String)` for an invalid ID or name, or the CLI's stderr (e.g. name already in use)
+pub async fn rename_container(
+    runtime: &Runtime,
+    container_id: &str,
+    new_name: &str,
+) -> Result<(), String> {
+    validate_container_id(container_id)?;
+    validate_container_name(new_name)?;
+
+    let runtime = runtime.clone();
+    let container_id = container_id.to_string();
+    let new_name = new_name.to_string();
+
+    let output = tokio::task::spawn_blocking(move || {
+        runtime_command(&runtime)
+            .args(["rename", &container_id, &new_name])
+            .output()
+    })
+    .await
+    .map_err(|e| format!("Rename task failed: {}", e))?
+    .map_err(|e| format!("Failed to execute rename: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(stderr.trim().to_string());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_container_name() {
+        assert!(validate_container_name("web").is_ok());
+        assert!(validate_container_name("my_app.v2-1").is_ok());
+        assert!(validate_container_name("0db").is_ok());
+
+        assert!(validate_container_name("").is_err());
+        assert!(validate_container_name("a").is_err());
+        assert!(validate_container_name("-web").is_err());
+        assert!(validate_container_name("_web").is_err());
+        assert!(validate_container_name("my app").is_err());
+        assert!(validate_container_name("web/1").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rename_rejects_invalid_name_before_spawning() {
+        // The runtime path doesn't exist, so only validation can produce this error
+        let runtime = Runtime::for_test("/nonexistent/docker");
+        let err = rename_container(&runtime, "abc", "bad name")
+            .await
+            .unwrap_err();
+        assert!(err.starts_with("Invalid container name"));
+
+        let err = rename_container(&runtime, "--help", "web")
+            .await
+            .unwrap_err();
+        assert_eq!(err, "Invalid container ID: --help");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_rename_surfaces_cli_failure() {
+        let ok = Runtime::for_test("true");
+        assert!(rename_container(&ok, "abc", "web").await.is_ok());
+
+        let failing = Runtime::for_test("false");
+        assert!(rename_container(&failing, "abc", "web").await.is_err());
+    }
+}
@@ -0,0 +1,447 @@
+//! Container listing
+//!
+//! `list_containers_raw` is an escape hatch for power users: it passes a
+//! user-supplied `--format` template straight to `docker ps` and returns the
+//! rendered lines untouched, so advanced UIs can show fields we don't model.
+//! `list_containers_brief` is the light query behind fast pickers: just ID,
+//! name and state from a tab-separated template instead of JSON.
+//! Label selectors narrow listings (and batch operations) to a group of
+//! containers such as everything labeled `app=web`.
+//! `containers_health_summary` tallies states and health checks for the
+//! overview badge.
+
+use serde::{Deserialize, Serialize};
+
+use crate::runtime::command::runtime_command;
+use crate::types::Runtime;
+
+/// Validates that a format string looks like a Go template
+///
+/// No shell is involved, so metacharacters are harmless; this only rejects
+/// strings that can't be a meaningful template (empty, no `{{ }}` action,
+/// unbalanced braces, or multi-line input).
+///
+/// # Returns
+/// - `Ok(())` if the template is acceptable
+/// - `Err(String)` describing why it was rejected
+pub fn validate_format(format: &str) -> Result<(), String> {
+    if format.trim().is_empty() {
+        return Err("Format template is empty".to_string());
+    }
+    if format.contains(['\n', '\r', '\0']) {
+        return Err("Format template must be a single line".to_string());
+    }
+    if format.matches("{{").count() != format.matches("}}").count() {
+        return Err("Format template has unbalanced braces".to_string());
+    }
+    if !format.contains("{{") {
+        return Err("Format template must contain at least one {{ }} action".to_string());
+    }
+    Ok(())
+}
+
+/// Builds the `ps` arguments for a raw listing
+fn build_list_args(format: &str) -> Vec<String> {
+    vec!["ps".to_string(), "--format".to_string(), format.to_string()]
+}
+
+/// Lists containers rendered with a custom `--format` template
+///
+/// # Arguments
+/// * `runtime` - The runtime to query
+/// * `format` - Go template passed verbatim to `ps --format`
+///
+/// # Returns
+/// - `Ok(Vec<String>)` with one rendered line per container
+/// - `Err(String)` if the template is rejected or the command fails
+pub async fn list_containers_raw(runtime: &Runtime, format: &str) -> Result<Vec<String>, String> {
+    validate_format(format)?;
+
+    let runtime = runtime.clone();
+    let args = build_list_args(format);
+
+    let output =
+        tokio::task::spawn_blocking(move || runtime_command(&runtime).args(&args).output())
+            .await
+            .map_err(|e| format!("List task failed: {}", e))?
+            .map_err(|e| format!("Failed to execute ps: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("ps failed: {}", stderr.trim()));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(String::from)
+        .collect())
+}
+
+/// Minimal container entry for fast pickers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContainerBrief {
+    pub id: String,
+    pub name: String,
+    pub state: String,
+}
+
+/// Builds the `ps` arguments for a brief listing
+fn build_brief_args(all: bool) -> Vec<String> {
+    let mut args = vec!["ps".to_string()];
+    if all {
+        args.push("-a".to_string());
+    }
+    args.push("--format".to_string());
+    args.push("{{.ID}}\t{{.Names}}\t{{.State}}".to_string());
+    args
+}
+
+/// Parses tab-separated `ID\tNames\tState` lines, skipping malformed ones
+fn parse_brief_lines(output: &str) -> Vec<ContainerBrief> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.trim().splitn(3, '\t');
+            let id = fields.next()?.trim();
+            let name = fields.next()?.trim();
+            let state = fields.next()?.trim();
+            if id.is_empty() {
+                return None;
+            }
+            Some(ContainerBrief {
+                id: id.to_string(),
+                name: name.to_string(),
+                state: state.to_lowercase(),
+            })
+        })
+        .collect()
+}
+
+/// Lists containers with only their ID, name and state
+///
+/// Uses a tab-separated template rather than JSON to keep the query light.
+///
+/// # Arguments
+/// * `runtime` - The runtime to query
+/// * `all` - Include stopped containers (`ps -a`)
+///
+/// # Returns
+/// - `Ok(Vec<ContainerBrief>)` (may be empty)
+/// - `Err(String)` if the command fails
+pub async fn list_containers_brief(
+    runtime: &Runtime,
+    all: bool,
+) -> Result<Vec<ContainerBrief>, String> {
+    let runtime = runtime.clone();
+    let args = build_brief_args(all);
+
+    let output =
+        tokio::task::spawn_blocking(move || runtime_command(&runtime).args(&args).output())
+            .await
+            .map_err(|e| format!("List task failed: {}", e))?
+            .map_err(|e| format!("Failed to execute ps: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("ps failed: {}", stderr.trim()));
+    }
+
+    Ok(parse_brief_lines(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Container counts by state and health for the overview badge
+///
+/// States are exclusive; `unhealthy` counts containers whose health check
+/// fails regardless of state, so an unhealthy running container is counted
+/// in both `running` and `unhealthy`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HealthSummary {
+    pub running: usize,
+    /// Exited, created and dead containers
+    pub stopped: usize,
+    pub paused: usize,
+    pub unhealthy: usize,
+    pub restarting: usize,
+}
+
+/// Extracts the health check result from a `ps` status column
+///
+/// Docker and Podman append it in parentheses, e.g.
+/// `Up 2 hours (unhealthy)` or `Up 5 seconds (health: starting)`.
+///
+/// # Returns
+/// `Some("healthy" | "unhealthy" | "starting")`, or `None` if the container
+/// has no health check
+pub fn parse_health(status: &str) -> Option<&'static str> {
+    let status = status.to_lowercase();
+    if status.contains("(unhealthy)") {
+        Some("unhealthy")
+    } else if status.contains("(healthy)") {
+        Some("healthy")
+    } else if status.contains("(health: starting)") || status.contains("(starting)") {
+        Some("starting")
+    } else {
+        None
+    }
+}
+
+/// Tallies tab-separated `State\tStatus` lines into a summary
+fn tally_health(output: &str) -> HealthSummary {
+    let mut summary = HealthSummary::default();
+
+    for line in output.lines().filter(|line| !line.trim().is_empty()) {
+        let (state, status) = line.trim().split_once('\t').unwrap_or((line.trim(), ""));
+        match state.trim().to_lowercase().as_str() {
+            "running" => summary.running += 1,
+            "paused" => summary.paused += 1,
+            "restarting" => summary.restarting += 1,
+            "exited" | "created" | "dead" | "stopped" => summary.stopped += 1,
+            _ => {}
+        }
+        if parse_health(status) == Some("unhealthy") {
+            summary.unhealthy += 1;
+        }
+    }
+    summary
+}
+
+/// Counts all containers by state and failing health checks
+///
+/// # Arguments
+/// * `runtime` - The runtime to query
+///
+/// # Returns
+/// - `Ok(HealthSummary)` across running and stopped containers
+/// - `Err(String)` if the command fails
+pub async fn containers_health_summary(runtime: &Runtime) -> Result<HealthSummary, String> {
+    let runtime = runtime.clone();
+    let args = [
+        "ps".to_string(),
+        "-a".to_string(),
+        "--format".to_string(),
+        "{{.State}}\t{{.Status}}".to_string(),
+    ];
+
+    let output =
+        tokio::task::spawn_blocking(move || runtime_command(&runtime).args(&args).output())
+            .await
+            .map_err(|e| format!("List task failed: {}", e))?
+            .map_err(|e| format!("Failed to execute ps: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("ps failed: {}", stderr.trim()));
+    }
+
+    Ok(tally_health(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Selects containers by label, optionally with a specific value
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LabelSelector {
+    pub key: String,
+    /// Match any value when `None`
+    pub value: Option<String>,
+}
+
+/// Builds the `--filter label=...` arguments for a selector
+///
+/// # Returns
+/// - `Ok(Vec<String>)` with `["--filter", "label=key[=value]"]`
+/// - `Err(String)` if the key is empty or contains `=`
+pub fn label_filter_args(selector: &LabelSelector) -> Result<Vec<String>, String> {
+    let key = selector.key.trim();
+    if key.is_empty() || key.contains('=') {
+        return Err(format!("Invalid label key: {}", selector.key));
+    }
+
+    let filter = match &selector.value {
+        Some(value) => format!("label={}={}", key, value),
+        None => format!("label={}", key),
+    };
+    Ok(vec!["--filter".to_string(), filter])
+}
+
+/// Lists the IDs of all containers (running or not) carrying a label
+///
+/// # Arguments
+/// * `runtime` - The runtime to query
+/// * `selector` - Label key and optional value to match
+///
+/// # Returns
+/// - `Ok(Vec<String>)` of container IDs (may be empty)
+/// - `Err(String)` if the selector is invalid or the command fails
+pub async fn containers_by_label(
+    runtime: &Runtime,
+    selector: &LabelSelector,
+) -> Result<Vec<String>, String> {
+    let mut args = vec!["ps".to_string(), "-a".to_string(), "-q".to_string()];
+    args.extend(label_filter_args(selector)?);
+
+    let runtime = runtime.clone();
+    let output =
+        tokio::task::spawn_blocking(move || runtime_command(&runtime).args(&args).output())
+            .await
+            .map_err(|e| format!("List task failed: {}", e))?
+            .map_err(|e| format!("Failed to execute ps: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("ps failed: {}", stderr.trim()));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(String::from)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_format() {
+        assert!(validate_format("{{.ID}}\t{{.Names}}").is_ok());
+        assert!(validate_format("{{json .}}").is_ok());
+        assert!(validate_format("").is_err());
+        assert!(validate_format(".ID").is_err());
+        assert!(validate_format("{{.ID}").is_err());
+        assert!(validate_format("{{.ID}}\n{{.Names}}").is_err());
+    }
+
+    #[test]
+    fn test_build_list_args_passes_format_verbatim() {
+        let args = build_list_args("{{.ID}} | {{.Label \"com.example\"}}");
+        assert_eq!(
+            args,
+            vec!["ps", "--format", "{{.ID}} | {{.Label \"com.example\"}}"]
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_list_containers_raw_passthrough() {
+        // `echo` prints its arguments, standing in for a runtime rendering the template
+        let runtime = Runtime::for_test("echo");
+        let lines = list_containers_raw(&runtime, "{{.ID}};{{.Ports}}")
+            .await
+            .unwrap();
+        assert_eq!(lines, vec!["ps --format {{.ID}};{{.Ports}}"]);
+    }
+
+    #[test]
+    fn test_parse_brief_lines() {
+        let fixture = "a1b2c3\tweb\trunning\nd4e5f6\tdb\tExited\n";
+        let containers = parse_brief_lines(fixture);
+
+        assert_eq!(
+            containers,
+            vec![
+                ContainerBrief {
+                    id: "a1b2c3".to_string(),
+                    name: "web".to_string(),
+                    state: "running".to_string(),
+                },
+                ContainerBrief {
+                    id: "d4e5f6".to_string(),
+                    name: "db".to_string(),
+                    state: "exited".to_string(),
+                },
+            ]
+        );
+        assert!(parse_brief_lines("garbage\n\n").is_empty());
+    }
+
+    #[test]
+    fn test_build_brief_args() {
+        assert_eq!(
+            build_brief_args(true),
+            vec!["ps", "-a", "--format", "{{.ID}}\t{{.Names}}\t{{.State}}"]
+        );
+        assert_eq!(build_brief_args(false).len(), 3);
+    }
+
+    #[test]
+    fn test_label_filter_args() {
+        let selector = LabelSelector {
+            key: "app".to_string(),
+            value: Some("web".to_string()),
+        };
+        assert_eq!(
+            label_filter_args(&selector).unwrap(),
+            vec!["--filter", "label=app=web"]
+        );
+
+        let key_only = LabelSelector {
+            key: "com.example.managed".to_string(),
+            value: None,
+        };
+        assert_eq!(
+            label_filter_args(&key_only).unwrap(),
+            vec!["--filter", "label=com.example.managed"]
+        );
+
+        let invalid = LabelSelector {
+            key: "app=web".to_string(),
+            value: None,
+        };
+        assert!(label_filter_args(&invalid).is_err());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_containers_by_label_passes_filter() {
+        // `echo` prints its arguments, standing in for the runtime's ID list
+        let runtime = Runtime::for_test("echo");
+        let selector = LabelSelector {
+            key: "app".to_string(),
+            value: Some("web".to_string()),
+        };
+        let ids = containers_by_label(&runtime, &selector).await.unwrap();
+        assert_eq!(ids, vec!["ps -a -q --filter label=app=web"]);
+    }
+
+    #[test]
+    fn test_parse_health() {
+        assert_eq!(parse_health("Up 2 hours (unhealthy)"), Some("unhealthy"));
+        assert_eq!(parse_health("Up 3 minutes (healthy)"), Some("healthy"));
+        assert_eq!(
+            parse_health("Up 5 seconds (health: starting)"),
+            Some("starting")
+        );
+        assert_eq!(parse_health("Up 2 hours"), None);
+    }
+
+    #[test]
+    fn test_tally_health_mixed() {
+        let fixture = "running\tUp 2 hours (healthy)\n\
+                       running\tUp 1 hour (unhealthy)\n\
+                       running\tUp 5 minutes\n\
+                       exited\tExited (0) 3 days ago\n\
+                       created\tCreated\n\
+                       paused\tUp 2 days (Paused)\n\
+                       restarting\tRestarting (1) 4 seconds ago\n\
+                       exited\tExited (137) 1 hour ago (unhealthy)\n\n";
+
+        assert_eq!(
+            tally_health(fixture),
+            HealthSummary {
+                running: 3,
+                stopped: 3,
+                paused: 1,
+                unhealthy: 2,
+                restarting: 1,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_containers_raw_rejects_invalid_format() {
+        let runtime = Runtime::for_test("/nonexistent/docker");
+        assert!(list_containers_raw(&runtime, "no template").await.is_err());
+    }
+}
@@ -0,0 +1,561 @@
+//! Container log export
+//!
+//! Writes the output of `docker logs` to a file so it can be attached to a
+//! ticket. Stdout and stderr share one pipe, so lines keep the order the
+//! runtime wrote them in. Optional byte/line caps stop reading early so a
+//! gigantic log can't exhaust memory or disk.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use crate::runtime::command::runtime_command;
+use crate::runtime::inspect::validate_container_id;
+use crate::types::Runtime;
+
+/// Marker appended when a cap cut the logs short
+const TRUNCATION_MARKER: &str = "... [truncated]\n";
+
+/// Options for a one-shot `logs` call
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LogOptions {
+    /// Only include the last N lines
+    pub tail: Option<u32>,
+    /// Prefix each line with its timestamp
+    #[serde(default)]
+    pub timestamps: bool,
+    /// Start of the time window (RFC3339, Unix timestamp or relative like `10m`)
+    pub since: Option<String>,
+    /// End of the time window, same formats as `since`
+    pub until: Option<String>,
+    /// Include log driver metadata (`--details`)
+    #[serde(default)]
+    pub details: bool,
+    /// Stop reading after this many bytes
+    #[serde(rename = "maxBytes")]
+    pub max_bytes: Option<u64>,
+    /// Stop reading after this many lines
+    #[serde(rename = "maxLines")]
+    pub max_lines: Option<u32>,
+}
+
+/// Outcome of a log export
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogExport {
+    /// Bytes written, including the truncation marker
+    pub bytes: u64,
+    /// Whether a byte or line cap was hit
+    pub truncated: bool,
+}
+
+/// Copies lines from `reader` to `writer` until EOF or a cap is hit
+///
+/// When a cap is hit the partial output is followed by `TRUNCATION_MARKER`
+/// and the rest of the input is left unread.
+///
+/// # Arguments
+/// * `max_bytes` - Stop after this many bytes of log output
+/// * `max_lines` - Stop after this many lines
+///
+/// # Returns
+/// Bytes written and whether the output was truncated
+fn copy_capped<R: BufRead, W: Write>(
+    mut reader: R,
+    writer: &mut W,
+    max_bytes: Option<u64>,
+    max_lines: Option<u32>,
+) -> std::io::Result<(u64, bool)> {
+    let mut written: u64 = 0;
+    let mut lines: u32 = 0;
+    let mut line = Vec::new();
+    let mut partial_line = false;
+
+    loop {
+        line.clear();
+        if reader.read_until(b'\n', &mut line)? == 0 {
+            return Ok((written, false));
+        }
+
+        if max_lines.is_some_and(|max| lines >= max) {
+            break;
+        }
+
+        let remaining = max_bytes.map(|max| max.saturating_sub(written));
+        if let Some(remaining) = remaining.filter(|remaining| line.len() as u64 > *remaining) {
+            if remaining > 0 {
+                writer.write_all(&line[..remaining as usize])?;
+                written += remaining;
+                partial_line = true;
+            }
+            break;
+        }
+
+        writer.write_all(&line)?;
+        written += line.len() as u64;
+        lines += 1;
+    }
+
+    // Keep the marker on its own line after a partial line
+    if partial_line {
+        writer.write_all(b"\n")?;
+        written += 1;
+    }
+    writer.write_all(TRUNCATION_MARKER.as_bytes())?;
+    written += TRUNCATION_MARKER.len() as u64;
+    Ok((written, true))
+}
+
+/// Parses a go-style duration such as `90s`, `10m` or `1h30m`
+///
+/// # Returns
+/// - `Ok(Some(Duration))` for a valid duration
+/// - `Ok(None)` if the value isn't a duration
+/// - `Err(String)` if the duration is too large to represent
+fn parse_relative_duration(value: &str) -> Result<Option<Duration>, String> {
+    let overflow = || format!("Duration out of range: {}", value);
+    let mut total = Duration::zero();
+    let mut rest = value;
+
+    while !rest.is_empty() {
+        let Some(digits) = rest.find(|c: char| !c.is_ascii_digit()) else {
+            return Ok(None);
+        };
+        let Ok(amount) = rest[..digits].parse::<i64>() else {
+            return Ok(None);
+        };
+        rest = &rest[digits..];
+
+        let unit_len = rest
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let unit = match &rest[..unit_len] {
+            "ms" => Duration::try_milliseconds(amount),
+            "s" => Duration::try_seconds(amount),
+            "m" => Duration::try_minutes(amount),
+            "h" => Duration::try_hours(amount),
+            _ => return Ok(None),
+        };
+        total = unit
+            .and_then(|unit| total.checked_add(&unit))
+            .ok_or_else(overflow)?;
+        rest = &rest[unit_len..];
+    }
+
+    Ok(Some(total))
+}
+
+/// Resolves a `--since`/`--until` value to an absolute time
+///
+/// Supports RFC3339 timestamps, Unix timestamps (optionally fractional) and
+/// relative durations counted back from `now`. Other formats the CLI accepts
+/// return `Ok(None)` and are left to the CLI to interpret.
+///
+/// # Returns
+/// - `Err(String)` if a relative duration reaches back past the earliest
+///   representable time
+fn parse_log_time(value: &str, now: DateTime<Utc>) -> Result<Option<DateTime<Utc>>, String> {
+    let value = value.trim();
+
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Ok(Some(time.with_timezone(&Utc)));
+    }
+    if value.chars().all(|c| c.is_ascii_digit() || c == '.') {
+        let Ok(seconds) = value.parse::<f64>() else {
+            return Ok(None);
+        };
+        return Ok(DateTime::from_timestamp_millis((seconds * 1000.0) as i64));
+    }
+    match parse_relative_duration(value)? {
+        Some(duration) => now
+            .checked_sub_signed(duration)
+            .map(Some)
+            .ok_or_else(|| format!("Duration out of range: {}", value)),
+        None => Ok(None),
+    }
+}
+
+/// Checks that `since` is before `until` when both can be resolved
+fn validate_time_window(since: Option<&str>, until: Option<&str>) -> Result<(), String> {
+    let now = Utc::now();
+    let start = since.map(|since| parse_log_time(since, now)).transpose()?;
+    let end = until.map(|until| parse_log_time(until, now)).transpose()?;
+
+    if let (Some(Some(start)), Some(Some(end))) = (start, end) {
+        if start >= end {
+            return Err(format!(
+                "since ({}) must be before until ({})",
+                since.unwrap_or_default(),
+                until.unwrap_or_default()
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Builds the `logs` arguments for a one-shot read
+///
+/// # Arguments
+/// * `container_id` - Container ID or name
+/// * `options` - Tail, timestamp, time window and detail options
+///
+/// # Returns
+/// - `Ok(Vec<String>)` with the full argument list
+/// - `Err(String)` if the container ID is invalid or `since` is not before `until`
+fn build_logs_args(container_id: &str, options: &LogOptions) -> Result<Vec<String>, String> {
+    validate_container_id(container_id)?;
+    validate_time_window(options.since.as_deref(), options.until.as_deref())?;
+
+    let mut args = vec!["logs".to_string()];
+
+    if let Some(tail) = options.tail {
+        args.push("--tail".to_string());
+        args.push(tail.to_string());
+    }
+    if options.timestamps {
+        args.push("--timestamps".to_string());
+    }
+    if let Some(since) = &options.since {
+        args.push("--since".to_string());
+        args.push(since.clone());
+    }
+    if let Some(until) = &options.until {
+        args.push("--until".to_string());
+        args.push(until.clone());
+    }
+    if options.details {
+        args.push("--details".to_string());
+    }
+
+    args.push(container_id.to_string());
+    Ok(args)
+}
+
+/// Checks that the output file can be created in an existing directory
+fn validate_output_path(output_path: &Path) -> Result<(), String> {
+    if output_path.is_dir() {
+        return Err(format!("{} is a directory", output_path.display()));
+    }
+
+    let parent = match output_path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    if !parent.is_dir() {
+        return Err(format!(
+            "Output directory does not exist: {}",
+            parent.display()
+        ));
+    }
+    Ok(())
+}
+
+/// Reads the CLI's error message from a failed export
+///
+/// stderr shares the file with stdout, so the error is its last line.
+fn last_line_of(path: &Path) -> Option<String> {
+    let contents = std::fs::read(path).ok()?;
+    String::from_utf8_lossy(&contents)
+        .lines()
+        .map(str::trim)
+        .rfind(|line| !line.is_empty())
+        .map(String::from)
+}
+
+/// Exports a container's logs to a file
+///
+/// If `logs` fails (e.g. no such container) the partial file, which would
+/// only hold the CLI's error, is removed.
+///
+/// # Arguments
+/// * `runtime` - The runtime to query
+/// * `container_id` - Container ID or name
+/// * `output_path` - File to write (created or truncated)
+/// * `options` - `logs` flags plus optional byte/line caps
+///
+/// # Returns
+/// - `Ok(LogExport)` with the number of bytes written and whether a cap was hit
+/// - `Err(String)` if the options or path are invalid, or `logs` fails
+pub async fn export_logs(
+    runtime: &Runtime,
+    container_id: &str,
+    output_path: &str,
+    options: &LogOptions,
+) -> Result<LogExport, String> {
+    let output_path = PathBuf::from(output_path);
+    validate_output_path(&output_path)?;
+
+    let runtime = runtime.clone();
+    let args = build_logs_args(container_id, options)?;
+    let (max_bytes, max_lines) = (options.max_bytes, options.max_lines);
+
+    tokio::task::spawn_blocking(move || {
+        let (reader, writer) =
+            std::io::pipe().map_err(|e| format!("Failed to create pipe: {}", e))?;
+        let stderr_writer = writer
+            .try_clone()
+            .map_err(|e| format!("Failed to create pipe: {}", e))?;
+
+        // The `Command` (and its pipe ends) is dropped at the end of this
+        // statement, so the reader sees EOF once the child exits
+        let mut child = runtime_command(&runtime)
+            .args(&args)
+            .stdout(writer)
+            .stderr(stderr_writer)
+            .spawn()
+            .map_err(|e| format!("Failed to execute logs: {}", e))?;
+
+        let file = File::create(&output_path)
+            .map_err(|e| format!("Failed to create {}: {}", output_path.display(), e))?;
+        let mut file = BufWriter::new(file);
+
+        let copied = copy_capped(BufReader::new(reader), &mut file, max_bytes, max_lines)
+            .and_then(|copied| file.flush().map(|_| copied));
+        let (bytes, truncated) = match copied {
+            Ok(copied) => copied,
+            Err(e) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                let _ = std::fs::remove_file(&output_path);
+                return Err(format!("Failed to write {}: {}", output_path.display(), e));
+            }
+        };
+
+        if truncated {
+            // Nothing more will be read, so don't wait for the rest of the log
+            let _ = child.kill();
+            let _ = child.wait();
+        } else {
+            let status = child
+                .wait()
+                .map_err(|e| format!("Failed to wait for logs: {}", e))?;
+            if !status.success() {
+                let message = last_line_of(&output_path)
+                    .unwrap_or_else(|| format!("logs exited with {}", status));
+                let _ = std::fs::remove_file(&output_path);
+                return Err(message);
+            }
+        }
+
+        Ok(LogExport { bytes, truncated })
+    })
+    .await
+    .map_err(|e| format!("Export task failed: {}", e))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn test_build_logs_args() {
+        assert_eq!(
+            build_logs_args("web", &LogOptions::default()).unwrap(),
+            vec!["logs", "web"]
+        );
+        assert!(build_logs_args("--follow", &LogOptions::default()).is_err());
+
+        let options = LogOptions {
+            tail: Some(100),
+            timestamps: true,
+            ..LogOptions::default()
+        };
+        assert_eq!(
+            build_logs_args("web", &options).unwrap(),
+            vec!["logs", "--tail", "100", "--timestamps", "web"]
+        );
+    }
+
+    #[test]
+    fn test_build_logs_args_time_window_and_details() {
+        let options = LogOptions {
+            since: Some("2024-01-01T00:00:00Z".to_string()),
+            until: Some("2024-01-01T12:00:00Z".to_string()),
+            details: true,
+            ..LogOptions::default()
+        };
+        assert_eq!(
+            build_logs_args("web", &options).unwrap(),
+            vec![
+                "logs",
+                "--since",
+                "2024-01-01T00:00:00Z",
+                "--until",
+                "2024-01-01T12:00:00Z",
+                "--details",
+                "web"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_logs_args_rejects_since_after_until() {
+        let options = LogOptions {
+            since: Some("2024-01-02T00:00:00Z".to_string()),
+            until: Some("2024-01-01T00:00:00Z".to_string()),
+            ..LogOptions::default()
+        };
+        assert!(build_logs_args("web", &options)
+            .unwrap_err()
+            .contains("must be before until"));
+
+        // Relative values count back from now: 10m ago is after 1h ago
+        let options = LogOptions {
+            since: Some("10m".to_string()),
+            until: Some("1h".to_string()),
+            ..LogOptions::default()
+        };
+        assert!(build_logs_args("web", &options).is_err());
+    }
+
+    #[test]
+    fn test_parse_log_time_formats() {
+        let now = Utc::now();
+        assert_eq!(
+            parse_log_time("1700000000", now)
+                .unwrap()
+                .unwrap()
+                .timestamp(),
+            1_700_000_000
+        );
+        assert_eq!(
+            parse_log_time("1h30m", now).unwrap().unwrap(),
+            now - Duration::minutes(90)
+        );
+        assert!(parse_log_time("yesterday", now).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_parse_log_time_overflow_is_an_error() {
+        let now = Utc::now();
+        assert!(parse_relative_duration("9999999999999h").is_err());
+        assert!(parse_relative_duration("9223372036854775807ms1ms").is_err());
+        assert!(parse_log_time("9999999999999h", now).is_err());
+        // Representable as a duration, but not as a time that far back
+        assert!(parse_log_time("2562047788h", now).is_err());
+
+        let options = LogOptions {
+            since: Some("9999999999999h".to_string()),
+            ..LogOptions::default()
+        };
+        assert!(build_logs_args("web", &options).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_export_logs_missing_directory() {
+        let runtime = Runtime::for_test("/nonexistent/docker");
+        let result = export_logs(
+            &runtime,
+            "web",
+            "/nonexistent/dir/web.log",
+            &LogOptions::default(),
+        )
+        .await;
+        assert!(result
+            .unwrap_err()
+            .starts_with("Output directory does not exist"));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_export_logs_writes_file() {
+        // `echo` prints its arguments, standing in for `docker logs` output
+        let runtime = Runtime::for_test("echo");
+        let output_path =
+            std::env::temp_dir().join(format!("harbor-master-export-{}.log", std::process::id()));
+        let output_str = output_path.to_str().unwrap();
+
+        let options = LogOptions {
+            tail: Some(5),
+            timestamps: true,
+            ..LogOptions::default()
+        };
+        let export = export_logs(&runtime, "web", output_str, &options)
+            .await
+            .unwrap();
+        let contents = std::fs::read_to_string(&output_path).unwrap();
+        std::fs::remove_file(&output_path).ok();
+
+        assert_eq!(contents, "logs --tail 5 --timestamps web\n");
+        assert_eq!(export.bytes, contents.len() as u64);
+        assert!(!export.truncated);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_export_logs_failure_removes_file() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // A fake runtime that fails like `docker logs` for an unknown container
+        let script = std::env::temp_dir().join(format!(
+            "harbor-master-export-fail-{}.sh",
+            std::process::id()
+        ));
+        std::fs::write(
+            &script,
+            "#!/bin/sh\necho 'Error response from daemon: No such container: web' >&2\nexit 1\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let runtime = Runtime::for_test(script.to_str().unwrap());
+        let output_path = std::env::temp_dir().join(format!(
+            "harbor-master-export-fail-{}.log",
+            std::process::id()
+        ));
+
+        let result = export_logs(
+            &runtime,
+            "web",
+            output_path.to_str().unwrap(),
+            &LogOptions::default(),
+        )
+        .await;
+        std::fs::remove_file(&script).ok();
+
+        assert_eq!(
+            result.unwrap_err(),
+            "Error response from daemon: No such container: web"
+        );
+        assert!(!output_path.exists());
+    }
+
+    const LOG_FIXTURE: &str = "line one\nline two\nline three\nline four\n";
+
+    #[test]
+    fn test_copy_capped_line_cap_truncates() {
+        let mut out = Vec::new();
+        let (bytes, truncated) =
+            copy_capped(LOG_FIXTURE.as_bytes(), &mut out, None, Some(2)).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(truncated);
+        assert_eq!(out, "line one\nline two\n... [truncated]\n");
+        assert_eq!(bytes, out.len() as u64);
+    }
+
+    #[test]
+    fn test_copy_capped_byte_cap_truncates() {
+        let mut out = Vec::new();
+        let (_, truncated) = copy_capped(LOG_FIXTURE.as_bytes(), &mut out, Some(13), None).unwrap();
+
+        assert!(truncated);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "line one\nline\n... [truncated]\n"
+        );
+    }
+
+    #[test]
+    fn test_copy_capped_under_cap() {
+        let mut out = Vec::new();
+        let (bytes, truncated) =
+            copy_capped(LOG_FIXTURE.as_bytes(), &mut out, Some(1024), Some(4)).unwrap();
+
+        assert!(!truncated);
+        assert_eq!(out, LOG_FIXTURE.as_bytes());
+        assert_eq!(bytes, LOG_FIXTURE.len() as u64);
+    }
+}
@@ -0,0 +1,12 @@
+// Container management operations
+
+pub mod batch;
+pub mod exec;
+pub mod follow;
+pub mod inspect;
+pub mod lifecycle;
+pub mod list;
+pub mod logs;
+pub mod ports;
+pub mod run;
+pub mod stats;
@@ -0,0 +1,141 @@
+//! Container port mappings
+//!
+//! Uses `docker port <id>`, which prints one `80/tcp -> 0.0.0.0:8080` line
+//! per published binding. That is cheaper and more reliable than parsing the
+//! human-readable ports column of `docker ps`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::runtime::command::runtime_command;
+use crate::runtime::inspect::validate_container_id;
+use crate::types::Runtime;
+
+/// A published container port
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PortBinding {
+    #[serde(rename = "containerPort")]
+    pub container_port: u16,
+    /// `tcp`, `udp` or `sctp`
+    pub protocol: String,
+    /// Host address without IPv6 brackets, e.g. `0.0.0.0` or `::`
+    #[serde(rename = "hostIp")]
+    pub host_ip: String,
+    #[serde(rename = "hostPort")]
+    pub host_port: u16,
+}
+
+/// Parses a single `docker port` line
+///
+/// Accepts `80/tcp -> 0.0.0.0:8080`, `80/tcp -> [::]:8080` and the older
+/// `80/tcp -> :::8080` form.
+fn parse_port_line(line: &str) -> Option<PortBinding> {
+    let (container, host) = line.trim().split_once(" -> ")?;
+
+    let (container_port, protocol) = match container.split_once('/') {
+        Some((port, protocol)) => (port, protocol),
+        None => (container, "tcp"),
+    };
+    let (host_ip, host_port) = host.trim().rsplit_once(':')?;
+    let host_ip = host_ip
+        .strip_prefix('[')
+        .and_then(|ip| ip.strip_suffix(']'))
+        .unwrap_or(host_ip);
+
+    Some(PortBinding {
+        container_port: container_port.parse().ok()?,
+        protocol: protocol.to_string(),
+        host_ip: host_ip.to_string(),
+        host_port: host_port.parse().ok()?,
+    })
+}
+
+/// Parses the output of `docker port <id>`
+///
+/// Lines that don't match the expected format are skipped.
+pub fn parse_port_mappings(output: &str) -> Vec<PortBinding> {
+    output.lines().filter_map(parse_port_line).collect()
+}
+
+/// Lists the published ports of a container
+///
+/// # Arguments
+/// * `runtime` - The runtime to query
+/// * `container_id` - Container ID or name
+///
+/// # Returns
+/// - `Ok(Vec<PortBinding>)` with one entry per host binding (may be empty)
+/// - `Err(String)` if the ID is invalid or the command fails, e.g. for an
+///   unknown container
+pub async fn get_port_mappings(
+    runtime: &Runtime,
+    container_id: &str,
+) -> Result<Vec<PortBinding>, String> {
+    validate_container_id(container_id)?;
+    let runtime = runtime.clone();
+    let container_id = container_id.to_string();
+
+    let output = tokio::task::spawn_blocking(move || {
+        runtime_command(&runtime)
+            .args(["port", &container_id])
+            .output()
+    })
+    .await
+    .map_err(|e| format!("Port task failed: {}", e))?
+    .map_err(|e| format!("Failed to execute port: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(stderr.trim().to_string());
+    }
+
+    Ok(parse_port_mappings(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PORT_FIXTURE: &str = "80/tcp -> 0.0.0.0:8080
+80/tcp -> [::]:8080
+443/tcp -> :::8443
+53/udp -> 127.0.0.1:5353
+";
+
+    #[test]
+    fn test_parse_port_mappings() {
+        let bindings = parse_port_mappings(PORT_FIXTURE);
+        assert_eq!(bindings.len(), 4);
+
+        assert_eq!(
+            bindings[0],
+            PortBinding {
+                container_port: 80,
+                protocol: "tcp".to_string(),
+                host_ip: "0.0.0.0".to_string(),
+                host_port: 8080,
+            }
+        );
+        assert_eq!(bindings[1].host_ip, "::");
+        assert_eq!(bindings[1].host_port, 8080);
+        assert_eq!(bindings[2].host_ip, "::");
+        assert_eq!(bindings[2].host_port, 8443);
+        assert_eq!(bindings[3].protocol, "udp");
+    }
+
+    #[test]
+    fn test_parse_port_mappings_skips_garbage() {
+        assert!(parse_port_mappings("").is_empty());
+        assert!(parse_port_mappings("not a port line\n80/tcp -> nowhere").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_port_mappings_rejects_flag_like_id() {
+        let runtime = Runtime::for_test("echo");
+        assert_eq!(
+            get_port_mappings(&runtime, "--help").await.unwrap_err(),
+            "Invalid container ID: --help"
+        );
+    }
+}
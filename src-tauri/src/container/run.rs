@@ -0,0 +1,417 @@
+//! Creating and starting containers
+//!
+//! Assembles a `docker run` argument vector from `RunOptions`. Detached runs
+//! print only the new container's ID on stdout (pull progress goes to
+//! stderr), which is what `create_container` returns.
+
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+use std::process::Stdio;
+
+use crate::container::lifecycle::validate_container_name;
+use crate::container::ports::PortBinding;
+use crate::image::reference::parse_image_reference;
+use crate::runtime::command::runtime_command;
+use crate::types::Runtime;
+
+/// Options for `docker run`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RunOptions {
+    pub image: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    /// `KEY=VALUE` entries, or a bare `KEY` to pass the host's value through
+    #[serde(default)]
+    pub env: Vec<String>,
+    #[serde(default)]
+    pub ports: Vec<PortBinding>,
+    /// `-v` specs such as `data:/var/lib/data` or `/host:/container:ro`
+    #[serde(default)]
+    pub volumes: Vec<String>,
+    /// Overrides the image's default command
+    #[serde(default)]
+    pub command: Option<Vec<String>>,
+    #[serde(default)]
+    pub detached: bool,
+    /// `no`, `always`, `unless-stopped` or `on-failure[:max-retries]`
+    #[serde(default, rename = "restartPolicy")]
+    pub restart_policy: Option<String>,
+    /// `--add-host` entries as `host:ip`, e.g. `api.local:10.0.0.5`
+    #[serde(default, rename = "extraHosts")]
+    pub extra_hosts: Vec<String>,
+    /// `--dns` server IP addresses
+    #[serde(default)]
+    pub dns: Vec<String>,
+    /// `--platform` for multi-arch images, e.g. `linux/amd64`
+    #[serde(default)]
+    pub platform: Option<String>,
+}
+
+/// Validates a `--restart` policy value
+fn validate_restart_policy(policy: &str) -> Result<(), String> {
+    let valid = match policy.split_once(':') {
+        Some(("on-failure", retries)) => retries.parse::<u32>().is_ok(),
+        Some(_) => false,
+        None => matches!(policy, "no" | "always" | "unless-stopped" | "on-failure"),
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("Invalid restart policy: {}", policy))
+    }
+}
+
+/// Validates an `--add-host` entry of the form `host:ip`
+///
+/// The IP may be IPv6 (everything after the first `:`) or Docker's special
+/// `host-gateway` value.
+fn validate_extra_host(entry: &str) -> Result<(), String> {
+    let valid = match entry.split_once(':') {
+        Some((host, ip)) => {
+            !host.is_empty()
+                && !host.contains(char::is_whitespace)
+                && (ip == "host-gateway" || ip.parse::<IpAddr>().is_ok())
+        }
+        None => false,
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("Invalid extra host (expected host:ip): {}", entry))
+    }
+}
+
+/// Validates a `--platform` value of the form `os[/arch[/variant]]`
+fn validate_platform(platform: &str) -> Result<(), String> {
+    let parts: Vec<&str> = platform.split('/').collect();
+    let valid = parts.len() <= 3
+        && parts.iter().all(|part| {
+            !part.is_empty()
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        });
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "Invalid platform (expected os[/arch[/variant]]): {}",
+            platform
+        ))
+    }
+}
+
+/// Formats a port binding as a `-p` value, e.g. `127.0.0.1:8080:80/tcp`
+fn format_port_binding(binding: &PortBinding) -> String {
+    let host_ip = match binding.host_ip.as_str() {
+        "" => String::new(),
+        ip if ip.contains(':') => format!("[{}]:", ip),
+        ip => format!("{}:", ip),
+    };
+    format!(
+        "{}{}:{}/{}",
+        host_ip, binding.host_port, binding.container_port, binding.protocol
+    )
+}
+
+/// Builds the `run` arguments for a container
+///
+/// # Returns
+/// - `Ok(Vec<String>)` with flags, then the image, then the command
+/// - `Err(String)` if the image is empty or malformed, or the name, env, restart policy,
+///   extra hosts, DNS servers or platform are invalid
+pub fn build_run_args(options: &RunOptions) -> Result<Vec<String>, String> {
+    let image = options.image.trim();
+    if image.is_empty() {
+        return Err("Image is required".to_string());
+    }
+    // Also keeps an image such as `--privileged` from being read as a flag
+    parse_image_reference(image)?;
+
+    let mut args = vec!["run".to_string()];
+    if options.detached {
+        args.push("--detach".to_string());
+    }
+    if let Some(name) = &options.name {
+        validate_container_name(name)?;
+        args.push("--name".to_string());
+        args.push(name.clone());
+    }
+    for entry in &options.env {
+        if entry.is_empty() || entry.starts_with('=') {
+            return Err(format!("Invalid environment entry: {}", entry));
+        }
+        args.push("--env".to_string());
+        args.push(entry.clone());
+    }
+    for binding in &options.ports {
+        args.push("--publish".to_string());
+        args.push(format_port_binding(binding));
+    }
+    for volume in &options.volumes {
+        args.push("--volume".to_string());
+        args.push(volume.clone());
+    }
+    if let Some(policy) = &options.restart_policy {
+        validate_restart_policy(policy)?;
+        args.push("--restart".to_string());
+        args.push(policy.clone());
+    }
+    for entry in &options.extra_hosts {
+        validate_extra_host(entry)?;
+        args.push("--add-host".to_string());
+        args.push(entry.clone());
+    }
+    for server in &options.dns {
+        if server.parse::<IpAddr>().is_err() {
+            return Err(format!("Invalid DNS server: {}", server));
+        }
+        args.push("--dns".to_string());
+        args.push(server.clone());
+    }
+    if let Some(platform) = &options.platform {
+        validate_platform(platform)?;
+        args.push("--platform".to_string());
+        args.push(platform.clone());
+    }
+
+    args.push(image.to_string());
+    if let Some(command) = &options.command {
+        args.extend(command.iter().cloned());
+    }
+    Ok(args)
+}
+
+/// Creates and starts a container
+///
+/// With `detached` set this returns as soon as the container has started.
+/// Without it the call blocks until the container exits and the returned
+/// string is the container's own output rather than its ID, so UI callers
+/// must always run detached (`run_container_command` enforces this).
+///
+/// # Arguments
+/// * `runtime` - The runtime to use
+/// * `options` - Image, name, environment, ports, volumes, command and restart policy
+///
+/// # Returns
+/// - `Ok(String)` with the new container ID (detached runs)
+/// - `Err(String)` for invalid options or the CLI's stderr
+pub async fn create_container(runtime: &Runtime, options: &RunOptions) -> Result<String, String> {
+    let runtime = runtime.clone();
+    let args = build_run_args(options)?;
+
+    let output = tokio::task::spawn_blocking(move || {
+        runtime_command(&runtime)
+            .args(&args)
+            .stdin(Stdio::null())
+            .output()
+    })
+    .await
+    .map_err(|e| format!("Run task failed: {}", e))?
+    .map_err(|e| format!("Failed to execute run: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(stderr.trim().to_string());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn binding(host_ip: &str, host_port: u16, container_port: u16) -> PortBinding {
+        PortBinding {
+            container_port,
+            protocol: "tcp".to_string(),
+            host_ip: host_ip.to_string(),
+            host_port,
+        }
+    }
+
+    #[test]
+    fn test_build_run_args_minimal() {
+        let options = RunOptions {
+            image: "nginx:latest".to_string(),
+            ..RunOptions::default()
+        };
+        assert_eq!(
+            build_run_args(&options).unwrap(),
+            vec!["run", "nginx:latest"]
+        );
+    }
+
+    #[test]
+    fn test_build_run_args_all_options() {
+        let options = RunOptions {
+            image: "nginx:latest".to_string(),
+            name: Some("web".to_string()),
+            env: vec!["MODE=prod".to_string(), "HOME".to_string()],
+            ports: vec![binding("127.0.0.1", 8080, 80), binding("::", 8443, 443)],
+            volumes: vec!["/srv/www:/usr/share/nginx/html:ro".to_string()],
+            command: Some(vec![
+                "nginx".to_string(),
+                "-g".to_string(),
+                "daemon off;".to_string(),
+            ]),
+            detached: true,
+            restart_policy: Some("on-failure:3".to_string()),
+            extra_hosts: vec!["api.local:10.0.0.5".to_string()],
+            dns: vec!["1.1.1.1".to_string()],
+            platform: Some("linux/amd64".to_string()),
+        };
+
+        assert_eq!(
+            build_run_args(&options).unwrap(),
+            vec![
+                "run",
+                "--detach",
+                "--name",
+                "web",
+                "--env",
+                "MODE=prod",
+                "--env",
+                "HOME",
+                "--publish",
+                "127.0.0.1:8080:80/tcp",
+                "--publish",
+                "[::]:8443:443/tcp",
+                "--volume",
+                "/srv/www:/usr/share/nginx/html:ro",
+                "--restart",
+                "on-failure:3",
+                "--add-host",
+                "api.local:10.0.0.5",
+                "--dns",
+                "1.1.1.1",
+                "--platform",
+                "linux/amd64",
+                "nginx:latest",
+                "nginx",
+                "-g",
+                "daemon off;",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_run_args_platform_only_when_set() {
+        let mut options = RunOptions {
+            image: "nginx".to_string(),
+            ..RunOptions::default()
+        };
+        assert!(!build_run_args(&options)
+            .unwrap()
+            .contains(&"--platform".to_string()));
+
+        options.platform = Some("linux/arm64/v8".to_string());
+        assert_eq!(
+            build_run_args(&options).unwrap(),
+            vec!["run", "--platform", "linux/arm64/v8", "nginx"]
+        );
+
+        options.platform = Some("linux/".to_string());
+        assert!(build_run_args(&options).is_err());
+    }
+
+    #[test]
+    fn test_build_run_args_rejects_flag_like_image() {
+        for image in ["--privileged", "-v=/:/host"] {
+            let options = RunOptions {
+                image: image.to_string(),
+                ..RunOptions::default()
+            };
+            assert!(build_run_args(&options).is_err(), "{}", image);
+        }
+    }
+
+    #[test]
+    fn test_format_port_binding_without_host_ip() {
+        assert_eq!(format_port_binding(&binding("", 8080, 80)), "8080:80/tcp");
+    }
+
+    #[test]
+    fn test_build_run_args_rejects_invalid() {
+        let base = RunOptions {
+            image: "nginx".to_string(),
+            ..RunOptions::default()
+        };
+
+        assert!(build_run_args(&RunOptions::default()).is_err());
+        assert!(build_run_args(&RunOptions {
+            name: Some("bad name".to_string()),
+            ..base.clone()
+        })
+        .is_err());
+        assert!(build_run_args(&RunOptions {
+            env: vec!["=value".to_string()],
+            ..base.clone()
+        })
+        .is_err());
+        assert!(build_run_args(&RunOptions {
+            restart_policy: Some("sometimes".to_string()),
+            ..base.clone()
+        })
+        .is_err());
+        assert!(build_run_args(&RunOptions {
+            extra_hosts: vec!["api.local=10.0.0.5".to_string()],
+            ..base.clone()
+        })
+        .is_err());
+        assert!(build_run_args(&RunOptions {
+            dns: vec!["dns.example.com".to_string()],
+            ..base
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_extra_host() {
+        for entry in [
+            "api.local:10.0.0.5",
+            "db:fd00::5",
+            "host.docker.internal:host-gateway",
+        ] {
+            assert!(validate_extra_host(entry).is_ok(), "{}", entry);
+        }
+        for entry in [
+            "api.local",
+            ":10.0.0.5",
+            "api.local:999.0.0.1",
+            "api local:10.0.0.5",
+        ] {
+            assert!(validate_extra_host(entry).is_err(), "{}", entry);
+        }
+    }
+
+    #[test]
+    fn test_validate_restart_policy() {
+        for policy in [
+            "no",
+            "always",
+            "unless-stopped",
+            "on-failure",
+            "on-failure:5",
+        ] {
+            assert!(validate_restart_policy(policy).is_ok(), "{}", policy);
+        }
+        assert!(validate_restart_policy("on-failure:x").is_err());
+        assert!(validate_restart_policy("always:3").is_err());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_create_container_returns_stdout() {
+        // `echo` prints its arguments, standing in for the runtime printing the ID
+        let runtime = Runtime::for_test("echo");
+        let options = RunOptions {
+            image: "nginx".to_string(),
+            detached: true,
+            ..RunOptions::default()
+        };
+        let id = create_container(&runtime, &options).await.unwrap();
+        assert_eq!(id, "run --detach nginx");
+    }
+}
@@ -0,0 +1,387 @@
+//! Container resource usage
+//!
+//! `stats --no-stream --format json` prints a one-shot usage snapshot, but
+//! the two runtimes disagree on its shape: Docker emits one object per line
+//! with `CPUPerc`/`MemUsage`-style keys, while Podman emits an array of
+//! objects with snake_case keys. Both render sizes and percentages as human
+//! strings ("12.3MiB / 7.6GiB", "0.16%"), which the parser normalizes.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+use crate::process::cancel::CancellationToken;
+use crate::runtime::command::runtime_command;
+use crate::runtime::inspect::validate_container_id;
+use crate::types::Runtime;
+
+/// Interval between samples when streaming stats
+pub const DEFAULT_STATS_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Samples kept per streamed container for sparklines
+pub const STATS_HISTORY_CAP: usize = 60;
+
+lazy_static::lazy_static! {
+    /// Recent samples of every container with an active stats stream
+    pub static ref STATS_HISTORY: StatsHistory = StatsHistory::new(STATS_HISTORY_CAP);
+}
+
+/// Point-in-time resource usage of a container
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ContainerStats {
+    #[serde(rename = "containerId")]
+    pub container_id: String,
+    #[serde(rename = "cpuPercent")]
+    pub cpu_percent: f64,
+    /// Bytes
+    #[serde(rename = "memUsage")]
+    pub mem_usage: u64,
+    /// Bytes
+    #[serde(rename = "memLimit")]
+    pub mem_limit: u64,
+    #[serde(rename = "memPercent")]
+    pub mem_percent: f64,
+    #[serde(rename = "netRx")]
+    pub net_rx: u64,
+    #[serde(rename = "netTx")]
+    pub net_tx: u64,
+    #[serde(rename = "blockRead")]
+    pub block_read: u64,
+    #[serde(rename = "blockWrite")]
+    pub block_write: u64,
+    pub pids: u64,
+}
+
+/// Bounded per-container ring buffers of recent samples
+pub struct StatsHistory {
+    samples: Mutex<HashMap<String, VecDeque<ContainerStats>>>,
+    cap: usize,
+}
+
+impl StatsHistory {
+    /// Creates an empty history keeping at most `cap` samples per container
+    ///
+    /// A `cap` of zero is treated as one so the latest sample is always kept.
+    pub fn new(cap: usize) -> Self {
+        Self {
+            samples: Mutex::new(HashMap::new()),
+            cap: cap.max(1),
+        }
+    }
+
+    /// Records a sample, dropping the oldest once the container is at the cap
+    pub fn push(&self, container_id: &str, sample: ContainerStats) {
+        if let Ok(mut samples) = self.samples.lock() {
+            let buffer = samples.entry(container_id.to_string()).or_default();
+            if buffer.len() == self.cap {
+                buffer.pop_front();
+            }
+            buffer.push_back(sample);
+        }
+    }
+
+    /// Returns a container's samples, oldest first
+    pub fn get(&self, container_id: &str) -> Vec<ContainerStats> {
+        self.samples
+            .lock()
+            .ok()
+            .and_then(|samples| {
+                samples
+                    .get(container_id)
+                    .map(|b| b.iter().cloned().collect())
+            })
+            .unwrap_or_default()
+    }
+
+    /// Drops the history of a container that is no longer streamed
+    pub fn evict(&self, container_id: &str) {
+        if let Ok(mut samples) = self.samples.lock() {
+            samples.remove(container_id);
+        }
+    }
+}
+
+/// Parses a percentage such as `0.16%`; `--` and garbage become 0
+fn parse_percent(value: &str) -> f64 {
+    value
+        .trim()
+        .trim_end_matches('%')
+        .trim()
+        .parse()
+        .unwrap_or(0.0)
+}
+
+/// Parses a human-readable size such as `12.3MiB`, `1.2kB` or `0B` into bytes
+fn parse_size(value: &str) -> u64 {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let Ok(number) = number.parse::<f64>() else {
+        return 0;
+    };
+
+    let multiplier: f64 = match unit.trim().to_lowercase().as_str() {
+        "" | "b" => 1.0,
+        "kb" => 1e3,
+        "mb" => 1e6,
+        "gb" => 1e9,
+        "tb" => 1e12,
+        "kib" => 1024.0,
+        "mib" => 1024.0 * 1024.0,
+        "gib" => 1024.0 * 1024.0 * 1024.0,
+        "tib" => 1024.0 * 1024.0 * 1024.0 * 1024.0,
+        _ => return 0,
+    };
+    (number * multiplier).round() as u64
+}
+
+/// Parses an `x / y` pair of sizes, e.g. `1.2kB / 648B`
+fn parse_size_pair(value: &str) -> (u64, u64) {
+    match value.split_once('/') {
+        Some((first, second)) => (parse_size(first), parse_size(second)),
+        None => (parse_size(value), 0),
+    }
+}
+
+/// Looks up the first present key as a string (numbers are rendered)
+fn field(entry: &Value, keys: &[&str]) -> String {
+    keys.iter()
+        .find_map(|key| match entry.get(*key)? {
+            Value::String(s) => Some(s.clone()),
+            Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+/// Normalizes one Docker or Podman stats object
+fn parse_stats_entry(entry: &Value) -> ContainerStats {
+    let (mem_usage, mem_limit) = parse_size_pair(&field(entry, &["MemUsage", "mem_usage"]));
+    let (net_rx, net_tx) = parse_size_pair(&field(entry, &["NetIO", "net_io"]));
+    let (block_read, block_write) = parse_size_pair(&field(entry, &["BlockIO", "block_io"]));
+
+    ContainerStats {
+        container_id: field(entry, &["ID", "Container", "id"]),
+        cpu_percent: parse_percent(&field(entry, &["CPUPerc", "cpu_percent"])),
+        mem_usage,
+        mem_limit,
+        mem_percent: parse_percent(&field(entry, &["MemPerc", "mem_percent"])),
+        net_rx,
+        net_tx,
+        block_read,
+        block_write,
+        pids: field(entry, &["PIDs", "pids"]).trim().parse().unwrap_or(0),
+    }
+}
+
+/// Parses `stats --no-stream --format json` output from either runtime
+///
+/// # Returns
+/// - `Ok(ContainerStats)` for the first container in the output
+/// - `Err(String)` if no stats object could be parsed
+pub fn parse_stats(output: &str) -> Result<ContainerStats, String> {
+    let trimmed = output.trim();
+
+    // Podman prints a JSON array; Docker prints one object per line
+    let entry = match serde_json::from_str::<Value>(trimmed) {
+        Ok(Value::Array(entries)) => entries.into_iter().next(),
+        Ok(value @ Value::Object(_)) => Some(value),
+        _ => trimmed
+            .lines()
+            .find_map(|line| serde_json::from_str::<Value>(line).ok()),
+    };
+
+    entry
+        .filter(Value::is_object)
+        .map(|entry| parse_stats_entry(&entry))
+        .ok_or_else(|| "No stats in runtime output".to_string())
+}
+
+/// Takes a one-shot usage snapshot of a container
+///
+/// # Arguments
+/// * `runtime` - The runtime to query
+/// * `container_id` - Container ID or name
+///
+/// # Returns
+/// - `Ok(ContainerStats)` with normalized values
+/// - `Err(String)` if the ID is invalid, the command fails or its output
+///   can't be parsed
+pub async fn get_stats(runtime: &Runtime, container_id: &str) -> Result<ContainerStats, String> {
+    validate_container_id(container_id)?;
+    let runtime = runtime.clone();
+    let container_id = container_id.to_string();
+
+    let output = tokio::task::spawn_blocking(move || {
+        runtime_command(&runtime)
+            .args(["stats", "--no-stream", "--format", "json", &container_id])
+            .output()
+    })
+    .await
+    .map_err(|e| format!("Stats task failed: {}", e))?
+    .map_err(|e| format!("Failed to execute stats: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(stderr.trim().to_string());
+    }
+
+    parse_stats(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Samples a container's stats on an interval, emitting `container-stats`
+///
+/// Every sample is also recorded in `STATS_HISTORY`.
+///
+/// # Arguments
+/// * `app` - App handle used to emit each sample
+/// * `runtime` - The runtime to query
+/// * `container_id` - Container ID or name
+/// * `interval` - Time between samples
+/// * `token` - Stops the stream when cancelled
+///
+/// # Returns
+/// - `Ok(())` once the token is cancelled
+/// - `Err(String)` if sampling fails, e.g. because the container was removed
+pub async fn stream_stats(
+    app: AppHandle,
+    runtime: &Runtime,
+    container_id: &str,
+    interval: Duration,
+    token: &CancellationToken,
+) -> Result<(), String> {
+    let mut tick = tokio::time::interval(interval);
+
+    loop {
+        tokio::select! {
+            _ = token.cancelled() => return Ok(()),
+            _ = tick.tick() => {}
+        }
+
+        let stats = get_stats(runtime, container_id).await?;
+        if token.is_cancelled() {
+            return Ok(());
+        }
+        if let Err(e) = app.emit("container-stats", &stats) {
+            eprintln!("Failed to emit container stats: {}", e);
+        }
+        STATS_HISTORY.push(container_id, stats);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCKER_FIXTURE: &str = r#"{"BlockIO":"4.1MB / 0B","CPUPerc":"1.25%","Container":"a1b2c3","ID":"a1b2c3","MemPerc":"0.16%","MemUsage":"12.5MiB / 7.5GiB","Name":"web","NetIO":"1.2kB / 648B","PIDs":"3"}"#;
+
+    const PODMAN_FIXTURE: &str = r#"[
+ {
+  "id": "d4e5f6",
+  "name": "db",
+  "cpu_time": "1.2s",
+  "cpu_percent": "0.50%",
+  "avg_cpu": "0.40%",
+  "mem_usage": "20MB / 2GB",
+  "mem_percent": "1.00%",
+  "net_io": "3kB / 1kB",
+  "block_io": "0B / 8.2MB",
+  "pids": "7"
+ }
+]"#;
+
+    #[test]
+    fn test_parse_docker_stats() {
+        let stats = parse_stats(DOCKER_FIXTURE).unwrap();
+        assert_eq!(stats.container_id, "a1b2c3");
+        assert_eq!(stats.cpu_percent, 1.25);
+        assert_eq!(stats.mem_usage, 13_107_200);
+        assert_eq!(stats.mem_limit, 8_053_063_680);
+        assert_eq!(stats.mem_percent, 0.16);
+        assert_eq!(stats.net_rx, 1_200);
+        assert_eq!(stats.net_tx, 648);
+        assert_eq!(stats.block_read, 4_100_000);
+        assert_eq!(stats.block_write, 0);
+        assert_eq!(stats.pids, 3);
+    }
+
+    #[test]
+    fn test_parse_podman_stats() {
+        let stats = parse_stats(PODMAN_FIXTURE).unwrap();
+        assert_eq!(stats.container_id, "d4e5f6");
+        assert_eq!(stats.cpu_percent, 0.5);
+        assert_eq!(stats.mem_usage, 20_000_000);
+        assert_eq!(stats.mem_limit, 2_000_000_000);
+        assert_eq!(stats.net_rx, 3_000);
+        assert_eq!(stats.net_tx, 1_000);
+        assert_eq!(stats.block_write, 8_200_000);
+        assert_eq!(stats.pids, 7);
+    }
+
+    #[test]
+    fn test_parse_stats_rejects_empty() {
+        assert!(parse_stats("").is_err());
+        assert!(parse_stats("[]").is_err());
+        assert!(parse_stats("not json").is_err());
+    }
+
+    #[test]
+    fn test_history_drops_oldest_past_cap() {
+        let history = StatsHistory::new(3);
+        for pids in 1..=5 {
+            history.push(
+                "web",
+                ContainerStats {
+                    pids,
+                    ..ContainerStats::default()
+                },
+            );
+        }
+
+        let pids: Vec<u64> = history.get("web").iter().map(|s| s.pids).collect();
+        assert_eq!(pids, vec![3, 4, 5]);
+
+        history.evict("web");
+        assert!(history.get("web").is_empty());
+    }
+
+    #[test]
+    fn test_history_zero_cap_keeps_latest() {
+        let history = StatsHistory::new(0);
+        for pids in 1..=2 {
+            history.push(
+                "web",
+                ContainerStats {
+                    pids,
+                    ..ContainerStats::default()
+                },
+            );
+        }
+
+        let pids: Vec<u64> = history.get("web").iter().map(|s| s.pids).collect();
+        assert_eq!(pids, vec![2]);
+    }
+
+    #[tokio::test]
+    async fn test_get_stats_rejects_flag_like_id() {
+        let runtime = Runtime::for_test("echo");
+        assert_eq!(
+            get_stats(&runtime, "--all").await.unwrap_err(),
+            "Invalid container ID: --all"
+        );
+    }
+
+    #[test]
+    fn test_parse_size_and_percent() {
+        assert_eq!(parse_size("0B"), 0);
+        assert_eq!(parse_size("1KiB"), 1024);
+        assert_eq!(parse_size("--"), 0);
+        assert_eq!(parse_percent("--"), 0.0);
+        assert_eq!(parse_percent("250.5%"), 250.5);
+    }
+}
@@ -0,0 +1,395 @@
+//! Building images from a Dockerfile
+//!
+//! BuildKit and the classic builder report results differently: the classic
+//! builder prints `Successfully built <id>` / `Successfully tagged <tag>` on
+//! stdout, BuildKit prints `writing image sha256:<id>` / `naming to <tag>` on
+//! stderr, and Podman ends with the bare image ID. Both streams are merged
+//! and handed to a callback line by line while the build runs; only the last
+//! `MAX_KEPT_LINES` are kept and parsed afterwards, so the ID comes out the
+//! same way either way without holding a long build log in memory.
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::io::{BufReader, Read};
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::image::reference::parse_image_reference;
+use crate::image::tag::tag_image;
+use crate::process::cancel::CancellationToken;
+use crate::process::lines::{read_bounded_line, MAX_LINE_BYTES};
+use crate::process::tracker::PROCESS_TRACKER;
+use crate::runtime::buildkit::buildkit_enabled;
+use crate::runtime::command::runtime_command;
+use crate::types::Runtime;
+
+/// Trailing build output lines kept for parsing the result and errors
+pub const MAX_KEPT_LINES: usize = 200;
+
+/// Called for every line of build output, from the reader thread
+pub type BuildLineHandler = Arc<dyn Fn(&str) + Send + Sync>;
+
+/// Payload of the `image-build-progress` event
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BuildProgress {
+    #[serde(rename = "buildId")]
+    pub build_id: String,
+    /// Line without its trailing newline
+    pub line: String,
+}
+
+/// Options for `build_image`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BuildOptions {
+    /// Build context directory
+    pub context: String,
+    /// Dockerfile path, when not `<context>/Dockerfile`
+    #[serde(default)]
+    pub dockerfile: Option<String>,
+    /// Tag for the built image, e.g. `myorg/web:1.0`
+    #[serde(default)]
+    pub tag: Option<String>,
+    #[serde(default, rename = "noCache")]
+    pub no_cache: bool,
+}
+
+/// Builds the `build` arguments for the given options
+///
+/// BuildKit's default TTY progress hides the lines we parse, so plain
+/// progress is requested when BuildKit is known to be in use.
+///
+/// # Returns
+/// - `Ok(Vec<String>)` ending with the context directory
+/// - `Err(String)` if the context is empty or the tag is invalid
+pub fn build_build_args(options: &BuildOptions, buildkit: bool) -> Result<Vec<String>, String> {
+    if options.context.trim().is_empty() {
+        return Err("Build context is empty".to_string());
+    }
+
+    let mut args = vec!["build".to_string()];
+    if buildkit {
+        args.push("--progress=plain".to_string());
+    }
+    if let Some(dockerfile) = &options.dockerfile {
+        args.push("--file".to_string());
+        args.push(dockerfile.clone());
+    }
+    if let Some(tag) = &options.tag {
+        parse_image_reference(tag)?;
+        args.push("--tag".to_string());
+        args.push(tag.clone());
+    }
+    if options.no_cache {
+        args.push("--no-cache".to_string());
+    }
+    args.push(options.context.clone());
+    Ok(args)
+}
+
+/// Extracts the built image ID from build output
+///
+/// Looks for the last classic `Successfully built <id>` line, BuildKit
+/// `writing image sha256:<id>` line, or a bare 64-character hex ID as
+/// printed by Podman.
+///
+/// # Returns
+/// - `Some(String)` with the ID as printed (`sha256:`-prefixed for BuildKit)
+/// - `None` if no ID was found
+pub fn parse_built_image_id(output: &str) -> Option<String> {
+    output.lines().rev().map(str::trim).find_map(|line| {
+        if let Some(id) = line.strip_prefix("Successfully built ") {
+            return Some(id.trim().to_string());
+        }
+        if let Some((_, rest)) = line.split_once("writing image ") {
+            return rest.split_whitespace().next().map(String::from);
+        }
+        let is_bare_id = line.len() == 64 && line.chars().all(|c| c.is_ascii_hexdigit());
+        is_bare_id.then(|| line.to_string())
+    })
+}
+
+/// Whether the build output shows that `tag` was applied
+///
+/// BuildKit names the fully qualified reference (`docker.io/library/web:1.0`
+/// for `web:1.0`), so a suffix match is enough.
+fn tag_applied(output: &str, tag: &str) -> bool {
+    output.lines().map(str::trim).any(|line| {
+        if let Some(tagged) = line.strip_prefix("Successfully tagged ") {
+            return tagged.trim() == tag || tagged.trim().ends_with(&format!("/{}", tag));
+        }
+        line.split_once("naming to ").is_some_and(|(_, rest)| {
+            rest.split_whitespace()
+                .next()
+                .is_some_and(|name| name == tag || name.ends_with(&format!("/{}", tag)))
+        })
+    })
+}
+
+/// Forwards every output line to `on_line` and returns the last lines
+///
+/// # Returns
+/// - `Ok(String)` with at most `MAX_KEPT_LINES` trailing lines
+/// - `Err` if reading the output fails
+fn stream_build_output<R: Read>(reader: R, on_line: &dyn Fn(&str)) -> std::io::Result<String> {
+    let mut reader = BufReader::new(reader);
+    let mut buf = Vec::new();
+    let mut kept: VecDeque<String> = VecDeque::with_capacity(MAX_KEPT_LINES);
+
+    while read_bounded_line(&mut reader, &mut buf, MAX_LINE_BYTES)? {
+        let line = String::from_utf8_lossy(&buf)
+            .trim_end_matches(['\n', '\r'])
+            .to_string();
+        on_line(&line);
+        if kept.len() == MAX_KEPT_LINES {
+            kept.pop_front();
+        }
+        kept.push_back(line);
+    }
+
+    Ok(Vec::from(kept).join("\n"))
+}
+
+/// Builds an image and returns its ID
+///
+/// The build child is registered with the process tracker and killed if
+/// `token` is cancelled. When a tag was requested but the output shows the
+/// builder didn't apply it, the image is tagged afterwards.
+///
+/// # Arguments
+/// * `runtime` - The runtime to build with
+/// * `options` - Context, Dockerfile, tag and cache settings
+/// * `token` - Cancels the build when triggered
+/// * `on_line` - Receives each line of build output as it arrives
+///
+/// # Returns
+/// - `Ok(String)` with the built image ID
+/// - `Err(String)` if the context is missing, the build fails or is
+///   cancelled, its output can't be read, or no image ID appears in it
+pub async fn build_image(
+    runtime: &Runtime,
+    options: &BuildOptions,
+    token: &CancellationToken,
+    on_line: BuildLineHandler,
+) -> Result<String, String> {
+    let buildkit = buildkit_enabled(runtime).await.unwrap_or(false);
+    let args = build_build_args(options, buildkit)?;
+    if !Path::new(&options.context).is_dir() {
+        return Err(format!("Build context not found: {}", options.context));
+    }
+    if token.is_cancelled() {
+        return Err("Build cancelled".to_string());
+    }
+
+    let (reader, writer) = std::io::pipe().map_err(|e| format!("Failed to create pipe: {}", e))?;
+    let stderr_writer = writer
+        .try_clone()
+        .map_err(|e| format!("Failed to create pipe: {}", e))?;
+
+    // The `Command` (and its pipe ends) is dropped at the end of this
+    // statement, so the reader sees EOF once the child exits
+    let child = runtime_command(runtime)
+        .args(&args)
+        .stdout(writer)
+        .stderr(stderr_writer)
+        .spawn()
+        .map_err(|e| format!("Failed to execute build: {}", e))?;
+    let handle = PROCESS_TRACKER.track(child);
+
+    let build = tokio::task::spawn_blocking(move || match stream_build_output(reader, &*on_line) {
+        Ok(output) => Ok((output, PROCESS_TRACKER.release(handle))),
+        Err(e) => {
+            PROCESS_TRACKER.kill(handle);
+            Err(format!("Failed to read build output: {}", e))
+        }
+    });
+
+    let (output, status) = tokio::select! {
+        _ = token.cancelled() => {
+            PROCESS_TRACKER.kill(handle);
+            return Err("Build cancelled".to_string());
+        }
+        result = build => result.map_err(|e| format!("Build task failed: {}", e))??,
+    };
+
+    match status {
+        Some(status) if status.success() => {}
+        Some(_) => {
+            let tail: Vec<&str> = output.lines().rev().take(10).collect();
+            let tail: Vec<&str> = tail.into_iter().rev().collect();
+            return Err(format!("build failed: {}", tail.join("\n").trim()));
+        }
+        None => return Err("Build cancelled".to_string()),
+    }
+
+    let image_id = parse_built_image_id(&output)
+        .ok_or_else(|| "Build succeeded but no image ID was reported".to_string())?;
+
+    if let Some(tag) = &options.tag {
+        if !tag_applied(&output, tag) {
+            let mut repository = parse_image_reference(tag)?;
+            let target_tag = repository
+                .tag
+                .take()
+                .unwrap_or_else(|| "latest".to_string());
+            repository.digest = None;
+            tag_image(runtime, &image_id, &repository.to_string(), &target_tag)
+                .await
+                .map_err(|e| format!("tag failed: {}", e))?;
+        }
+    }
+
+    Ok(image_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(context: &str, tag: Option<&str>) -> BuildOptions {
+        BuildOptions {
+            context: context.to_string(),
+            dockerfile: None,
+            tag: tag.map(String::from),
+            no_cache: false,
+        }
+    }
+
+    #[test]
+    fn test_parse_built_image_id_classic() {
+        let output = "Step 1/2 : FROM alpine\n ---> 1d34ffeaf190\nStep 2/2 : RUN true\n ---> Running in 8f2a\n ---> 5b1f3e0c9a7d\nSuccessfully built 5b1f3e0c9a7d\nSuccessfully tagged web:1.0\n";
+        assert_eq!(
+            parse_built_image_id(output).as_deref(),
+            Some("5b1f3e0c9a7d")
+        );
+    }
+
+    #[test]
+    fn test_parse_built_image_id_buildkit() {
+        let output = "#5 exporting to image\n#5 exporting layers done\n#5 writing image sha256:9c0b6a1f8e4d2c3b5a7f9e1d0c2b4a6f8e0d1c3b5a7f9e2d4c6b8a0f1e3d5c7b done\n#5 naming to docker.io/library/web:1.0 done\n#5 DONE 0.1s\n";
+        assert_eq!(
+            parse_built_image_id(output).as_deref(),
+            Some("sha256:9c0b6a1f8e4d2c3b5a7f9e1d0c2b4a6f8e0d1c3b5a7f9e2d4c6b8a0f1e3d5c7b")
+        );
+    }
+
+    #[test]
+    fn test_parse_built_image_id_podman_and_missing() {
+        let id = "9c0b6a1f8e4d2c3b5a7f9e1d0c2b4a6f8e0d1c3b5a7f9e2d4c6b8a0f1e3d5c7b";
+        let output = format!("STEP 1/1: FROM alpine\nCOMMIT\n{}\n", id);
+        assert_eq!(parse_built_image_id(&output).as_deref(), Some(id));
+        assert!(parse_built_image_id("error: failed to solve\n").is_none());
+    }
+
+    #[test]
+    fn test_tag_applied() {
+        assert!(tag_applied("Successfully tagged web:1.0\n", "web:1.0"));
+        assert!(tag_applied(
+            "#5 naming to docker.io/library/web:1.0 done\n",
+            "web:1.0"
+        ));
+        assert!(!tag_applied(
+            "#5 naming to docker.io/library/api:1.0 done\n",
+            "web:1.0"
+        ));
+        assert!(!tag_applied("Successfully built 5b1f3e0c9a7d\n", "web:1.0"));
+    }
+
+    #[test]
+    fn test_build_build_args() {
+        assert_eq!(
+            build_build_args(&options(".", Some("web:1.0")), true).unwrap(),
+            vec!["build", "--progress=plain", "--tag", "web:1.0", "."]
+        );
+
+        let mut custom = options("/src/app", None);
+        custom.dockerfile = Some("/src/app/Dockerfile.dev".to_string());
+        custom.no_cache = true;
+        assert_eq!(
+            build_build_args(&custom, false).unwrap(),
+            vec![
+                "build",
+                "--file",
+                "/src/app/Dockerfile.dev",
+                "--no-cache",
+                "/src/app"
+            ]
+        );
+
+        assert!(build_build_args(&options("", None), false).is_err());
+        assert!(build_build_args(&options(".", Some("Not Valid")), false).is_err());
+    }
+
+    #[test]
+    fn test_stream_build_output_forwards_all_and_keeps_tail() {
+        let input: String = (1..=MAX_KEPT_LINES + 50)
+            .map(|i| format!("#{} step\r\n", i))
+            .collect();
+        let streamed = std::sync::Mutex::new(Vec::new());
+
+        let kept = stream_build_output(input.as_bytes(), &|line: &str| {
+            streamed.lock().unwrap().push(line.to_string())
+        })
+        .unwrap();
+
+        let streamed = streamed.into_inner().unwrap();
+        assert_eq!(streamed.len(), MAX_KEPT_LINES + 50);
+        assert_eq!(streamed[0], "#1 step");
+
+        let kept: Vec<&str> = kept.lines().collect();
+        assert_eq!(kept.len(), MAX_KEPT_LINES);
+        assert_eq!(kept[0], "#51 step");
+        assert_eq!(
+            kept[MAX_KEPT_LINES - 1],
+            format!("#{} step", MAX_KEPT_LINES + 50)
+        );
+    }
+
+    #[test]
+    fn test_stream_build_output_surfaces_read_errors() {
+        struct FailingReader;
+        impl Read for FailingReader {
+            fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::other("pipe broke"))
+            }
+        }
+
+        let err = stream_build_output(FailingReader, &|_: &str| {}).unwrap_err();
+        assert_eq!(err.to_string(), "pipe broke");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_build_image_cancelled() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = std::env::temp_dir().join(format!("harbor-master-build-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let script = dir.join("docker");
+        std::fs::write(&script, "#!/bin/sh\nexec sleep 30\n").unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let runtime = Runtime::for_test(&script.to_string_lossy())
+            .with_type(crate::types::RuntimeType::Podman);
+
+        let token = CancellationToken::new();
+        let canceller = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+            canceller.cancel();
+        });
+
+        let started = std::time::Instant::now();
+        let context = dir.to_string_lossy().to_string();
+        let result = build_image(
+            &runtime,
+            &options(&context, None),
+            &token,
+            Arc::new(|_: &str| {}),
+        )
+        .await;
+
+        assert_eq!(result.unwrap_err(), "Build cancelled");
+        assert!(started.elapsed() < std::time::Duration::from_secs(10));
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,144 @@
+//! Image layer history
+//!
+//! Parses `image history --format json --no-trunc`. Docker prints one JSON
+//! object per line (`ID`, `CreatedAt`, `CreatedBy`, `Size`, `Comment`);
+//! Podman prints an array with lowercase keys and numeric sizes. `--no-trunc`
+//! keeps `created_by`, the full Dockerfile instruction, verbatim.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::runtime::command::runtime_command;
+use crate::runtime::json::parse_json_rows;
+use crate::types::Runtime;
+
+/// One layer of an image's history, newest first
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryLayer {
+    /// Layer image ID, or `<missing>` for layers built elsewhere
+    pub id: String,
+    pub created: String,
+    /// Instruction that created the layer
+    #[serde(rename = "createdBy")]
+    pub created_by: String,
+    /// Human-readable size as printed by the CLI, e.g. `7.34MB`
+    pub size: String,
+    pub comment: String,
+}
+
+/// Reads the first present string (or number) field among `keys`
+fn text_field(row: &Value, keys: &[&str]) -> String {
+    keys.iter()
+        .find_map(|key| match &row[key] {
+            Value::String(s) => Some(s.clone()),
+            Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+/// Converts one history row from either runtime's JSON
+fn parse_layer(row: &Value) -> HistoryLayer {
+    HistoryLayer {
+        id: text_field(row, &["ID", "id"]),
+        created: text_field(row, &["CreatedAt", "created"]),
+        created_by: text_field(row, &["CreatedBy", "createdBy"]),
+        size: text_field(row, &["Size", "size"]),
+        comment: text_field(row, &["Comment", "comment"]),
+    }
+}
+
+/// Parses `image history --format json` output
+///
+/// # Returns
+/// - `Ok(Vec<HistoryLayer>)` in the CLI's order (newest layer first)
+/// - `Err(String)` if a line isn't JSON
+pub fn parse_history(output: &str) -> Result<Vec<HistoryLayer>, String> {
+    let rows = parse_json_rows(output, "history")?;
+    Ok(rows.iter().map(parse_layer).collect())
+}
+
+/// Lists the layers of an image
+///
+/// # Arguments
+/// * `runtime` - The runtime to query
+/// * `image_id` - Image ID or reference
+///
+/// # Returns
+/// - `Ok(Vec<HistoryLayer>)`, newest layer first
+/// - `Err(String)` if the ID is invalid, the image doesn't exist or the
+///   output can't be parsed
+pub async fn get_history(runtime: &Runtime, image_id: &str) -> Result<Vec<HistoryLayer>, String> {
+    if image_id.trim().is_empty() {
+        return Err("Image ID is required".to_string());
+    }
+    if image_id.starts_with('-') {
+        return Err(format!("Invalid image ID: {}", image_id));
+    }
+
+    let runtime = runtime.clone();
+    let args = vec![
+        "image".to_string(),
+        "history".to_string(),
+        "--format".to_string(),
+        "json".to_string(),
+        "--no-trunc".to_string(),
+        image_id.to_string(),
+    ];
+
+    let output =
+        tokio::task::spawn_blocking(move || runtime_command(&runtime).args(&args).output())
+            .await
+            .map_err(|e| format!("History task failed: {}", e))?
+            .map_err(|e| format!("Failed to execute image history: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("image history failed: {}", stderr.trim()));
+    }
+
+    parse_history(&String::from_utf8_lossy(&output.stdout))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_docker_history_line() {
+        let line = r#"{"Comment":"buildkit.dockerfile.v0","CreatedAt":"2024-05-01T12:00:00Z","CreatedBy":"RUN /bin/sh -c apt-get update && apt-get install -y curl ca-certificates # buildkit","CreatedSince":"5 months ago","ID":"sha256:9c0b6a1f8e4d","Size":"54.3MB"}"#;
+        let layers = parse_history(line).unwrap();
+
+        assert_eq!(
+            layers,
+            vec![HistoryLayer {
+                id: "sha256:9c0b6a1f8e4d".to_string(),
+                created: "2024-05-01T12:00:00Z".to_string(),
+                created_by: "RUN /bin/sh -c apt-get update && apt-get install -y curl ca-certificates # buildkit".to_string(),
+                size: "54.3MB".to_string(),
+                comment: "buildkit.dockerfile.v0".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_podman_history() {
+        let output = r#"[{"id":"<missing>","created":"2024-05-01T12:00:00Z","createdBy":"/bin/sh -c #(nop) CMD [\"bash\"]","size":0,"comment":""}]"#;
+        let layers = parse_history(output).unwrap();
+
+        assert_eq!(layers[0].id, "<missing>");
+        assert_eq!(layers[0].created_by, "/bin/sh -c #(nop) CMD [\"bash\"]");
+        assert_eq!(layers[0].size, "0");
+        assert!(parse_history("garbage").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_history_rejects_flag_like_id() {
+        let runtime = Runtime::for_test("echo");
+        assert_eq!(
+            get_history(&runtime, "--output=/tmp/x").await.unwrap_err(),
+            "Invalid image ID: --output=/tmp/x"
+        );
+        assert!(get_history(&runtime, " ").await.is_err());
+    }
+}
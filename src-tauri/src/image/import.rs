@@ -0,0 +1,107 @@
+//! Importing root filesystem tarballs as images
+//!
+//! Unlike loading a saved image, `docker import` creates a single-layer image
+//! from a raw rootfs tarball and tags it with the given reference.
+
+use std::path::Path;
+
+use crate::image::reference::parse_image_reference;
+use crate::runtime::command::runtime_command;
+use crate::types::Runtime;
+
+/// Builds the `import` arguments for a tarball and target reference
+///
+/// # Returns
+/// - `Ok(Vec<String>)` with `["import", <tar>, <reference>]`
+/// - `Err(String)` if the reference is invalid or pins a digest
+pub fn build_import_args(source_path: &str, reference: &str) -> Result<Vec<String>, String> {
+    let parsed = parse_image_reference(reference)?;
+    if parsed.digest.is_some() {
+        return Err(format!(
+            "Import reference cannot contain a digest: {}",
+            reference
+        ));
+    }
+
+    Ok(vec![
+        "import".to_string(),
+        source_path.to_string(),
+        parsed.to_string(),
+    ])
+}
+
+/// Extracts the image ID from `import` output
+///
+/// The CLI prints the new ID (e.g. `sha256:...`) as its last line.
+pub fn parse_import_output(output: &str) -> Option<String> {
+    output
+        .lines()
+        .map(str::trim)
+        .rfind(|line| !line.is_empty())
+        .map(String::from)
+}
+
+/// Imports a rootfs tarball as a new image
+///
+/// # Arguments
+/// * `runtime` - The runtime to use
+/// * `source_path` - Path of the tarball on the host
+/// * `reference` - Tag for the new image, e.g. `myorg/rootfs:1.0`
+///
+/// # Returns
+/// - `Ok(String)` with the new image ID
+/// - `Err(String)` for a missing file, invalid reference or CLI failure
+pub async fn import_image(
+    runtime: &Runtime,
+    source_path: &str,
+    reference: &str,
+) -> Result<String, String> {
+    let args = build_import_args(source_path, reference)?;
+    if !Path::new(source_path).is_file() {
+        return Err(format!("Tarball not found: {}", source_path));
+    }
+
+    let runtime = runtime.clone();
+    let output =
+        tokio::task::spawn_blocking(move || runtime_command(&runtime).args(&args).output())
+            .await
+            .map_err(|e| format!("Import task failed: {}", e))?
+            .map_err(|e| format!("Failed to execute import: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(stderr.trim().to_string());
+    }
+
+    parse_import_output(&String::from_utf8_lossy(&output.stdout))
+        .ok_or_else(|| "Import did not report an image ID".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_import_args() {
+        assert_eq!(
+            build_import_args("/tmp/rootfs.tar", "myorg/rootfs:1.0").unwrap(),
+            vec!["import", "/tmp/rootfs.tar", "myorg/rootfs:1.0"]
+        );
+        assert!(build_import_args("/tmp/rootfs.tar", "Bad Ref").is_err());
+        assert!(build_import_args(
+            "/tmp/rootfs.tar",
+            "myorg/rootfs@sha256:0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_parse_import_output() {
+        let id = "sha256:8f1e2d3c4b5a69788f1e2d3c4b5a69788f1e2d3c4b5a69788f1e2d3c4b5a6978";
+        assert_eq!(
+            parse_import_output(&format!("{}\n", id)).as_deref(),
+            Some(id)
+        );
+        assert_eq!(parse_import_output("\n  \n"), None);
+    }
+}
@@ -0,0 +1,10 @@
+// Image management operations
+
+pub mod build;
+pub mod history;
+pub mod import;
+pub mod progress;
+pub mod prune;
+pub mod push;
+pub mod reference;
+pub mod tag;
@@ -0,0 +1,51 @@
+//! Layer transfer progress shared by image pulls and pushes
+//!
+//! Both directions print one `<layer>: <status> [progress]` line per update;
+//! only the status vocabulary differs, so the per-direction parsers produce
+//! the same `TransferProgress` event payload.
+
+use serde::{Deserialize, Serialize};
+
+/// Progress of a single layer as reported by the CLI
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LayerProgress {
+    /// Short layer ID, e.g. `5f70bf18a086`
+    pub id: String,
+    /// Status as printed, e.g. `Pushing` or `Layer already exists`
+    pub status: String,
+    /// Transferred/total as printed, e.g. `1.024kB/3.2MB`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress: Option<String>,
+    /// Whether the layer needs no further transfer
+    pub complete: bool,
+}
+
+/// Progress event for an image transfer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferProgress {
+    /// Reference being pulled or pushed
+    pub image: String,
+    #[serde(flatten)]
+    pub layer: LayerProgress,
+}
+
+/// Splits a `<layer>: <rest>` progress line
+///
+/// Lines whose prefix isn't a hex layer ID (e.g. `latest: digest: ...`) are
+/// summaries rather than layer updates.
+pub(crate) fn split_layer_line(line: &str) -> Option<(&str, &str)> {
+    let (id, rest) = line.trim().split_once(": ")?;
+    let is_layer = id.len() >= 12 && id.chars().all(|c| c.is_ascii_hexdigit());
+    is_layer.then_some((id, rest.trim()))
+}
+
+/// Extracts the `current/total` figure trailing a progress bar
+///
+/// `[=====>    ]  1.024kB/3.2MB` yields `1.024kB/3.2MB`.
+pub(crate) fn parse_transfer_amount(rest: &str) -> Option<String> {
+    let after_bar = rest.rsplit_once(']').map_or(rest, |(_, tail)| tail);
+    after_bar
+        .split_whitespace()
+        .find(|token| token.contains('/'))
+        .map(String::from)
+}
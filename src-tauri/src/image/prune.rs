@@ -0,0 +1,330 @@
+//! Pruning unused images
+//!
+//! An `until` filter keeps recently created images: only images older than
+//! the given timestamp or Go duration (e.g. `24h`) are removed.
+//! `prune_images_older_than` builds that filter from a Rust `Duration` as an
+//! absolute UTC timestamp, so the local timezone never shifts the cutoff.
+//!
+//! Newer Docker releases accept `--format json` and report
+//! `{ImagesDeleted, SpaceReclaimed}`; that is requested first and parsed in
+//! preference to the human-readable text, which older Docker and Podman
+//! print instead.
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::runtime::command::runtime_command;
+use crate::types::{Runtime, RuntimeType};
+
+/// Options for `prune_images`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PruneImagesOptions {
+    /// Remove all unused images, not just dangling ones
+    #[serde(default)]
+    pub all: bool,
+    /// Only prune images created before this timestamp or duration
+    #[serde(default)]
+    pub until: Option<String>,
+}
+
+/// Images removed by a prune and the space it freed
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PruneImagesResult {
+    /// IDs of deleted images
+    pub deleted: Vec<String>,
+    /// Tags removed along the way
+    pub untagged: Vec<String>,
+    /// Human-readable total as printed by the CLI, e.g. `1.2GB`
+    #[serde(rename = "spaceReclaimed")]
+    pub space_reclaimed: Option<String>,
+}
+
+/// Builds the `image prune` arguments
+///
+/// # Returns
+/// - `Ok(Vec<String>)` with `["image", "prune", "--force", ...]`
+/// - `Err(String)` if `until` is empty or contains whitespace
+pub fn build_prune_args(options: &PruneImagesOptions) -> Result<Vec<String>, String> {
+    let mut args = vec![
+        "image".to_string(),
+        "prune".to_string(),
+        "--force".to_string(),
+    ];
+    if options.all {
+        args.push("--all".to_string());
+    }
+    if let Some(until) = &options.until {
+        if until.trim().is_empty() || until.contains(char::is_whitespace) {
+            return Err(format!("Invalid until filter: {:?}", until));
+        }
+        args.push("--filter".to_string());
+        args.push(format!("until={}", until));
+    }
+    Ok(args)
+}
+
+/// Converts an age into an `until` filter value relative to `now`
+///
+/// # Returns
+/// RFC 3339 UTC timestamp of `now - age`, e.g. `2024-05-01T12:00:00Z`
+pub fn until_timestamp(now: DateTime<Utc>, age: Duration) -> String {
+    // Ages beyond chrono's range clamp to the epoch, which every image postdates
+    let cutoff = chrono::Duration::from_std(age)
+        .ok()
+        .and_then(|age| now.checked_sub_signed(age))
+        .map_or(DateTime::UNIX_EPOCH, |cutoff| {
+            cutoff.max(DateTime::UNIX_EPOCH)
+        });
+    cutoff.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// One entry of `ImagesDeleted` in JSON prune output
+#[derive(Debug, Deserialize)]
+struct JsonPruneItem {
+    #[serde(rename = "Deleted", default)]
+    deleted: Option<String>,
+    #[serde(rename = "Untagged", default)]
+    untagged: Option<String>,
+}
+
+/// JSON prune output as printed with `--format json`
+#[derive(Debug, Deserialize)]
+struct JsonPruneReport {
+    #[serde(rename = "ImagesDeleted", default)]
+    images_deleted: Option<Vec<JsonPruneItem>>,
+    #[serde(rename = "SpaceReclaimed", default)]
+    space_reclaimed: u64,
+}
+
+/// Formats a byte count the way the Docker CLI does, e.g. `1.2GB`
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "kB", "MB", "GB", "TB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1000.0 && unit < UNITS.len() - 1 {
+        value /= 1000.0;
+        unit += 1;
+    }
+    let formatted = format!("{:.3}", value);
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+    format!("{}{}", trimmed, UNITS[unit])
+}
+
+/// Parses `image prune --format json` output
+///
+/// # Returns
+/// - `Some(PruneImagesResult)` if the output is a JSON prune report
+/// - `None` for text output, so the caller can fall back to `parse_prune_output`
+pub fn parse_prune_json(output: &str) -> Option<PruneImagesResult> {
+    let report: JsonPruneReport = serde_json::from_str(output.trim()).ok()?;
+
+    let mut result = PruneImagesResult {
+        space_reclaimed: Some(format_bytes(report.space_reclaimed)),
+        ..PruneImagesResult::default()
+    };
+    for item in report.images_deleted.unwrap_or_default() {
+        if let Some(id) = item.deleted {
+            result.deleted.push(id);
+        }
+        if let Some(reference) = item.untagged {
+            result.untagged.push(reference);
+        }
+    }
+    Some(result)
+}
+
+/// Parses `image prune` output
+///
+/// Docker prints `untagged: <ref>` / `deleted: sha256:<id>` lines and a
+/// `Total reclaimed space:` footer; Podman prints bare image IDs.
+pub fn parse_prune_output(output: &str) -> PruneImagesResult {
+    let mut result = PruneImagesResult::default();
+
+    for line in output.lines().map(str::trim) {
+        let lower = line.to_lowercase();
+        if lower.starts_with("deleted: ") {
+            result
+                .deleted
+                .push(line["deleted: ".len()..].trim().to_string());
+        } else if lower.starts_with("untagged: ") {
+            result
+                .untagged
+                .push(line["untagged: ".len()..].trim().to_string());
+        } else if let Some(space) = line.strip_prefix("Total reclaimed space:") {
+            result.space_reclaimed = Some(space.trim().to_string());
+        } else if line.len() == 64 && line.chars().all(|c| c.is_ascii_hexdigit()) {
+            result.deleted.push(line.to_string());
+        }
+    }
+    result
+}
+
+/// Runs `image prune` with the given arguments
+///
+/// # Returns
+/// - `Ok(Ok(stdout))` on success
+/// - `Ok(Err(stderr))` if the CLI exited non-zero
+/// - `Err(String)` if it couldn't be run at all
+async fn run_prune(runtime: &Runtime, args: Vec<String>) -> Result<Result<String, String>, String> {
+    let runtime = runtime.clone();
+    let output =
+        tokio::task::spawn_blocking(move || runtime_command(&runtime).args(&args).output())
+            .await
+            .map_err(|e| format!("Prune task failed: {}", e))?
+            .map_err(|e| format!("Failed to execute image prune: {}", e))?;
+
+    if output.status.success() {
+        Ok(Ok(String::from_utf8_lossy(&output.stdout).into_owned()))
+    } else {
+        Ok(Err(String::from_utf8_lossy(&output.stderr)
+            .trim()
+            .to_string()))
+    }
+}
+
+/// Removes unused images
+///
+/// Docker is asked for JSON output first; a CLI too old for `--format`
+/// rejects the flag before pruning anything, and the prune is rerun without
+/// it.
+///
+/// # Arguments
+/// * `runtime` - The runtime to prune
+/// * `options` - Whether to include tagged images and an optional `until` cutoff
+///
+/// # Returns
+/// - `Ok(PruneImagesResult)` with what was removed
+/// - `Err(String)` if the filter is invalid or the command fails
+pub async fn prune_images(
+    runtime: &Runtime,
+    options: &PruneImagesOptions,
+) -> Result<PruneImagesResult, String> {
+    let args = build_prune_args(options)?;
+
+    let result = if runtime.runtime_type == RuntimeType::Docker {
+        let mut json_args = args.clone();
+        json_args.push("--format".to_string());
+        json_args.push("json".to_string());
+        match run_prune(runtime, json_args).await? {
+            Err(stderr) if stderr.contains("unknown flag") => run_prune(runtime, args).await?,
+            result => result,
+        }
+    } else {
+        run_prune(runtime, args).await?
+    };
+
+    let stdout = result.map_err(|stderr| format!("image prune failed: {}", stderr))?;
+    Ok(parse_prune_json(&stdout).unwrap_or_else(|| parse_prune_output(&stdout)))
+}
+
+/// Removes unused images created more than `age` ago
+///
+/// Tagged images are included, since keeping recently used images is the
+/// point of an age cutoff.
+///
+/// # Arguments
+/// * `runtime` - The runtime to prune
+/// * `age` - Minimum age of images to remove
+pub async fn prune_images_older_than(
+    runtime: &Runtime,
+    age: Duration,
+) -> Result<PruneImagesResult, String> {
+    let options = PruneImagesOptions {
+        all: true,
+        until: Some(until_timestamp(Utc::now(), age)),
+    };
+    prune_images(runtime, &options).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_prune_args() {
+        assert_eq!(
+            build_prune_args(&PruneImagesOptions::default()).unwrap(),
+            vec!["image", "prune", "--force"]
+        );
+
+        let options = PruneImagesOptions {
+            all: true,
+            until: Some("24h".to_string()),
+        };
+        assert_eq!(
+            build_prune_args(&options).unwrap(),
+            vec![
+                "image",
+                "prune",
+                "--force",
+                "--all",
+                "--filter",
+                "until=24h"
+            ]
+        );
+
+        let invalid = PruneImagesOptions {
+            all: false,
+            until: Some("24 h".to_string()),
+        };
+        assert!(build_prune_args(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_until_timestamp_24h_in_the_past() {
+        let now = Utc::now();
+        let until = until_timestamp(now, Duration::from_secs(24 * 60 * 60));
+
+        assert!(until.ends_with('Z'));
+        let cutoff = DateTime::parse_from_rfc3339(&until)
+            .unwrap()
+            .with_timezone(&Utc);
+        let age = now - cutoff;
+        // Seconds precision may round the cutoff down by up to a second
+        assert!(age >= chrono::Duration::hours(24));
+        assert!(age < chrono::Duration::hours(24) + chrono::Duration::seconds(2));
+    }
+
+    #[test]
+    fn test_until_timestamp_saturates() {
+        let until = until_timestamp(Utc::now(), Duration::MAX);
+        assert_eq!(until, "1970-01-01T00:00:00Z");
+    }
+
+    #[test]
+    fn test_parse_prune_output() {
+        let docker = "Deleted Images:\nuntagged: web:1.0\nuntagged: web@sha256:4f5a\ndeleted: sha256:9c0b6a1f\ndeleted: sha256:1d34ffea\n\nTotal reclaimed space: 1.2GB\n";
+        let result = parse_prune_output(docker);
+        assert_eq!(result.deleted, vec!["sha256:9c0b6a1f", "sha256:1d34ffea"]);
+        assert_eq!(result.untagged, vec!["web:1.0", "web@sha256:4f5a"]);
+        assert_eq!(result.space_reclaimed.as_deref(), Some("1.2GB"));
+
+        let id = "9c0b6a1f8e4d2c3b5a7f9e1d0c2b4a6f8e0d1c3b5a7f9e2d4c6b8a0f1e3d5c7b";
+        let podman = parse_prune_output(&format!("{}\n", id));
+        assert_eq!(podman.deleted, vec![id]);
+        assert!(podman.space_reclaimed.is_none());
+
+        assert!(parse_prune_json(docker).is_none());
+    }
+
+    #[test]
+    fn test_parse_prune_json() {
+        let json = r#"{"ImagesDeleted":[{"Untagged":"web:1.0"},{"Deleted":"sha256:9c0b6a1f"},{"Deleted":"sha256:1d34ffea"}],"SpaceReclaimed":1234567890}"#;
+        let result = parse_prune_json(json).unwrap();
+        assert_eq!(result.deleted, vec!["sha256:9c0b6a1f", "sha256:1d34ffea"]);
+        assert_eq!(result.untagged, vec!["web:1.0"]);
+        assert_eq!(result.space_reclaimed.as_deref(), Some("1.235GB"));
+
+        let empty = parse_prune_json(r#"{"ImagesDeleted":null,"SpaceReclaimed":0}"#).unwrap();
+        assert!(empty.deleted.is_empty());
+        assert_eq!(empty.space_reclaimed.as_deref(), Some("0B"));
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512B");
+        assert_eq!(format_bytes(1_200_000_000), "1.2GB");
+        assert_eq!(format_bytes(48_000), "48kB");
+    }
+}
@@ -0,0 +1,572 @@
+//! Pushing images to a registry
+//!
+//! `push` takes no credential flags, so an authenticated push first runs
+//! `login <registry> --password-stdin` (keeping the secret out of the process
+//! list), pushes, and logs out again. All three run against a throwaway
+//! credential store (`DOCKER_CONFIG` / `REGISTRY_AUTH_FILE` pointing into a
+//! temporary directory) so the user's own logins are never touched; the
+//! active Docker context's endpoint is passed with `-H` since that store
+//! hides it. Docker prints `<layer>: Pushing`, `Pushed` and
+//! `Layer already exists` lines on stdout; each is forwarded as an
+//! `image-push-progress` event.
+
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicU64, Ordering};
+use tauri::{AppHandle, Emitter};
+
+use crate::image::progress::{
+    parse_transfer_amount, split_layer_line, LayerProgress, TransferProgress,
+};
+use crate::image::reference::{is_valid_tag, parse_image_reference};
+use crate::process::tracker::PROCESS_TRACKER;
+use crate::runtime::command::{docker_host_from_env, runtime_command};
+use crate::types::{Runtime, RuntimeType};
+
+/// Distinguishes temporary credential stores created by one process
+static AUTH_DIR_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Registry credentials for a push
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegistryAuth {
+    pub username: String,
+    pub password: String,
+}
+
+impl std::fmt::Debug for RegistryAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RegistryAuth")
+            .field("username", &self.username)
+            .field("password", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Options for `push_image`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PushOptions {
+    /// Repository to push, e.g. `ghcr.io/org/app`
+    #[serde(rename = "imageName")]
+    pub image_name: String,
+    /// Tag to push; `None` pushes `latest`
+    #[serde(default)]
+    pub tag: Option<String>,
+    #[serde(default)]
+    pub auth: Option<RegistryAuth>,
+}
+
+/// Resolves the reference to push and the registry to log in to
+///
+/// # Returns
+/// - `Ok((reference, registry))`; `registry` is `None` for Docker Hub
+/// - `Err(String)` if the name or tag is invalid
+pub fn resolve_push_target(options: &PushOptions) -> Result<(String, Option<String>), String> {
+    let parsed = parse_image_reference(&options.image_name)?;
+    if parsed.digest.is_some() {
+        return Err(format!(
+            "Cannot push a digest reference: {}",
+            options.image_name
+        ));
+    }
+
+    let tag = match (&options.tag, &parsed.tag) {
+        (Some(tag), _) => {
+            if !is_valid_tag(tag) {
+                return Err(format!("Invalid tag: {}", tag));
+            }
+            tag.clone()
+        }
+        (None, Some(tag)) => tag.clone(),
+        (None, None) => "latest".to_string(),
+    };
+
+    let mut target = parsed.clone();
+    target.tag = Some(tag);
+    Ok((target.to_string(), parsed.registry))
+}
+
+/// Builds the `login` arguments; the password is written to stdin
+pub fn build_login_args(registry: Option<&str>, username: &str) -> Vec<String> {
+    let mut args = vec!["login".to_string()];
+    if let Some(registry) = registry {
+        args.push(registry.to_string());
+    }
+    args.push("--username".to_string());
+    args.push(username.to_string());
+    args.push("--password-stdin".to_string());
+    args
+}
+
+/// Parses one line of `push` output
+///
+/// # Returns
+/// - `Some(LayerProgress)` for a layer update
+/// - `None` for summary lines such as `The push refers to repository [...]`
+pub fn parse_push_progress(line: &str) -> Option<LayerProgress> {
+    let (id, rest) = split_layer_line(line)?;
+
+    let (status, progress) = if rest.starts_with("Pushing") {
+        ("Pushing", parse_transfer_amount(rest))
+    } else {
+        (rest, None)
+    };
+    let complete =
+        matches!(status, "Pushed" | "Layer already exists") || status.starts_with("Mounted from");
+
+    Some(LayerProgress {
+        id: id.to_string(),
+        status: status.to_string(),
+        progress,
+        complete,
+    })
+}
+
+/// Removes every occurrence of `secret` from `message`
+fn redact(message: &str, secret: &str) -> String {
+    if secret.is_empty() {
+        message.to_string()
+    } else {
+        message.replace(secret, "********")
+    }
+}
+
+/// Temporary credential store used for a single authenticated push
+///
+/// The directory is removed when the value is dropped.
+struct TempAuthStore {
+    dir: PathBuf,
+}
+
+impl TempAuthStore {
+    /// Creates a new, owner-only directory under the system temp dir
+    ///
+    /// The name is unpredictable and the directory must not exist yet, so
+    /// another user can't pre-create it and read the credentials written into it.
+    fn create() -> Result<Self, String> {
+        let dir = std::env::temp_dir().join(format!(
+            "harbor-master-auth-{}-{:016x}",
+            std::process::id(),
+            random_suffix()
+        ));
+
+        let mut builder = std::fs::DirBuilder::new();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::DirBuilderExt;
+            builder.mode(0o700);
+        }
+        builder
+            .create(&dir)
+            .map_err(|e| format!("Failed to create credential store: {}", e))?;
+        let store = Self { dir };
+
+        // The umask can only narrow the mode above; make sure the owner keeps access
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&store.dir, std::fs::Permissions::from_mode(0o700))
+                .map_err(|e| format!("Failed to secure credential store: {}", e))?;
+        }
+        Ok(store)
+    }
+}
+
+/// Unpredictable 64-bit value for naming a credential store
+///
+/// `RandomState` is seeded from the OS random source; mixing in the time and
+/// a counter keeps names distinct within one process.
+fn random_suffix() -> u64 {
+    use std::hash::{BuildHasher, Hasher};
+
+    let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+    hasher.write_u64(AUTH_DIR_COUNTER.fetch_add(1, Ordering::SeqCst));
+    if let Ok(elapsed) = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH) {
+        hasher.write_u128(elapsed.as_nanos());
+    }
+    hasher.finish()
+}
+
+impl Drop for TempAuthStore {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+/// Environment variable and value pointing a runtime at the store in `dir`
+///
+/// Podman reads a single auth file from `REGISTRY_AUTH_FILE`; Docker and
+/// nerdctl read `config.json` from the `DOCKER_CONFIG` directory.
+pub fn auth_store_env(runtime_type: &RuntimeType, dir: &Path) -> (&'static str, PathBuf) {
+    match runtime_type {
+        RuntimeType::Podman => ("REGISTRY_AUTH_FILE", dir.join("auth.json")),
+        _ => ("DOCKER_CONFIG", dir.to_path_buf()),
+    }
+}
+
+/// Prints the Docker endpoint of the active context
+const CONTEXT_ENDPOINT_ARGS: [&str; 4] = [
+    "context",
+    "inspect",
+    "--format",
+    "{{.Endpoints.docker.Host}}",
+];
+
+/// Pins a Docker runtime to the daemon of the user's active context
+///
+/// Pointing `DOCKER_CONFIG` at the temporary store also hides the user's
+/// `currentContext`, which would send an authenticated push to the default
+/// socket instead of e.g. Colima or Docker Desktop's `desktop-linux`. A
+/// runtime host or `DOCKER_HOST` already takes precedence over the context
+/// and is kept as is.
+///
+/// # Arguments
+/// * `runtime` - The runtime about to be used with a temporary store
+/// * `env_host` - Value of `DOCKER_HOST`, if set
+fn pin_active_endpoint(runtime: &Runtime, env_host: Option<String>) -> Runtime {
+    let mut pinned = runtime.clone();
+    let has_host = runtime
+        .host
+        .as_deref()
+        .is_some_and(|host| !host.trim().is_empty());
+    if runtime.runtime_type != RuntimeType::Docker || has_host || env_host.is_some() {
+        return pinned;
+    }
+
+    // Context lookup is client-only, so it runs against the user's own config
+    let output = Command::new(&runtime.path)
+        .args(CONTEXT_ENDPOINT_ARGS)
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output();
+    if let Ok(output) = output {
+        let endpoint = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if output.status.success() && !endpoint.is_empty() {
+            pinned.host = Some(endpoint);
+        }
+    }
+    pinned
+}
+
+/// Creates a daemon command, using the temporary credential store if given
+fn auth_command(runtime: &Runtime, store: Option<&TempAuthStore>) -> Command {
+    let mut command = runtime_command(runtime);
+    if let Some(store) = store {
+        let (key, value) = auth_store_env(&runtime.runtime_type, &store.dir);
+        command.env(key, value);
+    }
+    command
+}
+
+/// Runs `login` with the password on stdin
+fn login(
+    runtime: &Runtime,
+    registry: Option<&str>,
+    auth: &RegistryAuth,
+    store: &TempAuthStore,
+) -> Result<(), String> {
+    let mut child = auth_command(runtime, Some(store))
+        .args(build_login_args(registry, &auth.username))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to run login: {}", e))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        if let Err(e) = stdin.write_all(auth.password.as_bytes()) {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(format!("Failed to send password to login: {}", e));
+        }
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("Failed to run login: {}", e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!(
+            "Login failed: {}",
+            redact(stderr.trim(), &auth.password)
+        ));
+    }
+    Ok(())
+}
+
+/// Runs `logout`, ignoring failures since the push already finished
+fn logout(runtime: &Runtime, registry: Option<&str>, store: &TempAuthStore) {
+    let mut command = auth_command(runtime, Some(store));
+    command.arg("logout");
+    if let Some(registry) = registry {
+        command.arg(registry);
+    }
+    let _ = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+}
+
+/// Pushes an image, streaming layer progress as `image-push-progress` events
+///
+/// The push child is registered with the process tracker so it is killed if
+/// the app exits mid-push.
+///
+/// # Arguments
+/// * `runtime` - The runtime to push with
+/// * `options` - Repository, tag and optional credentials
+/// * `app` - App handle used to emit progress events
+///
+/// # Returns
+/// - `Ok(String)` with the pushed reference
+/// - `Err(String)` with the CLI's error (e.g. `unauthorized`); credentials
+///   are never included
+pub async fn push_image(
+    runtime: &Runtime,
+    options: PushOptions,
+    app: AppHandle,
+) -> Result<String, String> {
+    let (reference, registry) = resolve_push_target(&options)?;
+    let runtime = runtime.clone();
+
+    tokio::task::spawn_blocking(move || {
+        let Some(auth) = &options.auth else {
+            return run_push(&runtime, &reference, &app, None).map(|()| reference);
+        };
+
+        let runtime = pin_active_endpoint(&runtime, docker_host_from_env());
+        let store = TempAuthStore::create()?;
+        login(&runtime, registry.as_deref(), auth, &store)?;
+        let result = run_push(&runtime, &reference, &app, Some(&store));
+        logout(&runtime, registry.as_deref(), &store);
+
+        result
+            .map(|()| reference)
+            .map_err(|e| redact(&e, &auth.password))
+    })
+    .await
+    .map_err(|e| format!("Push task failed: {}", e))?
+}
+
+/// Spawns `push` and forwards its progress lines
+fn run_push(
+    runtime: &Runtime,
+    reference: &str,
+    app: &AppHandle,
+    store: Option<&TempAuthStore>,
+) -> Result<(), String> {
+    let mut child = auth_command(runtime, store)
+        .args(["push", reference])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to run push: {}", e))?;
+
+    let stdout = child.stdout.take();
+    let stderr = child.stderr.take();
+    let handle = PROCESS_TRACKER.track(child);
+
+    // Drain stderr alongside stdout so a chatty child can't block on a full pipe
+    let stderr_reader = std::thread::spawn(move || {
+        let mut text = String::new();
+        if let Some(mut stderr) = stderr {
+            let _ = stderr.read_to_string(&mut text);
+        }
+        text
+    });
+
+    if let Some(stdout) = stdout {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            let Some(layer) = parse_push_progress(&line) else {
+                continue;
+            };
+            let progress = TransferProgress {
+                image: reference.to_string(),
+                layer,
+            };
+            if let Err(e) = app.emit("image-push-progress", &progress) {
+                eprintln!("Failed to emit push progress: {}", e);
+            }
+        }
+    }
+
+    let stderr = stderr_reader.join().unwrap_or_default();
+    match PROCESS_TRACKER.release(handle) {
+        Some(status) if status.success() => Ok(()),
+        Some(_) => Err(format!("Push failed: {}", stderr.trim())),
+        None => Err("Push was cancelled".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(image_name: &str, tag: Option<&str>) -> PushOptions {
+        PushOptions {
+            image_name: image_name.to_string(),
+            tag: tag.map(String::from),
+            auth: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_push_progress() {
+        assert_eq!(
+            parse_push_progress("5f70bf18a086: Pushing [==>        ]  1.024kB/3.2MB"),
+            Some(LayerProgress {
+                id: "5f70bf18a086".to_string(),
+                status: "Pushing".to_string(),
+                progress: Some("1.024kB/3.2MB".to_string()),
+                complete: false,
+            })
+        );
+
+        let pushed = parse_push_progress("5f70bf18a086: Pushed").unwrap();
+        assert_eq!(pushed.status, "Pushed");
+        assert!(pushed.complete);
+
+        let existing = parse_push_progress("a1b2c3d4e5f6: Layer already exists").unwrap();
+        assert_eq!(existing.status, "Layer already exists");
+        assert!(existing.complete);
+
+        let preparing = parse_push_progress("a1b2c3d4e5f6: Preparing").unwrap();
+        assert!(!preparing.complete);
+    }
+
+    #[test]
+    fn test_parse_push_progress_skips_summary_lines() {
+        assert_eq!(
+            parse_push_progress("The push refers to repository [ghcr.io/org/app]"),
+            None
+        );
+        assert_eq!(
+            parse_push_progress("1.0: digest: sha256:0123456789ab size: 1234"),
+            None
+        );
+        assert_eq!(parse_push_progress(""), None);
+    }
+
+    #[test]
+    fn test_resolve_push_target() {
+        assert_eq!(
+            resolve_push_target(&options("ghcr.io/org/app", Some("1.0"))).unwrap(),
+            (
+                "ghcr.io/org/app:1.0".to_string(),
+                Some("ghcr.io".to_string())
+            )
+        );
+        assert_eq!(
+            resolve_push_target(&options("myuser/app", None)).unwrap(),
+            ("myuser/app:latest".to_string(), None)
+        );
+        assert!(resolve_push_target(&options("app", Some("-bad"))).is_err());
+    }
+
+    #[test]
+    fn test_login_args_keep_password_off_argv() {
+        let args = build_login_args(Some("ghcr.io"), "octocat");
+        assert_eq!(
+            args,
+            vec![
+                "login",
+                "ghcr.io",
+                "--username",
+                "octocat",
+                "--password-stdin"
+            ]
+        );
+        assert!(!args.iter().any(|a| a == "--password"));
+    }
+
+    #[test]
+    fn test_auth_store_env() {
+        let dir = Path::new("/tmp/harbor-master-auth-1-0");
+        assert_eq!(
+            auth_store_env(&RuntimeType::Docker, dir),
+            ("DOCKER_CONFIG", dir.to_path_buf())
+        );
+        assert_eq!(
+            auth_store_env(&RuntimeType::Podman, dir),
+            ("REGISTRY_AUTH_FILE", dir.join("auth.json"))
+        );
+    }
+
+    #[test]
+    fn test_temp_auth_store_removed_on_drop() {
+        let store = TempAuthStore::create().unwrap();
+        let dir = store.dir.clone();
+        assert!(dir.is_dir());
+        drop(store);
+        assert!(!dir.exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_temp_auth_store_is_private_and_unique() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let first = TempAuthStore::create().unwrap();
+        let second = TempAuthStore::create().unwrap();
+        assert_ne!(first.dir, second.dir);
+
+        let mode = std::fs::metadata(&first.dir).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o700);
+    }
+
+    #[test]
+    fn test_redact_and_debug_hide_password() {
+        assert_eq!(
+            redact("denied for hunter2", "hunter2"),
+            "denied for ********"
+        );
+        let auth = RegistryAuth {
+            username: "octocat".to_string(),
+            password: "hunter2".to_string(),
+        };
+        assert!(!format!("{:?}", auth).contains("hunter2"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_auth_command_keeps_active_context_endpoint() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // A fake docker whose active context points at a Colima socket
+        let script =
+            std::env::temp_dir().join(format!("harbor-master-context-{}.sh", std::process::id()));
+        std::fs::write(
+            &script,
+            "#!/bin/sh\n[ \"$1\" = context ] && echo unix:///home/me/.colima/default/docker.sock\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let runtime = Runtime::for_test(&script.to_string_lossy());
+
+        let pinned = pin_active_endpoint(&runtime, None);
+        let store = TempAuthStore::create().unwrap();
+        let command = auth_command(&pinned, Some(&store));
+        let args: Vec<String> = command
+            .get_args()
+            .map(|arg| arg.to_string_lossy().to_string())
+            .collect();
+        assert_eq!(
+            args,
+            vec!["-H", "unix:///home/me/.colima/default/docker.sock"]
+        );
+
+        // An explicit host or DOCKER_HOST already selects the daemon
+        let remote = runtime.clone().with_host("tcp://10.0.0.5:2376");
+        assert_eq!(
+            pin_active_endpoint(&remote, None).host.as_deref(),
+            Some("tcp://10.0.0.5:2376")
+        );
+        let from_env = pin_active_endpoint(&runtime, Some("tcp://10.0.0.6:2376".to_string()));
+        assert!(from_env.host.is_none());
+
+        std::fs::remove_file(&script).ok();
+    }
+}
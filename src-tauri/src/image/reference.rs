@@ -0,0 +1,221 @@
+//! Image reference parsing and validation
+//!
+//! Implements the Docker distribution reference grammar:
+//!
+//! ```text
+//! reference := name [ ":" tag ] [ "@" digest ]
+//! name      := [ domain "/" ] path-component [ "/" path-component ]*
+//! domain    := host [ ":" port ]
+//! ```
+//!
+//! The first path segment is treated as a registry only when it looks like a
+//! host (contains `.` or `:`, is `localhost`, or has uppercase letters),
+//! matching how the Docker CLI splits `nginx` from `ghcr.io/org/app`.
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+lazy_static::lazy_static! {
+    static ref DOMAIN_RE: Regex = Regex::new(
+        r"^(?:[a-zA-Z0-9](?:[a-zA-Z0-9-]*[a-zA-Z0-9])?(?:\.[a-zA-Z0-9](?:[a-zA-Z0-9-]*[a-zA-Z0-9])?)*|\[[0-9a-fA-F:]+\])(?::[0-9]+)?$"
+    )
+    .unwrap();
+    static ref PATH_COMPONENT_RE: Regex =
+        Regex::new(r"^[a-z0-9]+(?:(?:[._]|__|-+)[a-z0-9]+)*$").unwrap();
+    static ref TAG_RE: Regex = Regex::new(r"^[\w][\w.-]{0,127}$").unwrap();
+    static ref DIGEST_RE: Regex =
+        Regex::new(r"^[A-Za-z][A-Za-z0-9]*(?:[-_+.][A-Za-z][A-Za-z0-9]*)*:[0-9a-fA-F]{32,}$")
+            .unwrap();
+}
+
+/// Maximum length of the name portion (registry + repository)
+const MAX_NAME_LENGTH: usize = 255;
+
+/// A parsed image reference such as `reg:5000/org/app:1.2@sha256:...`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageReference {
+    /// Registry host with optional port; `None` means the default registry
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registry: Option<String>,
+    /// Repository path, e.g. `nginx` or `org/app`
+    pub repository: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub digest: Option<String>,
+}
+
+impl fmt::Display for ImageReference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(registry) = &self.registry {
+            write!(f, "{}/", registry)?;
+        }
+        write!(f, "{}", self.repository)?;
+        if let Some(tag) = &self.tag {
+            write!(f, ":{}", tag)?;
+        }
+        if let Some(digest) = &self.digest {
+            write!(f, "@{}", digest)?;
+        }
+        Ok(())
+    }
+}
+
+/// Validates a tag against the Docker tag grammar (`[\w][\w.-]{0,127}`)
+pub fn is_valid_tag(tag: &str) -> bool {
+    TAG_RE.is_match(tag)
+}
+
+/// Whether the first path segment should be read as a registry host
+fn looks_like_registry(segment: &str) -> bool {
+    segment.contains('.')
+        || segment.contains(':')
+        || segment == "localhost"
+        || segment.chars().any(|c| c.is_ascii_uppercase())
+}
+
+/// Parses and validates an image reference
+///
+/// # Arguments
+/// * `input` - Reference such as `nginx`, `ghcr.io/org/app:1.2`, or
+///   `reg:5000/app@sha256:<hex>`
+///
+/// # Returns
+/// - `Ok(ImageReference)` with the registry, repository, tag and digest split out
+/// - `Err(String)` describing which part of the reference is invalid
+///
+/// # Example
+/// ```
+/// use harbor_master::image::reference::parse_image_reference;
+///
+/// let reference = parse_image_reference("ghcr.io/org/app:1.2").unwrap();
+/// assert_eq!(reference.registry.as_deref(), Some("ghcr.io"));
+/// assert_eq!(reference.repository, "org/app");
+/// assert_eq!(reference.tag.as_deref(), Some("1.2"));
+/// ```
+pub fn parse_image_reference(input: &str) -> Result<ImageReference, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err("Image reference cannot be empty".to_string());
+    }
+
+    let (name_and_tag, digest) = match input.split_once('@') {
+        Some((name, digest)) => {
+            if !DIGEST_RE.is_match(digest) {
+                return Err(format!("Invalid digest: {}", digest));
+            }
+            (name, Some(digest.to_string()))
+        }
+        None => (input, None),
+    };
+
+    // A ':' after the last '/' separates the tag; earlier ones belong to a registry port
+    let (name, tag) = match name_and_tag.rfind(':') {
+        Some(idx) if !name_and_tag[idx + 1..].contains('/') => {
+            let tag = &name_and_tag[idx + 1..];
+            if !is_valid_tag(tag) {
+                return Err(format!("Invalid tag: {}", tag));
+            }
+            (&name_and_tag[..idx], Some(tag.to_string()))
+        }
+        _ => (name_and_tag, None),
+    };
+
+    if name.is_empty() {
+        return Err("Image name cannot be empty".to_string());
+    }
+    if name.len() > MAX_NAME_LENGTH {
+        return Err(format!("Image name exceeds {} characters", MAX_NAME_LENGTH));
+    }
+
+    let (registry, repository) = match name.split_once('/') {
+        Some((first, rest)) if looks_like_registry(first) => {
+            if !DOMAIN_RE.is_match(first) {
+                return Err(format!("Invalid registry host: {}", first));
+            }
+            (Some(first.to_string()), rest)
+        }
+        _ => (None, name),
+    };
+
+    for component in repository.split('/') {
+        if !PATH_COMPONENT_RE.is_match(component) {
+            return Err(format!(
+                "Invalid repository component '{}': must be lowercase letters, digits and separators",
+                component
+            ));
+        }
+    }
+
+    Ok(ImageReference {
+        registry,
+        repository: repository.to_string(),
+        tag,
+        digest,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIGEST: &str = "sha256:0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+
+    #[test]
+    fn test_parse_simple_name() {
+        let reference = parse_image_reference("nginx").unwrap();
+        assert_eq!(reference.registry, None);
+        assert_eq!(reference.repository, "nginx");
+        assert_eq!(reference.tag, None);
+        assert_eq!(reference.digest, None);
+    }
+
+    #[test]
+    fn test_parse_registry_with_tag() {
+        let reference = parse_image_reference("ghcr.io/org/app:1.2").unwrap();
+        assert_eq!(reference.registry.as_deref(), Some("ghcr.io"));
+        assert_eq!(reference.repository, "org/app");
+        assert_eq!(reference.tag.as_deref(), Some("1.2"));
+        assert_eq!(reference.to_string(), "ghcr.io/org/app:1.2");
+    }
+
+    #[test]
+    fn test_parse_registry_port_with_digest() {
+        let input = format!("reg:5000/app@{}", DIGEST);
+        let reference = parse_image_reference(&input).unwrap();
+        assert_eq!(reference.registry.as_deref(), Some("reg:5000"));
+        assert_eq!(reference.repository, "app");
+        assert_eq!(reference.tag, None);
+        assert_eq!(reference.digest.as_deref(), Some(DIGEST));
+        assert_eq!(reference.to_string(), input);
+    }
+
+    #[test]
+    fn test_parse_user_repository_without_registry() {
+        let reference = parse_image_reference("library/nginx:1.25-alpine").unwrap();
+        assert_eq!(reference.registry, None);
+        assert_eq!(reference.repository, "library/nginx");
+        assert_eq!(reference.tag.as_deref(), Some("1.25-alpine"));
+    }
+
+    #[test]
+    fn test_parse_invalid_uppercase_repository() {
+        let result = parse_image_reference("org/MyApp");
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("MyApp"));
+    }
+
+    #[test]
+    fn test_parse_invalid_references() {
+        let invalid = vec!["", "nginx:", "nginx:-bad", "ghcr.io/", "app@sha256:short"];
+
+        for input in invalid {
+            assert!(
+                parse_image_reference(input).is_err(),
+                "Should fail for: {}",
+                input
+            );
+        }
+    }
+}
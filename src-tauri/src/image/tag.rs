@@ -0,0 +1,128 @@
+//! Image tagging
+//!
+//! `docker tag` reports a malformed target only as "invalid reference
+//! format", so the repository and tag are validated here first to give the
+//! UI a message naming the offending part.
+
+use crate::image::reference::{is_valid_tag, parse_image_reference};
+use crate::runtime::command::runtime_command;
+use crate::runtime::inspect::{validate_resource_id, ResourceKind};
+use crate::types::Runtime;
+
+/// Builds the `tag` arguments for a source image and target repository/tag
+///
+/// # Returns
+/// - `Ok(Vec<String>)` with `["tag", <source>, <repo>:<tag>]`
+/// - `Err(String)` if any part is empty, the source looks like a flag or the
+///   target is malformed
+pub fn build_tag_args(
+    source: &str,
+    target_repo: &str,
+    target_tag: &str,
+) -> Result<Vec<String>, String> {
+    let source = source.trim();
+    let target_repo = target_repo.trim();
+    let target_tag = target_tag.trim();
+
+    if source.is_empty() {
+        return Err("Source image is required".to_string());
+    }
+    validate_resource_id(ResourceKind::Image, source)?;
+    if target_repo.is_empty() {
+        return Err("Target repository is required".to_string());
+    }
+    if target_tag.is_empty() {
+        return Err("Target tag is required".to_string());
+    }
+    if !is_valid_tag(target_tag) {
+        return Err(format!(
+            "Invalid tag '{}': must start with a letter, digit or underscore and contain at most 128 letters, digits, '_', '.' or '-'",
+            target_tag
+        ));
+    }
+
+    let repo = parse_image_reference(target_repo)?;
+    if repo.tag.is_some() || repo.digest.is_some() {
+        return Err(format!(
+            "Target repository must not include a tag or digest: {}",
+            target_repo
+        ));
+    }
+
+    Ok(vec![
+        "tag".to_string(),
+        source.to_string(),
+        format!("{}:{}", repo, target_tag),
+    ])
+}
+
+/// Adds a tag to an existing image
+///
+/// # Arguments
+/// * `runtime` - The runtime to use
+/// * `source` - Image ID or reference to tag
+/// * `target_repo` - Repository for the new tag, e.g. `ghcr.io/org/app`
+/// * `target_tag` - The new tag, e.g. `1.2.0`
+///
+/// # Returns
+/// - `Ok(())` once the tag exists
+/// - `Err(String)` for an invalid target or CLI failure
+pub async fn tag_image(
+    runtime: &Runtime,
+    source: &str,
+    target_repo: &str,
+    target_tag: &str,
+) -> Result<(), String> {
+    let args = build_tag_args(source, target_repo, target_tag)?;
+
+    let runtime = runtime.clone();
+    let output =
+        tokio::task::spawn_blocking(move || runtime_command(&runtime).args(&args).output())
+            .await
+            .map_err(|e| format!("Tag task failed: {}", e))?
+            .map_err(|e| format!("Failed to execute tag: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(stderr.trim().to_string());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_tag_args() {
+        assert_eq!(
+            build_tag_args("sha256:abc123", "ghcr.io/org/app", "1.2.0").unwrap(),
+            vec!["tag", "sha256:abc123", "ghcr.io/org/app:1.2.0"]
+        );
+        assert_eq!(
+            build_tag_args("nginx:latest", "localhost:5000/nginx", "v1").unwrap(),
+            vec!["tag", "nginx:latest", "localhost:5000/nginx:v1"]
+        );
+    }
+
+    #[test]
+    fn test_build_tag_args_rejects_invalid_input() {
+        assert!(build_tag_args("", "app", "1.0").is_err());
+        assert!(build_tag_args("nginx", " ", "1.0").is_err());
+        assert!(build_tag_args("nginx", "app", "").is_err());
+        assert!(build_tag_args("nginx", "app", "-leading-dash").is_err());
+        assert!(build_tag_args("nginx", "app", &"a".repeat(129)).is_err());
+        assert!(build_tag_args("nginx", "My App", "1.0").is_err());
+        assert!(build_tag_args("nginx", "app:1.0", "2.0").is_err());
+    }
+
+    #[test]
+    fn test_build_tag_args_rejects_flag_like_source() {
+        // `docker tag --help …` exits 0 without tagging anything
+        assert_eq!(
+            build_tag_args("--help", "app", "1.0").unwrap_err(),
+            "Invalid image ID: --help"
+        );
+    }
+}
@@ -2,7 +2,13 @@
 // This file exposes the internal modules for integration tests
 
 pub mod commands;
+pub mod compose;
 pub mod config;
+pub mod container;
+pub mod image;
+pub mod network;
+pub mod platform;
 pub mod polling;
+pub mod process;
 pub mod runtime;
 pub mod types;
@@ -2,14 +2,20 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 mod commands;
+mod compose;
 mod config;
+mod container;
+mod image;
+mod network;
+mod platform;
 mod polling;
+mod process;
 mod runtime;
 mod types;
 
 use tauri::{
     menu::{MenuBuilder, MenuItemBuilder, SubmenuBuilder},
-    Emitter, Manager,
+    Emitter, Manager, RunEvent,
 };
 
 fn main() {
@@ -24,15 +30,81 @@ fn main() {
             commands::get_window_size,
             // Runtime detection commands
             commands::detect_runtimes,
+            commands::cancel_detection,
+            commands::cancel_all_operations,
             commands::get_runtime_preferences,
             commands::set_runtime_preferences,
+            commands::set_preferred_type,
+            commands::get_auto_selected_runtime,
+            commands::get_preferences_schema,
+            commands::get_audit_log,
             commands::select_runtime,
             commands::clear_detection_cache,
+            commands::refresh_all,
             // Status polling commands
             commands::start_status_polling,
             commands::stop_status_polling,
+            commands::watch_daemon_events,
+            // Runtime info
+            commands::runtime_info,
+            commands::system_disk_usage_command,
+            commands::get_insecure_registries,
+            commands::get_registry_mirrors,
+            commands::get_runtime_warnings,
+            commands::verify_runtime,
+            commands::runtime_supports,
+            commands::buildkit_enabled,
+            // Compose commands
+            commands::detect_compose,
+            commands::compose_restart,
+            // Container commands
+            commands::container_exists,
+            commands::get_resource_limits,
+            commands::get_log_config,
+            commands::container_env_overrides,
+            commands::export_logs,
+            commands::multi_container_logs,
+            commands::stop_multi_container_logs,
+            commands::run_container_command,
+            commands::exec_container_command,
+            commands::rename_container_command,
+            commands::get_port_mappings,
+            commands::stats_command,
+            commands::stream_container_stats,
+            commands::stop_container_stats,
+            commands::get_stats_history,
+            commands::inspect_raw,
+            commands::list_containers_raw,
+            commands::list_containers_brief,
+            commands::containers_health_summary,
+            commands::containers_by_label,
+            commands::pause_all_containers,
+            commands::unpause_all_containers,
+            commands::kill_all_containers,
+            // Image commands
+            commands::image_exists,
+            commands::image_history_command,
+            commands::tag_image_command,
+            commands::push_image,
+            commands::import_image,
+            commands::build_image,
+            commands::cancel_build,
+            commands::prune_images,
+            commands::prune_images_older_than,
+            commands::parse_image_reference,
+            // Network and volume commands
+            commands::network_exists,
+            commands::network::list_networks,
+            commands::network::inspect_network,
+            commands::network::create_network,
+            commands::network::remove_network,
+            commands::network::prune_networks,
+            commands::network::network_connect_command,
+            commands::network::network_disconnect_command,
+            commands::volume_exists,
             // Platform info
             commands::get_platform,
+            commands::get_platform_info,
         ])
         .setup(|app| {
             // Build the menu
@@ -63,6 +135,12 @@ fn main() {
             }
             Ok(())
         })
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|_app, event| {
+            if let RunEvent::Exit = event {
+                // Kill pulls, log follows and watchers still running on shutdown
+                process::tracker::cleanup_spawned_processes();
+            }
+        });
 }
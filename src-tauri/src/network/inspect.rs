@@ -0,0 +1,78 @@
+//! Network inspection
+//!
+//! `inspect_network` parses the full document, including attached containers.
+
+use crate::network::lifecycle::{run_network_command, validate_network_name};
+use crate::network::list::{parse_networks, Network};
+use crate::types::Runtime;
+
+/// Parses `network inspect` output into the first network
+pub fn parse_network_inspect(output: &str) -> Result<Network, String> {
+    parse_networks(output)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| "Inspect returned no networks".to_string())
+}
+
+/// Inspects a network, including the containers attached to it
+///
+/// # Arguments
+/// * `runtime` - The runtime to query
+/// * `id` - Network name or ID
+///
+/// # Returns
+/// - `Ok(Network)` with attached container IDs
+/// - `Err(String)` if the ID is invalid, the network doesn't exist or the
+///   output can't be parsed
+pub async fn inspect_network(runtime: &Runtime, id: &str) -> Result<Network, String> {
+    validate_network_name(id)?;
+    let output = run_network_command(runtime, &["inspect", id]).await?;
+    parse_network_inspect(&output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_docker_network_inspect() {
+        let output = r#"[
+            {
+                "Name": "stack_backend",
+                "Id": "9a8b7c6d5e4f",
+                "Scope": "local",
+                "Driver": "bridge",
+                "IPAM": {"Driver": "default", "Config": [{"Subnet": "172.18.0.0/16", "Gateway": "172.18.0.1"}]},
+                "Internal": false,
+                "Containers": {
+                    "e5f6a7b8": {"Name": "db", "IPv4Address": "172.18.0.3/16"},
+                    "a1b2c3d4": {"Name": "web", "IPv4Address": "172.18.0.2/16"}
+                }
+            }
+        ]"#;
+        let network = parse_network_inspect(output).unwrap();
+
+        assert_eq!(network.id, "9a8b7c6d5e4f");
+        assert_eq!(network.ipam_subnets, vec!["172.18.0.0/16"]);
+        assert_eq!(network.containers, vec!["a1b2c3d4", "e5f6a7b8"]);
+    }
+
+    #[test]
+    fn test_parse_podman_network_inspect() {
+        let output = r#"[{"name": "podman", "id": "2f259bab", "driver": "bridge", "subnets": [{"subnet": "10.88.0.0/16"}], "internal": true, "containers": {"c0ffee01": {"name": "web"}}}]"#;
+        let network = parse_network_inspect(output).unwrap();
+
+        assert_eq!(network.name, "podman");
+        assert!(network.internal);
+        assert_eq!(network.containers, vec!["c0ffee01"]);
+        assert!(parse_network_inspect("[]").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_inspect_network_rejects_flag_like_id() {
+        // `echo` would succeed, so an error means the CLI never ran
+        let runtime = Runtime::for_test("echo");
+        let result = inspect_network(&runtime, "--format=json").await;
+        assert_eq!(result.unwrap_err(), "Invalid network name: --format=json");
+    }
+}
@@ -0,0 +1,379 @@
+//! Network creation, removal, pruning and container attachment
+
+use serde::{Deserialize, Serialize};
+
+use crate::runtime::command::runtime_command;
+use crate::types::Runtime;
+
+/// Options for `network create`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CreateNetworkOptions {
+    pub name: String,
+    /// Defaults to the runtime's bridge driver when `None`
+    #[serde(default)]
+    pub driver: Option<String>,
+    /// Subnets in CIDR form
+    #[serde(default)]
+    pub subnets: Vec<String>,
+    #[serde(default)]
+    pub gateway: Option<String>,
+    #[serde(default)]
+    pub internal: bool,
+    /// `key=value` labels
+    #[serde(default)]
+    pub labels: Vec<String>,
+}
+
+/// Options for attaching a container to a network
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConnectOptions {
+    /// Extra DNS name for the container on this network
+    #[serde(default)]
+    pub alias: Option<String>,
+    /// Static IPv4 or IPv6 address on the network
+    #[serde(default)]
+    pub ip: Option<String>,
+}
+
+/// Runs `<runtime> network <args...>` and returns its stdout
+///
+/// # Returns
+/// - `Ok(String)` with the command's stdout
+/// - `Err(String)` with the CLI's stderr on failure
+pub(crate) async fn run_network_command(
+    runtime: &Runtime,
+    args: &[&str],
+) -> Result<String, String> {
+    let runtime = runtime.clone();
+    let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+
+    let output = tokio::task::spawn_blocking(move || {
+        runtime_command(&runtime)
+            .arg("network")
+            .args(&args)
+            .output()
+    })
+    .await
+    .map_err(|e| format!("Network task failed: {}", e))?
+    .map_err(|e| format!("Failed to execute network: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(stderr.trim().to_string());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Validates a network name or ID passed as a positional argument
+pub(crate) fn validate_network_name(name: &str) -> Result<(), String> {
+    if name.is_empty() || name.starts_with('-') || name.chars().any(char::is_whitespace) {
+        return Err(format!("Invalid network name: {}", name));
+    }
+    Ok(())
+}
+
+/// Builds the arguments after `network` for creating a network
+pub fn build_create_args(options: &CreateNetworkOptions) -> Result<Vec<String>, String> {
+    validate_network_name(&options.name)?;
+
+    let mut args = vec!["create".to_string()];
+    if let Some(driver) = &options.driver {
+        args.push("--driver".to_string());
+        args.push(driver.clone());
+    }
+    for subnet in &options.subnets {
+        args.push("--subnet".to_string());
+        args.push(subnet.clone());
+    }
+    if let Some(gateway) = &options.gateway {
+        args.push("--gateway".to_string());
+        args.push(gateway.clone());
+    }
+    if options.internal {
+        args.push("--internal".to_string());
+    }
+    for label in &options.labels {
+        args.push("--label".to_string());
+        args.push(label.clone());
+    }
+    args.push(options.name.clone());
+    Ok(args)
+}
+
+/// Parses `network prune` output into the removed network names
+///
+/// Docker prints a `Deleted Networks:` header; Podman prints bare names.
+pub fn parse_prune_output(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.ends_with(':'))
+        .map(String::from)
+        .collect()
+}
+
+/// Builds the arguments after `network` for connecting a container
+pub fn build_connect_args(
+    network_id: &str,
+    container_id: &str,
+    options: &ConnectOptions,
+) -> Result<Vec<String>, String> {
+    validate_network_name(network_id)?;
+    validate_network_name(container_id)?;
+
+    let mut args = vec!["connect".to_string()];
+    if let Some(alias) = &options.alias {
+        args.push("--alias".to_string());
+        args.push(alias.clone());
+    }
+    if let Some(ip) = &options.ip {
+        let flag = if ip.contains(':') { "--ip6" } else { "--ip" };
+        args.push(flag.to_string());
+        args.push(ip.clone());
+    }
+    args.push(network_id.to_string());
+    args.push(container_id.to_string());
+    Ok(args)
+}
+
+/// Rewrites the CLI's connect/disconnect errors into clear messages
+///
+/// Recognizes an already-attached container and a missing network (the
+/// wording differs between Docker and Podman); anything else is returned as is.
+pub fn describe_connect_error(network_id: &str, container_id: &str, stderr: &str) -> String {
+    let lower = stderr.to_lowercase();
+    if lower.contains("already exists in network") || lower.contains("already connected") {
+        return format!(
+            "Container {} is already connected to network {}",
+            container_id, network_id
+        );
+    }
+    if lower.contains("network")
+        && (lower.contains("not found") || lower.contains("no such network"))
+    {
+        return format!("Network {} not found", network_id);
+    }
+    stderr.to_string()
+}
+
+/// Attaches a container to a network
+///
+/// # Arguments
+/// * `runtime` - The runtime to use
+/// * `network_id` - Network name or ID
+/// * `container_id` - Container name or ID
+/// * `options` - Optional alias and static IP
+///
+/// # Returns
+/// - `Ok(())` once connected
+/// - `Err(String)`, with already-connected and unknown-network failures spelled out
+pub async fn connect_container(
+    runtime: &Runtime,
+    network_id: &str,
+    container_id: &str,
+    options: &ConnectOptions,
+) -> Result<(), String> {
+    let args = build_connect_args(network_id, container_id, options)?;
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    run_network_command(runtime, &args)
+        .await
+        .map(|_| ())
+        .map_err(|e| describe_connect_error(network_id, container_id, &e))
+}
+
+/// Detaches a container from a network
+///
+/// # Arguments
+/// * `force` - Disconnect even if the container is in a bad state
+///
+/// # Returns
+/// - `Ok(())` once disconnected
+/// - `Err(String)`, with the unknown-network failure spelled out
+pub async fn disconnect_container(
+    runtime: &Runtime,
+    network_id: &str,
+    container_id: &str,
+    force: bool,
+) -> Result<(), String> {
+    validate_network_name(network_id)?;
+    validate_network_name(container_id)?;
+
+    let mut args = vec!["disconnect"];
+    if force {
+        args.push("--force");
+    }
+    args.push(network_id);
+    args.push(container_id);
+
+    run_network_command(runtime, &args)
+        .await
+        .map(|_| ())
+        .map_err(|e| describe_connect_error(network_id, container_id, &e))
+}
+
+/// Creates a network
+///
+/// # Returns
+/// - `Ok(String)` with the new network's ID
+/// - `Err(String)` for an invalid name or the CLI's stderr
+pub async fn create_network(
+    runtime: &Runtime,
+    options: &CreateNetworkOptions,
+) -> Result<String, String> {
+    let args = build_create_args(options)?;
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let output = run_network_command(runtime, &args).await?;
+    Ok(output.trim().to_string())
+}
+
+/// Removes a network
+///
+/// # Returns
+/// - `Ok(())` once removed
+/// - `Err(String)` with the CLI's stderr, e.g. when containers are still attached
+pub async fn remove_network(runtime: &Runtime, id: &str) -> Result<(), String> {
+    validate_network_name(id)?;
+    run_network_command(runtime, &["rm", id]).await?;
+    Ok(())
+}
+
+/// Removes every network not used by a container
+///
+/// # Returns
+/// - `Ok(Vec<String>)` with the removed network names (may be empty)
+/// - `Err(String)` with the CLI's stderr
+pub async fn prune_networks(runtime: &Runtime) -> Result<Vec<String>, String> {
+    let output = run_network_command(runtime, &["prune", "--force"]).await?;
+    Ok(parse_prune_output(&output))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_create_args() {
+        let options = CreateNetworkOptions {
+            name: "backend".to_string(),
+            driver: Some("bridge".to_string()),
+            subnets: vec!["172.28.0.0/16".to_string()],
+            gateway: Some("172.28.0.1".to_string()),
+            internal: true,
+            labels: vec!["stack=web".to_string()],
+        };
+        assert_eq!(
+            build_create_args(&options).unwrap(),
+            vec![
+                "create",
+                "--driver",
+                "bridge",
+                "--subnet",
+                "172.28.0.0/16",
+                "--gateway",
+                "172.28.0.1",
+                "--internal",
+                "--label",
+                "stack=web",
+                "backend"
+            ]
+        );
+
+        let minimal = CreateNetworkOptions {
+            name: "backend".to_string(),
+            ..CreateNetworkOptions::default()
+        };
+        assert_eq!(
+            build_create_args(&minimal).unwrap(),
+            vec!["create", "backend"]
+        );
+    }
+
+    #[test]
+    fn test_build_create_args_rejects_invalid_name() {
+        for name in ["", "--internal", "my net"] {
+            let options = CreateNetworkOptions {
+                name: name.to_string(),
+                ..CreateNetworkOptions::default()
+            };
+            assert!(build_create_args(&options).is_err(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_parse_prune_output() {
+        assert_eq!(
+            parse_prune_output("Deleted Networks:\nstack_default\nold_net\n"),
+            vec!["stack_default", "old_net"]
+        );
+        assert_eq!(parse_prune_output("old_net\n"), vec!["old_net"]);
+        assert!(parse_prune_output("").is_empty());
+    }
+
+    #[test]
+    fn test_build_connect_args() {
+        assert_eq!(
+            build_connect_args("backend", "web", &ConnectOptions::default()).unwrap(),
+            vec!["connect", "backend", "web"]
+        );
+
+        let options = ConnectOptions {
+            alias: Some("api".to_string()),
+            ip: Some("172.28.0.10".to_string()),
+        };
+        assert_eq!(
+            build_connect_args("backend", "web", &options).unwrap(),
+            vec![
+                "connect",
+                "--alias",
+                "api",
+                "--ip",
+                "172.28.0.10",
+                "backend",
+                "web"
+            ]
+        );
+
+        let ipv6 = ConnectOptions {
+            ip: Some("fd00::10".to_string()),
+            ..ConnectOptions::default()
+        };
+        assert_eq!(
+            build_connect_args("backend", "web", &ipv6).unwrap()[1],
+            "--ip6"
+        );
+        assert!(build_connect_args("", "web", &ConnectOptions::default()).is_err());
+    }
+
+    #[test]
+    fn test_describe_connect_error() {
+        assert_eq!(
+            describe_connect_error(
+                "backend",
+                "web",
+                "Error response from daemon: endpoint with name web already exists in network backend"
+            ),
+            "Container web is already connected to network backend"
+        );
+        assert_eq!(
+            describe_connect_error(
+                "missing",
+                "web",
+                "Error response from daemon: network missing not found"
+            ),
+            "Network missing not found"
+        );
+        assert_eq!(
+            describe_connect_error(
+                "missing",
+                "web",
+                "Error: unable to find network with name or ID missing: network not found"
+            ),
+            "Network missing not found"
+        );
+        assert_eq!(
+            describe_connect_error("n", "c", "permission denied"),
+            "permission denied"
+        );
+    }
+}
@@ -0,0 +1,185 @@
+//! Network listing and parsing
+//!
+//! Docker prints `network ls --format json` as one object per line with
+//! PascalCase keys and stringly booleans (`"Internal": "false"`); Podman
+//! prints a single array with snake_case keys and real booleans. Inspect
+//! documents differ the same way. Both are normalized into `Network`.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::network::lifecycle::run_network_command;
+use crate::types::Runtime;
+
+/// A container network
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Network {
+    pub id: String,
+    pub name: String,
+    pub driver: String,
+    /// `local`, `swarm` or `global`
+    pub scope: String,
+    /// Whether the network has no external connectivity
+    pub internal: bool,
+    /// Subnets in CIDR form, e.g. `172.18.0.0/16`
+    #[serde(rename = "ipamSubnets")]
+    pub ipam_subnets: Vec<String>,
+    /// IDs of attached containers (inspect only; listings leave this empty)
+    pub containers: Vec<String>,
+}
+
+/// Looks up the first present key as a string
+fn string_field(entry: &Value, keys: &[&str]) -> String {
+    keys.iter()
+        .find_map(|key| entry.get(*key)?.as_str())
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Reads a boolean that may be encoded as `true` or `"true"`
+fn bool_field(entry: &Value, keys: &[&str]) -> bool {
+    keys.iter().any(|key| match entry.get(*key) {
+        Some(Value::Bool(value)) => *value,
+        Some(Value::String(value)) => value.eq_ignore_ascii_case("true"),
+        _ => false,
+    })
+}
+
+/// Normalizes one Docker or Podman network object
+pub fn parse_network_value(entry: &Value) -> Network {
+    let mut ipam_subnets: Vec<String> = Vec::new();
+    if let Some(configs) = entry["IPAM"]["Config"].as_array() {
+        ipam_subnets.extend(
+            configs
+                .iter()
+                .filter_map(|c| c["Subnet"].as_str())
+                .map(String::from),
+        );
+    }
+    if let Some(subnets) = entry["subnets"].as_array() {
+        ipam_subnets.extend(
+            subnets
+                .iter()
+                .filter_map(|s| s["subnet"].as_str())
+                .map(String::from),
+        );
+    }
+
+    let mut containers: Vec<String> = ["Containers", "containers"]
+        .iter()
+        .filter_map(|key| entry.get(*key)?.as_object())
+        .flat_map(|map| map.keys().cloned())
+        .collect();
+    containers.sort();
+
+    let scope = string_field(entry, &["Scope", "scope"]);
+
+    Network {
+        id: string_field(entry, &["ID", "Id", "id"]),
+        name: string_field(entry, &["Name", "name"]),
+        driver: string_field(entry, &["Driver", "driver"]),
+        // Podman networks are always host-local and don't report a scope
+        scope: if scope.is_empty() {
+            "local".to_string()
+        } else {
+            scope
+        },
+        internal: bool_field(entry, &["Internal", "internal"]),
+        ipam_subnets,
+        containers,
+    }
+}
+
+/// Parses JSON network output: a single array, or one object per line
+///
+/// # Returns
+/// - `Ok(Vec<Network>)` (empty for empty output)
+/// - `Err(String)` if a non-empty line isn't valid JSON
+pub fn parse_networks(output: &str) -> Result<Vec<Network>, String> {
+    let trimmed = output.trim();
+    if trimmed.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    if let Ok(Value::Array(entries)) = serde_json::from_str::<Value>(trimmed) {
+        return Ok(entries.iter().map(parse_network_value).collect());
+    }
+
+    trimmed
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str::<Value>(line)
+                .map(|entry| parse_network_value(&entry))
+                .map_err(|e| format!("Failed to parse network output: {}", e))
+        })
+        .collect()
+}
+
+/// Lists every network
+///
+/// # Arguments
+/// * `runtime` - The runtime to query
+///
+/// # Returns
+/// - `Ok(Vec<Network>)` without attached containers (listings don't report them)
+/// - `Err(String)` if the command fails or its output can't be parsed
+pub async fn list_networks(runtime: &Runtime) -> Result<Vec<Network>, String> {
+    let output = run_network_command(runtime, &["ls", "--format", "json"]).await?;
+    parse_networks(&output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCKER_LS_FIXTURE: &str = r#"{"CreatedAt":"2024-01-15 10:30:45.123456789 +0000 UTC","Driver":"bridge","ID":"4f1e9a8b7c6d","IPv6":"false","Internal":"false","Labels":"","Name":"bridge","Scope":"local"}
+{"CreatedAt":"2024-01-15 10:31:00.000000000 +0000 UTC","Driver":"bridge","ID":"9a8b7c6d5e4f","IPv6":"false","Internal":"true","Labels":"com.docker.compose.project=stack","Name":"stack_backend","Scope":"local"}
+"#;
+
+    const PODMAN_LS_FIXTURE: &str = r#"[
+ {
+  "name": "podman",
+  "id": "2f259bab93aaaaa2542ba43ef33eb990d0999ee1b9924b557b7be53c0b7a1bb9",
+  "driver": "bridge",
+  "network_interface": "podman0",
+  "created": "2024-01-15T10:30:45.123456789Z",
+  "subnets": [{"subnet": "10.88.0.0/16", "gateway": "10.88.0.1"}],
+  "ipv6_enabled": false,
+  "internal": false,
+  "dns_enabled": false
+ }
+]"#;
+
+    #[test]
+    fn test_parse_docker_network_list() {
+        let networks = parse_networks(DOCKER_LS_FIXTURE).unwrap();
+        assert_eq!(networks.len(), 2);
+
+        assert_eq!(networks[0].id, "4f1e9a8b7c6d");
+        assert_eq!(networks[0].name, "bridge");
+        assert_eq!(networks[0].driver, "bridge");
+        assert_eq!(networks[0].scope, "local");
+        assert!(!networks[0].internal);
+        assert!(networks[1].internal);
+    }
+
+    #[test]
+    fn test_parse_podman_network_list() {
+        let networks = parse_networks(PODMAN_LS_FIXTURE).unwrap();
+        assert_eq!(networks.len(), 1);
+
+        let network = &networks[0];
+        assert_eq!(network.name, "podman");
+        assert!(network.id.starts_with("2f259bab"));
+        assert_eq!(network.scope, "local");
+        assert_eq!(network.ipam_subnets, vec!["10.88.0.0/16"]);
+        assert!(!network.internal);
+    }
+
+    #[test]
+    fn test_parse_networks_empty_and_invalid() {
+        assert!(parse_networks("").unwrap().is_empty());
+        assert!(parse_networks("not json").is_err());
+    }
+}
@@ -0,0 +1,5 @@
+// Network management operations
+
+pub mod inspect;
+pub mod lifecycle;
+pub mod list;
@@ -0,0 +1,99 @@
+//! Host platform detection
+//!
+//! Reports the OS, CPU architecture, kernel version and whether the app is
+//! running inside WSL, so the UI can tailor setup guidance (e.g. Apple
+//! Silicon image platform warnings or Docker Desktop WSL integration).
+
+use crate::types::PlatformInfo;
+
+/// Checks `/proc/version` contents for the WSL kernel signature
+///
+/// # Arguments
+/// * `contents` - Contents of `/proc/version`
+fn is_wsl_kernel(contents: &str) -> bool {
+    let contents = contents.to_lowercase();
+    contents.contains("microsoft") || contents.contains("wsl")
+}
+
+/// Detects WSL by examining `/proc/version`
+#[cfg(target_os = "linux")]
+fn detect_wsl() -> bool {
+    std::fs::read_to_string("/proc/version")
+        .map(|contents| is_wsl_kernel(&contents))
+        .unwrap_or(false)
+}
+
+/// Stub for WSL detection on non-Linux platforms
+#[cfg(not(target_os = "linux"))]
+fn detect_wsl() -> bool {
+    false
+}
+
+/// Reads the running kernel release
+///
+/// # Platform Behavior
+/// - **Linux**: `/proc/sys/kernel/osrelease`
+/// - **macOS**: `uname -r`
+/// - **Windows**: not reported
+#[cfg(target_os = "linux")]
+fn kernel_version() -> Option<String> {
+    std::fs::read_to_string("/proc/sys/kernel/osrelease")
+        .ok()
+        .map(|release| release.trim().to_string())
+        .filter(|release| !release.is_empty())
+}
+
+#[cfg(target_os = "macos")]
+fn kernel_version() -> Option<String> {
+    let output = std::process::Command::new("uname")
+        .arg("-r")
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let release = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    Some(release).filter(|release| !release.is_empty())
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn kernel_version() -> Option<String> {
+    None
+}
+
+/// Collects information about the host platform
+///
+/// # Returns
+/// `PlatformInfo` with `os` and `arch` from `std::env::consts`
+pub fn platform_info() -> PlatformInfo {
+    PlatformInfo {
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        is_wsl: detect_wsl(),
+        kernel_version: kernel_version(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_platform_info_populated() {
+        let info = platform_info();
+        assert_eq!(info.os, std::env::consts::OS);
+        assert_eq!(info.arch, std::env::consts::ARCH);
+        assert!(!info.os.is_empty());
+        assert!(!info.arch.is_empty());
+    }
+
+    #[test]
+    fn test_is_wsl_kernel() {
+        assert!(is_wsl_kernel(
+            "Linux version 5.15.133.1-microsoft-standard-WSL2 (root@1c602f52c2e4)"
+        ));
+        assert!(!is_wsl_kernel(
+            "Linux version 6.5.0-14-generic (buildd@lcy02-amd64-110)"
+        ));
+    }
+}
@@ -0,0 +1,3 @@
+// Host platform information
+
+pub mod info;
@@ -0,0 +1,131 @@
+//! Crash-loop detection
+//!
+//! The poller samples each container's `RestartCount`. A container whose
+//! count grows by more than `threshold` within `window` is reported once;
+//! its history then restarts from the latest sample, so it is only reported
+//! again after another burst of restarts.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+/// Restarts within the window before a container counts as crash-looping
+pub const DEFAULT_CRASH_LOOP_THRESHOLD: u32 = 3;
+
+/// Window over which restart increases are summed
+pub const DEFAULT_CRASH_LOOP_WINDOW: Duration = Duration::from_secs(300);
+
+/// Payload of the `container-crash-looping` event
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrashLoopEvent {
+    #[serde(rename = "runtimeId")]
+    pub runtime_id: String,
+    #[serde(rename = "containerId")]
+    pub container_id: String,
+    #[serde(rename = "restartCount")]
+    pub restart_count: u32,
+    /// Restarts observed within the window
+    pub increase: u32,
+}
+
+/// Per-container restart count samples
+pub struct CrashLoopTracker {
+    threshold: u32,
+    window: Duration,
+    samples: HashMap<String, VecDeque<(Instant, u32)>>,
+}
+
+impl CrashLoopTracker {
+    /// Creates a tracker reporting more than `threshold` restarts within `window`
+    pub fn new(threshold: u32, window: Duration) -> Self {
+        Self {
+            threshold,
+            window,
+            samples: HashMap::new(),
+        }
+    }
+
+    /// Records a restart count sample
+    ///
+    /// # Arguments
+    /// * `container_id` - Container the sample belongs to
+    /// * `restart_count` - `RestartCount` from inspect
+    /// * `now` - Time of the sample
+    ///
+    /// # Returns
+    /// - `Some(increase)` the first time the container crosses the threshold
+    /// - `None` otherwise
+    pub fn observe(&mut self, container_id: &str, restart_count: u32, now: Instant) -> Option<u32> {
+        let samples = self.samples.entry(container_id.to_string()).or_default();
+
+        while samples
+            .front()
+            .is_some_and(|(at, _)| now.duration_since(*at) > self.window)
+        {
+            samples.pop_front();
+        }
+        // A lower count means the container was recreated under the same ID
+        if samples
+            .back()
+            .is_some_and(|(_, count)| restart_count < *count)
+        {
+            samples.clear();
+        }
+        samples.push_back((now, restart_count));
+
+        let oldest = samples.front().map_or(restart_count, |(_, count)| *count);
+        let increase = restart_count - oldest;
+        if increase <= self.threshold {
+            return None;
+        }
+
+        samples.clear();
+        samples.push_back((now, restart_count));
+        Some(increase)
+    }
+
+    /// Forgets containers that are no longer present
+    pub fn retain(&mut self, container_ids: &[String]) {
+        self.samples.retain(|id, _| container_ids.contains(id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_increasing_restart_counts_trigger_once() {
+        let mut tracker = CrashLoopTracker::new(3, Duration::from_secs(60));
+        let start = Instant::now();
+
+        let triggered: Vec<Option<u32>> = (0..8)
+            .map(|poll| tracker.observe("web", poll, start + Duration::from_secs(5 * poll as u64)))
+            .collect();
+
+        assert_eq!(triggered.iter().filter(|t| t.is_some()).count(), 1);
+        assert_eq!(triggered[4], Some(4));
+    }
+
+    #[test]
+    fn test_slow_restarts_outside_window_do_not_trigger() {
+        let mut tracker = CrashLoopTracker::new(3, Duration::from_secs(60));
+        let start = Instant::now();
+
+        for poll in 0..10u32 {
+            let at = start + Duration::from_secs(30 * poll as u64);
+            assert_eq!(tracker.observe("web", poll, at), None);
+        }
+    }
+
+    #[test]
+    fn test_retain_forgets_removed_containers() {
+        let mut tracker = CrashLoopTracker::new(3, Duration::from_secs(60));
+        tracker.observe("web", 1, Instant::now());
+        tracker.observe("db", 1, Instant::now());
+
+        tracker.retain(&["db".to_string()]);
+        assert!(!tracker.samples.contains_key("web"));
+        assert!(tracker.samples.contains_key("db"));
+    }
+}
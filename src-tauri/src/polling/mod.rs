@@ -1,5 +1,6 @@
 // Background status polling service
 
+pub mod crash_loop;
 pub mod service;
 
 pub use service::PollingService;
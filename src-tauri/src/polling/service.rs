@@ -1,13 +1,56 @@
 use chrono::Utc;
+use std::collections::HashMap;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter};
 use tokio::sync::{Mutex, RwLock};
 use tokio::time::interval;
 
+use crate::container::inspect::get_restart_counts;
+use crate::polling::crash_loop::{
+    CrashLoopEvent, CrashLoopTracker, DEFAULT_CRASH_LOOP_THRESHOLD, DEFAULT_CRASH_LOOP_WINDOW,
+};
 use crate::runtime::status::check_status;
 use crate::types::{Runtime, RuntimeStatus, StatusUpdate};
 
+/// Longest backoff, in multiples of the poll interval (2^5 = 32 intervals)
+const MAX_BACKOFF_EXPONENT: u32 = 5;
+
+/// Backoff state of a runtime whose status checks have been failing
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Backoff {
+    /// Consecutive failed checks, capped at `MAX_BACKOFF_EXPONENT`
+    failures: u32,
+    /// The runtime is skipped until this instant
+    next_check: Instant,
+}
+
+/// Returns whether a runtime is still backing off at `now`
+fn in_backoff(backoffs: &HashMap<String, Backoff>, runtime_id: &str, now: Instant) -> bool {
+    backoffs
+        .get(runtime_id)
+        .is_some_and(|backoff| now < backoff.next_check)
+}
+
+/// Records a failed check, pushing the next check out to `now + base * 2^failures`
+fn record_failure(
+    backoffs: &mut HashMap<String, Backoff>,
+    runtime_id: &str,
+    now: Instant,
+    base: Duration,
+) {
+    let failures = backoffs.get(runtime_id).map_or(1, |backoff| {
+        (backoff.failures + 1).min(MAX_BACKOFF_EXPONENT)
+    });
+    backoffs.insert(
+        runtime_id.to_string(),
+        Backoff {
+            failures,
+            next_check: now + base * 2u32.pow(failures),
+        },
+    );
+}
+
 /// Polling service state
 pub struct PollingService {
     /// Currently monitored runtimes
@@ -16,8 +59,13 @@ pub struct PollingService {
     is_running: Arc<Mutex<bool>>,
     /// Poll interval in seconds
     interval_secs: u64,
-    /// Failure counts for exponential backoff
-    failure_counts: Arc<RwLock<std::collections::HashMap<String, u32>>>,
+    /// Exponential backoff of failing runtimes, by runtime ID
+    backoffs: Arc<RwLock<HashMap<String, Backoff>>>,
+    /// Restart count history for crash-loop detection, per runtime
+    crash_loops: Arc<Mutex<HashMap<String, CrashLoopTracker>>>,
+    /// Restarts within `crash_loop_window` that count as a crash loop
+    crash_loop_threshold: u32,
+    crash_loop_window: Duration,
 }
 
 impl PollingService {
@@ -26,10 +74,20 @@ impl PollingService {
             runtimes: Arc::new(RwLock::new(Vec::new())),
             is_running: Arc::new(Mutex::new(false)),
             interval_secs,
-            failure_counts: Arc::new(RwLock::new(std::collections::HashMap::new())),
+            backoffs: Arc::new(RwLock::new(HashMap::new())),
+            crash_loops: Arc::new(Mutex::new(HashMap::new())),
+            crash_loop_threshold: DEFAULT_CRASH_LOOP_THRESHOLD,
+            crash_loop_window: DEFAULT_CRASH_LOOP_WINDOW,
         }
     }
 
+    /// Sets how many restarts within `window` count as a crash loop
+    pub fn with_crash_loop_config(mut self, threshold: u32, window: Duration) -> Self {
+        self.crash_loop_threshold = threshold;
+        self.crash_loop_window = window;
+        self
+    }
+
     /// Update the list of runtimes to monitor
     pub async fn set_runtimes(&self, runtimes: Vec<Runtime>) {
         let mut lock = self.runtimes.write().await;
@@ -47,7 +105,10 @@ impl PollingService {
 
         let runtimes = Arc::clone(&self.runtimes);
         let is_running_clone = Arc::clone(&self.is_running);
-        let failure_counts = Arc::clone(&self.failure_counts);
+        let backoffs = Arc::clone(&self.backoffs);
+        let crash_loops = Arc::clone(&self.crash_loops);
+        let (crash_loop_threshold, crash_loop_window) =
+            (self.crash_loop_threshold, self.crash_loop_window);
         let interval_duration = Duration::from_secs(self.interval_secs);
 
         tokio::spawn(async move {
@@ -76,40 +137,26 @@ impl PollingService {
                 for runtime in current_runtimes {
                     let runtime_id = runtime.id.clone();
 
-                    // Check if we should apply backoff
-                    let should_skip = {
-                        let failures = failure_counts.read().await;
-                        if let Some(&count) = failures.get(&runtime_id) {
-                            if count > 0 {
-                                // Exponential backoff: skip check for 2^count intervals
-                                let backoff_intervals = 2u32.pow(count.min(5));
-                                // For simplicity, we'll just skip on certain intervals
-                                // A more sophisticated implementation would track per-runtime timers
-                                use rand::Rng;
-                                rand::thread_rng().gen::<u32>() % backoff_intervals != 0
-                            } else {
-                                false
-                            }
-                        } else {
-                            false
-                        }
-                    };
-
-                    if should_skip {
+                    // Skip runtimes still backing off after failed checks
+                    if in_backoff(&*backoffs.read().await, &runtime_id, Instant::now()) {
                         continue;
                     }
 
                     let new_status = check_status(&runtime).await;
 
-                    // Update failure count
-                    let mut failures = failure_counts.write().await;
+                    // Update backoff
+                    let mut backoff_lock = backoffs.write().await;
                     if new_status == RuntimeStatus::Error || new_status == RuntimeStatus::Unknown {
-                        let count = failures.entry(runtime_id.clone()).or_insert(0);
-                        *count = (*count + 1).min(5); // Cap at 5 for max backoff of 2^5 = 32 intervals
+                        record_failure(
+                            &mut backoff_lock,
+                            &runtime_id,
+                            Instant::now(),
+                            interval_duration,
+                        );
                     } else {
-                        failures.remove(&runtime_id);
+                        backoff_lock.remove(&runtime_id);
                     }
-                    drop(failures);
+                    drop(backoff_lock);
 
                     // Emit status update event
                     let update = StatusUpdate {
@@ -122,6 +169,38 @@ impl PollingService {
                     if let Err(e) = app.emit("runtime-status-update", &update) {
                         eprintln!("Failed to emit status update: {}", e);
                     }
+
+                    // Sample restart counts of a running runtime's containers
+                    if update.status != RuntimeStatus::Running {
+                        continue;
+                    }
+                    let Ok(counts) = get_restart_counts(&runtime).await else {
+                        continue;
+                    };
+
+                    let now = Instant::now();
+                    let mut trackers = crash_loops.lock().await;
+                    let tracker = trackers.entry(runtime_id.clone()).or_insert_with(|| {
+                        CrashLoopTracker::new(crash_loop_threshold, crash_loop_window)
+                    });
+                    for (container_id, restart_count) in &counts {
+                        let Some(increase) = tracker.observe(container_id, *restart_count, now)
+                        else {
+                            continue;
+                        };
+
+                        let event = CrashLoopEvent {
+                            runtime_id: runtime_id.clone(),
+                            container_id: container_id.clone(),
+                            restart_count: *restart_count,
+                            increase,
+                        };
+                        if let Err(e) = app.emit("container-crash-looping", &event) {
+                            eprintln!("Failed to emit crash loop event: {}", e);
+                        }
+                    }
+                    let present: Vec<String> = counts.into_keys().collect();
+                    tracker.retain(&present);
                 }
             }
         });
@@ -129,6 +208,15 @@ impl PollingService {
         Ok(())
     }
 
+    /// Clears the backoff for a runtime so the next tick checks it
+    ///
+    /// Called when a daemon event signals the runtime changed state, so a
+    /// runtime that was backing off after failures is re-checked right away.
+    /// Must be called from a blocking context such as an event watcher thread.
+    pub fn reset_backoff_blocking(&self, runtime_id: &str) {
+        self.backoffs.blocking_write().remove(runtime_id);
+    }
+
     /// Stop polling
     pub async fn stop(&self) {
         let mut is_running = self.is_running.lock().await;
@@ -146,28 +234,6 @@ impl PollingService {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{RuntimeType, Version};
-
-    fn create_test_runtime(id: &str) -> Runtime {
-        Runtime {
-            id: id.to_string(),
-            runtime_type: RuntimeType::Docker,
-            path: "/usr/bin/docker".to_string(),
-            version: Version {
-                major: 24,
-                minor: 0,
-                patch: 7,
-                full: "24.0.7".to_string(),
-            },
-            status: RuntimeStatus::Unknown,
-            last_checked: Utc::now(),
-            detected_at: Utc::now(),
-            mode: None,
-            is_wsl: None,
-            error: None,
-            version_warning: None,
-        }
-    }
 
     #[tokio::test]
     async fn test_service_creation() {
@@ -175,10 +241,58 @@ mod tests {
         assert!(!service.is_running().await);
     }
 
+    #[test]
+    fn test_reset_backoff_blocking() {
+        let service = PollingService::new(5);
+        record_failure(
+            &mut service.backoffs.blocking_write(),
+            "test1",
+            Instant::now(),
+            Duration::from_secs(5),
+        );
+
+        service.reset_backoff_blocking("test1");
+        assert!(service.backoffs.blocking_read().is_empty());
+    }
+
+    #[test]
+    fn test_backoff_skip_intervals_grow() {
+        let base = Duration::from_secs(5);
+        let start = Instant::now();
+        let mut backoffs = HashMap::new();
+        assert!(!in_backoff(&backoffs, "test1", start));
+
+        // Fail on every check the backoff allows and record the wait each time
+        let mut now = start;
+        let mut waits = Vec::new();
+        for _ in 0..7 {
+            record_failure(&mut backoffs, "test1", now, base);
+            let next_check = backoffs["test1"].next_check;
+            assert!(in_backoff(
+                &backoffs,
+                "test1",
+                next_check - Duration::from_millis(1)
+            ));
+            assert!(!in_backoff(&backoffs, "test1", next_check));
+            waits.push((next_check - now).as_secs());
+            now = next_check;
+        }
+
+        assert_eq!(waits, vec![10, 20, 40, 80, 160, 160, 160]);
+        assert!(!in_backoff(&backoffs, "other", start));
+    }
+
     #[tokio::test]
     async fn test_set_runtimes() {
         let service = PollingService::new(5);
-        let runtimes = vec![create_test_runtime("test1"), create_test_runtime("test2")];
+        let runtimes = vec![
+            Runtime::for_test("/usr/bin/docker")
+                .with_id("test1")
+                .with_status(RuntimeStatus::Unknown),
+            Runtime::for_test("/usr/bin/docker")
+                .with_id("test2")
+                .with_status(RuntimeStatus::Unknown),
+        ];
 
         service.set_runtimes(runtimes.clone()).await;
 
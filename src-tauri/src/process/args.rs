@@ -0,0 +1,111 @@
+//! Splitting of long ID lists across several CLI invocations
+//!
+//! Commands that take many IDs at once are split with `run_chunked` so a
+//! host with hundreds of containers never exceeds the OS argv limit.
+
+/// Conservative budget for the IDs passed to one command
+///
+/// Well below Linux's `ARG_MAX` and Windows' 32K command line limit, leaving
+/// room for the executable path and fixed arguments.
+pub const MAX_ARGS_BYTES: usize = 16 * 1024;
+
+/// Splits IDs into chunks whose combined length stays under `max_bytes`
+///
+/// Every chunk holds at least one ID, so a single oversized ID still gets
+/// its own command rather than being dropped.
+pub fn chunk_ids(ids: &[String], max_bytes: usize) -> Vec<&[String]> {
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    let mut size = 0;
+
+    for (index, id) in ids.iter().enumerate() {
+        // One separator byte per argument
+        let len = id.len() + 1;
+        if index > start && size + len > max_bytes {
+            chunks.push(&ids[start..index]);
+            start = index;
+            size = 0;
+        }
+        size += len;
+    }
+    if start < ids.len() {
+        chunks.push(&ids[start..]);
+    }
+
+    chunks
+}
+
+/// Runs a command once per chunk of IDs and concatenates the outputs
+///
+/// # Arguments
+/// * `ids` - IDs to pass to the command
+/// * `max_bytes` - Budget for the IDs of one invocation
+/// * `runner` - Runs the command for one chunk and returns its stdout
+///
+/// # Returns
+/// - `Ok(String)` with every chunk's output in order
+/// - `Err(String)` from the first failing chunk
+pub fn run_chunked<F>(ids: &[String], max_bytes: usize, mut runner: F) -> Result<String, String>
+where
+    F: FnMut(&[String]) -> Result<String, String>,
+{
+    let mut combined = String::new();
+    for chunk in chunk_ids(ids, max_bytes) {
+        let output = runner(chunk)?;
+        combined.push_str(&output);
+        if !combined.is_empty() && !combined.ends_with('\n') {
+            combined.push('\n');
+        }
+    }
+    Ok(combined)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_chunked_splits_many_ids() {
+        let ids: Vec<String> = (0..500).map(|i| format!("{:064x}", i)).collect();
+        let mut invocations = Vec::new();
+
+        let combined = run_chunked(&ids, MAX_ARGS_BYTES, |chunk| {
+            invocations.push(chunk.len());
+            Ok(chunk.join("\n"))
+        })
+        .unwrap();
+
+        assert!(invocations.len() > 1);
+        assert_eq!(invocations.iter().sum::<usize>(), 500);
+        let lines: Vec<&str> = combined.lines().collect();
+        assert_eq!(lines.len(), 500);
+        assert_eq!(lines[0], ids[0]);
+        assert_eq!(lines[499], ids[499]);
+    }
+
+    #[test]
+    fn test_chunk_ids_bounds() {
+        let ids: Vec<String> = ["aaaa", "bbbb", "cccc"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let chunks = chunk_ids(&ids, 10);
+        assert_eq!(chunks, vec![&ids[0..2], &ids[2..3]]);
+
+        // An ID longer than the budget still gets a chunk of its own
+        assert_eq!(chunk_ids(&ids, 1).len(), 3);
+        assert!(chunk_ids(&[], 10).is_empty());
+    }
+
+    #[test]
+    fn test_run_chunked_stops_at_first_failure() {
+        let ids: Vec<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+        let mut calls = 0;
+        let result = run_chunked(&ids, 2, |_| {
+            calls += 1;
+            Err("boom".to_string())
+        });
+        assert_eq!(result, Err("boom".to_string()));
+        assert_eq!(calls, 1);
+    }
+}
@@ -0,0 +1,89 @@
+//! Cooperative cancellation for in-flight operations
+//!
+//! A `CancellationToken` is shared between an operation and whoever may
+//! abort it. Operations poll `is_cancelled()` between steps and race their
+//! slow futures against `cancelled()`.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tokio::sync::Notify;
+
+/// Cloneable handle used to signal and observe cancellation
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<TokenState>,
+}
+
+#[derive(Default)]
+struct TokenState {
+    cancelled: AtomicBool,
+    notify: Notify,
+}
+
+impl CancellationToken {
+    /// Creates a token that is not cancelled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Signals cancellation and wakes every task waiting on `cancelled()`
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+        self.inner.notify.notify_waiters();
+    }
+
+    /// Whether `cancel()` has been called
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Whether both handles refer to the same token
+    pub fn same_token(&self, other: &CancellationToken) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    /// Completes once the token is cancelled
+    pub async fn cancelled(&self) {
+        loop {
+            let notified = self.inner.notify.notified();
+            tokio::pin!(notified);
+            // Register interest before checking the flag so a concurrent
+            // cancel() between the check and the await isn't missed
+            notified.as_mut().enable();
+
+            if self.is_cancelled() {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_cancel_wakes_waiter() {
+        let token = CancellationToken::new();
+        let waiter = token.clone();
+
+        let task = tokio::spawn(async move { waiter.cancelled().await });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        token.cancel();
+
+        let result = tokio::time::timeout(Duration::from_secs(1), task).await;
+        assert!(result.is_ok());
+        assert!(token.is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn test_already_cancelled_returns_immediately() {
+        let token = CancellationToken::new();
+        token.cancel();
+
+        let result = tokio::time::timeout(Duration::from_millis(100), token.cancelled()).await;
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,65 @@
+//! Bounded line reading for child process output
+//!
+//! Output streamed from a long-running child (log follows, builds) is read
+//! line by line, but a child writing without newlines must not make the
+//! reader buffer without bound, so overlong lines are cut at `MAX_LINE_BYTES`.
+
+use std::io::{BufRead, Read};
+
+/// Longest line forwarded from a child; the rest of the line is dropped
+pub const MAX_LINE_BYTES: usize = 16 * 1024;
+
+/// Reads one line into `buf`, keeping at most `max` bytes of it
+///
+/// # Returns
+/// `Ok(false)` at end of stream
+pub fn read_bounded_line<R: BufRead>(
+    reader: &mut R,
+    buf: &mut Vec<u8>,
+    max: usize,
+) -> std::io::Result<bool> {
+    buf.clear();
+    if reader.by_ref().take(max as u64).read_until(b'\n', buf)? == 0 {
+        return Ok(false);
+    }
+
+    if buf.len() == max && buf.last() != Some(&b'\n') {
+        // Skip the rest of an overlong line without buffering it
+        loop {
+            let available = reader.fill_buf()?;
+            if available.is_empty() {
+                break;
+            }
+            match available.iter().position(|b| *b == b'\n') {
+                Some(newline) => {
+                    reader.consume(newline + 1);
+                    break;
+                }
+                None => {
+                    let len = available.len();
+                    reader.consume(len);
+                }
+            }
+        }
+    }
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufReader;
+
+    #[test]
+    fn test_read_bounded_line_truncates_long_lines() {
+        let input = format!("{}\nshort\n", "x".repeat(100));
+        let mut reader = BufReader::with_capacity(16, input.as_bytes());
+        let mut buf = Vec::new();
+
+        assert!(read_bounded_line(&mut reader, &mut buf, 10).unwrap());
+        assert_eq!(buf, b"xxxxxxxxxx");
+        assert!(read_bounded_line(&mut reader, &mut buf, 10).unwrap());
+        assert_eq!(buf, b"short\n");
+        assert!(!read_bounded_line(&mut reader, &mut buf, 10).unwrap());
+    }
+}
@@ -0,0 +1,6 @@
+// Tracking and cancellation of long-running operations, their argv chunking and output reading
+
+pub mod args;
+pub mod cancel;
+pub mod lines;
+pub mod tracker;
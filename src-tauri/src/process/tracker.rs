@@ -0,0 +1,217 @@
+//! Global tracker for spawned child processes
+//!
+//! Long-running operations (log follows, event watchers, pulls) spawn CLI
+//! children that would otherwise outlive the app when the window closes
+//! mid-operation. Registering them here lets the shutdown hook kill and reap
+//! every child that is still alive.
+
+use std::collections::HashMap;
+use std::process::{Child, ExitStatus};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+lazy_static::lazy_static! {
+    /// Process tracker shared by every module that spawns long-running children
+    pub static ref PROCESS_TRACKER: ProcessTracker = ProcessTracker::new();
+}
+
+/// Thread-safe registry of spawned child processes
+pub struct ProcessTracker {
+    /// Tracked children keyed by their tracking handle
+    children: Mutex<HashMap<u64, Child>>,
+    /// Next handle to hand out
+    next_handle: AtomicU64,
+}
+
+impl ProcessTracker {
+    /// Creates an empty tracker
+    pub fn new() -> Self {
+        Self {
+            children: Mutex::new(HashMap::new()),
+            next_handle: AtomicU64::new(1),
+        }
+    }
+
+    /// Starts tracking a child process
+    ///
+    /// Take any piped stdout/stderr from the child before tracking it, since
+    /// the tracker owns the `Child` from here on.
+    ///
+    /// # Returns
+    /// Handle used to kill or release the child later
+    pub fn track(&self, child: Child) -> u64 {
+        let handle = self.next_handle.fetch_add(1, Ordering::SeqCst);
+        if let Ok(mut children) = self.children.lock() {
+            children.insert(handle, child);
+        }
+        handle
+    }
+
+    /// Stops tracking a child that finished on its own and reaps it
+    ///
+    /// # Returns
+    /// - `Some(ExitStatus)` of the reaped child
+    /// - `None` if the handle is unknown, e.g. because the child was killed
+    pub fn release(&self, handle: u64) -> Option<ExitStatus> {
+        let mut child = self
+            .children
+            .lock()
+            .ok()
+            .and_then(|mut children| children.remove(&handle))?;
+
+        child.wait().ok()
+    }
+
+    /// Kills a single tracked child and reaps it
+    ///
+    /// # Returns
+    /// `true` if the handle was tracked
+    pub fn kill(&self, handle: u64) -> bool {
+        let child = self
+            .children
+            .lock()
+            .ok()
+            .and_then(|mut children| children.remove(&handle));
+
+        match child {
+            Some(mut child) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Kills and reaps every tracked child, reporting which handles were stopped
+    ///
+    /// Safe to call when nothing is tracked.
+    ///
+    /// # Returns
+    /// Handles of the children that were killed, in ascending order
+    pub fn cancel_all(&self) -> Vec<u64> {
+        let children: Vec<(u64, Child)> = match self.children.lock() {
+            Ok(mut children) => children.drain().collect(),
+            Err(_) => return Vec::new(),
+        };
+
+        let mut handles = Vec::with_capacity(children.len());
+        for (handle, mut child) in children {
+            let _ = child.kill();
+            let _ = child.wait();
+            handles.push(handle);
+        }
+        handles.sort_unstable();
+        handles
+    }
+
+    /// Kills and reaps every tracked child
+    ///
+    /// Safe to call when nothing is tracked.
+    ///
+    /// # Returns
+    /// Number of children that were killed
+    pub fn kill_all(&self) -> usize {
+        self.cancel_all().len()
+    }
+
+    /// Number of currently tracked children
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.children.lock().map(|c| c.len()).unwrap_or(0)
+    }
+
+    /// Whether no children are currently tracked
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for ProcessTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Kills every child registered with the global tracker
+///
+/// Called from the application exit hook so no CLI process outlives the app.
+///
+/// # Returns
+/// Number of children that were killed
+pub fn cleanup_spawned_processes() -> usize {
+    PROCESS_TRACKER.kill_all()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    fn spawn_sleeper() -> Child {
+        std::process::Command::new("sleep")
+            .arg("30")
+            .spawn()
+            .expect("failed to spawn sleep")
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_kill_all_kills_tracked_child() {
+        let tracker = ProcessTracker::new();
+        let child = spawn_sleeper();
+        let pid = child.id();
+
+        tracker.track(child);
+        assert_eq!(tracker.len(), 1);
+
+        let start = std::time::Instant::now();
+        assert_eq!(tracker.kill_all(), 1);
+        assert!(tracker.is_empty());
+
+        // The child was killed and reaped rather than left sleeping
+        assert!(start.elapsed() < std::time::Duration::from_secs(10));
+        #[cfg(target_os = "linux")]
+        assert!(!std::path::Path::new(&format!("/proc/{}", pid)).exists());
+        let _ = pid;
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_kill_single_handle() {
+        let tracker = ProcessTracker::new();
+        let first = tracker.track(spawn_sleeper());
+        let second = tracker.track(spawn_sleeper());
+
+        assert!(tracker.kill(first));
+        assert!(!tracker.kill(first));
+        assert_eq!(tracker.len(), 1);
+
+        assert!(tracker.kill(second));
+        assert!(tracker.is_empty());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_cancel_all_cancels_every_operation() {
+        let tracker = ProcessTracker::new();
+        let first = tracker.track(spawn_sleeper());
+        let second = tracker.track(spawn_sleeper());
+
+        assert_eq!(tracker.cancel_all(), vec![first, second]);
+        assert!(tracker.is_empty());
+
+        // Cancelled operations can no longer be released as finished
+        assert!(tracker.release(first).is_none());
+        assert!(tracker.release(second).is_none());
+    }
+
+    #[test]
+    fn test_kill_all_when_empty() {
+        let tracker = ProcessTracker::new();
+        assert_eq!(tracker.kill_all(), 0);
+        assert!(tracker.cancel_all().is_empty());
+        assert!(tracker.release(42).is_none());
+    }
+}
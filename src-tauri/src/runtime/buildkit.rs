@@ -0,0 +1,167 @@
+//! BuildKit default-builder detection
+//!
+//! Classic builder and BuildKit print build progress in different line
+//! formats, so the build progress parser needs to know which one a plain
+//! `docker build` will use. `DOCKER_BUILDKIT` wins when set; otherwise
+//! BuildKit is the default on Engine 23.0 and later, but only when the CLI
+//! has the `buildx` plugin (listed under `ClientInfo.Plugins`). Without it
+//! the CLI falls back to the legacy builder.
+
+use serde_json::Value;
+
+use crate::runtime::info::fetch_info_json;
+use crate::types::{Runtime, RuntimeType};
+
+/// Interprets a `DOCKER_BUILDKIT` value
+///
+/// # Returns
+/// `Some(true)` for `1`/`true`, `Some(false)` for `0`/`false`, `None` for
+/// anything else (the CLI ignores unrecognized values too)
+fn buildkit_from_env(value: &str) -> Option<bool> {
+    match value.trim().to_lowercase().as_str() {
+        "1" | "true" => Some(true),
+        "0" | "false" => Some(false),
+        _ => None,
+    }
+}
+
+/// Checks `ClientInfo.Plugins` for the `buildx` CLI plugin
+fn has_buildx_plugin(info: &Value) -> bool {
+    info["ClientInfo"]["Plugins"]
+        .as_array()
+        .is_some_and(|plugins| plugins.iter().any(|p| p["Name"] == "buildx"))
+}
+
+/// Infers the default builder from a `docker info --format json` document
+///
+/// # Returns
+/// `Some(true)` for Engine 23+ with the buildx plugin, `Some(false)` for
+/// older engines or a CLI without buildx, `None` if the server version is
+/// missing
+fn buildkit_from_info(info: &Value) -> Option<bool> {
+    let version = info["ServerVersion"].as_str()?;
+    let major: u32 = version.split('.').next()?.trim().parse().ok()?;
+    Some(major >= 23 && has_buildx_plugin(info))
+}
+
+/// Combines the environment override and the info document
+///
+/// # Arguments
+/// * `runtime_type` - Podman never builds with BuildKit, nerdctl always does
+/// * `env` - Value of `DOCKER_BUILDKIT`, if set
+/// * `info` - Parsed info document, if it could be fetched
+pub fn resolve_buildkit(
+    runtime_type: &RuntimeType,
+    env: Option<&str>,
+    info: Option<&Value>,
+) -> Option<bool> {
+    if *runtime_type == RuntimeType::Podman {
+        return Some(false);
+    }
+    if *runtime_type == RuntimeType::Nerdctl {
+        return Some(true);
+    }
+    if let Some(enabled) = env.and_then(buildkit_from_env) {
+        return Some(enabled);
+    }
+    info.and_then(buildkit_from_info)
+}
+
+/// Reports whether a plain build on this runtime uses BuildKit
+///
+/// # Arguments
+/// * `runtime` - The runtime to check
+///
+/// # Returns
+/// - `Some(bool)` when it can be determined
+/// - `None` if `DOCKER_BUILDKIT` is unset and the daemon can't be queried
+pub async fn buildkit_enabled(runtime: &Runtime) -> Option<bool> {
+    let env = std::env::var("DOCKER_BUILDKIT").ok();
+    if let Some(enabled) = resolve_buildkit(&runtime.runtime_type, env.as_deref(), None) {
+        return Some(enabled);
+    }
+
+    let info = fetch_info_json(runtime).await.ok();
+    resolve_buildkit(&runtime.runtime_type, env.as_deref(), info.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INFO_FIXTURE: &str = r#"{
+        "ServerVersion": "24.0.7",
+        "ClientInfo": {
+            "Version": "24.0.7",
+            "Plugins": [{"Name": "buildx", "Version": "v0.11.2"}]
+        }
+    }"#;
+
+    #[test]
+    fn test_buildkit_env_override() {
+        let info: Value = serde_json::from_str(INFO_FIXTURE).unwrap();
+
+        assert_eq!(
+            resolve_buildkit(&RuntimeType::Docker, Some("0"), Some(&info)),
+            Some(false)
+        );
+        assert_eq!(
+            resolve_buildkit(&RuntimeType::Docker, Some("1"), None),
+            Some(true)
+        );
+        // Unrecognized values fall through to the info document
+        assert_eq!(
+            resolve_buildkit(&RuntimeType::Docker, Some("maybe"), Some(&info)),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn test_buildkit_from_info_fixture() {
+        let info: Value = serde_json::from_str(INFO_FIXTURE).unwrap();
+        assert_eq!(
+            resolve_buildkit(&RuntimeType::Docker, None, Some(&info)),
+            Some(true)
+        );
+
+        // Engine 23+ without buildx falls back to the legacy builder
+        let no_buildx: Value = serde_json::from_str(
+            r#"{
+                "ServerVersion": "24.0.7",
+                "ClientInfo": {
+                    "Version": "24.0.7",
+                    "Plugins": [{"Name": "compose", "Version": "v2.23.0"}]
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            resolve_buildkit(&RuntimeType::Docker, None, Some(&no_buildx)),
+            Some(false)
+        );
+
+        let old: Value = serde_json::from_str(r#"{"ServerVersion": "20.10.24"}"#).unwrap();
+        assert_eq!(
+            resolve_buildkit(&RuntimeType::Docker, None, Some(&old)),
+            Some(false)
+        );
+
+        assert_eq!(resolve_buildkit(&RuntimeType::Docker, None, None), None);
+    }
+
+    #[test]
+    fn test_buildkit_podman() {
+        assert_eq!(
+            resolve_buildkit(&RuntimeType::Podman, Some("1"), None),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn test_buildkit_nerdctl() {
+        assert_eq!(
+            resolve_buildkit(&RuntimeType::Nerdctl, Some("0"), None),
+            Some(true)
+        );
+    }
+}
@@ -3,11 +3,36 @@
 //! This module provides a thread-safe cache for runtime detection results
 //! to avoid expensive repeated detections. Each cache entry expires after
 //! a configurable TTL period.
+//!
+//! Entries can be persisted to `detection-cache.json` in the config dir so a
+//! cold start skips the scan. In memory, expiry is tracked with `Instant`,
+//! which doesn't survive a restart; on load each entry's age is taken from
+//! its wall-clock `detected_at` instead.
 
+use crate::config::preferences::get_config_dir;
 use crate::types::{DetectionResult, RuntimeType};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
+/// On-disk form of one cache entry
+#[derive(Serialize, Deserialize)]
+struct PersistedEntry {
+    #[serde(rename = "runtimeType")]
+    runtime_type: RuntimeType,
+    result: DetectionResult,
+}
+
+/// Get the full path to the persisted detection cache
+pub fn get_cache_path() -> Result<PathBuf, Box<dyn Error>> {
+    Ok(get_config_dir()?.join("detection-cache.json"))
+}
+
 /// Internal cache entry with expiration timestamp
 struct CacheEntry {
     /// The cached detection result
@@ -46,6 +71,8 @@ pub struct DetectionCache {
     entries: Arc<Mutex<std::collections::HashMap<RuntimeType, CacheEntry>>>,
     /// Duration before cached entries expire
     ttl: Duration,
+    /// Whether entries changed since the last `persist_to`
+    unsaved: AtomicBool,
 }
 
 impl DetectionCache {
@@ -60,6 +87,7 @@ impl DetectionCache {
         Self {
             entries: Arc::new(Mutex::new(std::collections::HashMap::new())),
             ttl: Duration::from_secs(ttl_seconds),
+            unsaved: AtomicBool::new(false),
         }
     }
 
@@ -94,6 +122,7 @@ impl DetectionCache {
 
         if let Ok(mut entries) = self.entries.lock() {
             entries.insert(runtime_type, entry);
+            self.unsaved.store(true, Ordering::SeqCst);
         }
     }
 
@@ -105,6 +134,7 @@ impl DetectionCache {
     pub fn clear(&self, runtime_type: &RuntimeType) {
         if let Ok(mut entries) = self.entries.lock() {
             entries.remove(runtime_type);
+            self.unsaved.store(true, Ordering::SeqCst);
         }
     }
 
@@ -114,7 +144,88 @@ impl DetectionCache {
     pub fn clear_all(&self) {
         if let Ok(mut entries) = self.entries.lock() {
             entries.clear();
+            self.unsaved.store(true, Ordering::SeqCst);
+        }
+    }
+
+    /// Writes the unexpired entries to `path` as JSON
+    pub fn persist_to(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        let persisted: Vec<PersistedEntry> = {
+            let entries = self.entries.lock().map_err(|e| e.to_string())?;
+            let now = Instant::now();
+            entries
+                .iter()
+                .filter(|(_, entry)| now < entry.expires_at)
+                .map(|(runtime_type, entry)| PersistedEntry {
+                    runtime_type: runtime_type.clone(),
+                    result: entry.result.clone(),
+                })
+                .collect()
+        };
+
+        fs::write(path, serde_json::to_string_pretty(&persisted)?)?;
+        self.unsaved.store(false, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Whether entries were stored or cleared since the last `persist_to`
+    pub fn has_unsaved_changes(&self) -> bool {
+        self.unsaved.load(Ordering::SeqCst)
+    }
+
+    /// Loads entries written by `persist_to`, skipping those older than the TTL
+    ///
+    /// An entry detected `age` ago expires after the remaining `ttl - age`.
+    /// Entries dated in the future (clock changes) are treated as just
+    /// detected. A missing file loads nothing.
+    ///
+    /// # Returns
+    /// Number of entries loaded
+    pub fn load_from(&self, path: &Path) -> Result<usize, Box<dyn Error>> {
+        if !path.exists() {
+            return Ok(0);
+        }
+
+        let persisted: Vec<PersistedEntry> = serde_json::from_str(&fs::read_to_string(path)?)?;
+        let now = Utc::now();
+        let mut entries = self.entries.lock().map_err(|e| e.to_string())?;
+        let mut loaded = 0;
+
+        for PersistedEntry {
+            runtime_type,
+            result,
+        } in persisted
+        {
+            let age = (now - result.detected_at)
+                .to_std()
+                .unwrap_or(Duration::ZERO);
+            let Some(remaining) = self.ttl.checked_sub(age).filter(|r| !r.is_zero()) else {
+                continue;
+            };
+            entries.insert(
+                runtime_type,
+                CacheEntry {
+                    result,
+                    expires_at: Instant::now() + remaining,
+                },
+            );
+            loaded += 1;
         }
+
+        Ok(loaded)
+    }
+
+    /// Persists the cache to `detection-cache.json` in the config dir
+    pub fn persist_to_disk(&self) -> Result<(), Box<dyn Error>> {
+        self.persist_to(&get_cache_path()?)
+    }
+
+    /// Loads the cache from `detection-cache.json` in the config dir
+    ///
+    /// # Returns
+    /// Number of entries still within their TTL
+    pub fn load_from_disk(&self) -> Result<usize, Box<dyn Error>> {
+        self.load_from(&get_cache_path()?)
     }
 }
 
@@ -161,6 +272,39 @@ mod tests {
         assert!(cache.get(&RuntimeType::Docker).is_none());
     }
 
+    #[test]
+    fn test_persist_and_load_round_trip() {
+        let path = std::env::temp_dir().join(format!(
+            "harbor-master-detection-cache-{}.json",
+            std::process::id()
+        ));
+        let cache = DetectionCache::new(60);
+        let fresh = DetectionResult {
+            runtimes: vec![],
+            detected_at: chrono::Utc::now(),
+            duration: 100,
+            errors: vec![],
+        };
+        let stale = DetectionResult {
+            detected_at: chrono::Utc::now() - chrono::Duration::seconds(120),
+            ..fresh.clone()
+        };
+        cache.set(RuntimeType::Docker, fresh);
+        cache.set(RuntimeType::Podman, stale);
+        assert!(cache.has_unsaved_changes());
+        cache.persist_to(&path).unwrap();
+        assert!(!cache.has_unsaved_changes());
+
+        // A restarted app only keeps entries detected within the TTL
+        let restarted = DetectionCache::new(60);
+        assert_eq!(restarted.load_from(&path).unwrap(), 1);
+        assert_eq!(restarted.get(&RuntimeType::Docker).unwrap().duration, 100);
+        assert!(restarted.get(&RuntimeType::Podman).is_none());
+
+        fs::remove_file(&path).unwrap();
+        assert_eq!(DetectionCache::new(60).load_from(&path).unwrap(), 0);
+    }
+
     #[test]
     fn test_cache_clear() {
         let cache = DetectionCache::new(60);
@@ -0,0 +1,286 @@
+//! Runtime capability probing with per-runtime caching
+//!
+//! This module probes optional CLI plugins (Compose, Buildx, Scout) for a
+//! runtime and caches the result per runtime ID, so switching back and forth
+//! between runtimes doesn't re-run the probes every time. `runtime_supports`
+//! combines those probes with the runtime type and version into a single
+//! feature gate for the UI.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::time::timeout;
+
+use crate::types::{Feature, Runtime, RuntimeCapabilities, RuntimeType};
+
+/// Maximum time to wait for a single capability probe (3 seconds)
+const PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Runs `<runtime> <args...>` and reports whether it exited successfully
+///
+/// # Arguments
+/// * `path` - Path to the runtime executable
+/// * `args` - Arguments identifying the plugin, e.g. `["compose", "version"]`
+///
+/// # Returns
+/// `true` if the command succeeded within the timeout, `false` otherwise
+async fn probe_command(path: &str, args: &'static [&'static str]) -> bool {
+    let path_buf = PathBuf::from(path);
+
+    let result = timeout(PROBE_TIMEOUT, async {
+        tokio::task::spawn_blocking(move || Command::new(&path_buf).args(args).output()).await
+    })
+    .await;
+
+    matches!(result, Ok(Ok(Ok(output))) if output.status.success())
+}
+
+/// Probes the Compose, Buildx and Scout plugins for a runtime
+///
+/// # Arguments
+/// * `runtime` - The runtime to probe
+///
+/// # Returns
+/// `RuntimeCapabilities` describing which plugins responded
+pub async fn probe_capabilities(runtime: &Runtime) -> RuntimeCapabilities {
+    let (compose, buildx, scan) = tokio::join!(
+        probe_command(&runtime.path, &["compose", "version"]),
+        probe_command(&runtime.path, &["buildx", "version"]),
+        probe_command(&runtime.path, &["scout", "version"])
+    );
+
+    RuntimeCapabilities {
+        runtime_id: runtime.id.clone(),
+        compose,
+        buildx,
+        scan,
+    }
+}
+
+/// Decides whether a feature is available for a runtime
+///
+/// Plugin-backed features need a probe result; the rest depend only on the
+/// runtime type and version:
+/// - **Pods**: Podman only
+/// - **SystemDfJson**: `system df --format json` needs Docker 23+, Podman 4+
+///   or nerdctl 1+
+///
+/// # Arguments
+/// * `runtime` - The runtime to check
+/// * `capabilities` - Cached probe results, if any
+/// * `feature` - The feature the UI wants to enable
+///
+/// # Returns
+/// `true` if the feature can be used; plugin features are `false` when
+/// nothing has been probed yet
+pub fn runtime_supports(
+    runtime: &Runtime,
+    capabilities: Option<&RuntimeCapabilities>,
+    feature: Feature,
+) -> bool {
+    match feature {
+        Feature::Compose => capabilities.is_some_and(|c| c.compose),
+        Feature::Buildx => capabilities.is_some_and(|c| c.buildx),
+        Feature::Scan => capabilities.is_some_and(|c| c.scan),
+        Feature::Pods => runtime.runtime_type == RuntimeType::Podman,
+        Feature::SystemDfJson => match runtime.runtime_type {
+            RuntimeType::Docker => runtime.version.major >= 23,
+            RuntimeType::Podman => runtime.version.major >= 4,
+            RuntimeType::Nerdctl => runtime.version.major >= 1,
+        },
+    }
+}
+
+/// Thread-safe cache of probed capabilities keyed by runtime ID
+pub struct CapabilityCache {
+    entries: Mutex<HashMap<String, RuntimeCapabilities>>,
+}
+
+impl CapabilityCache {
+    /// Creates an empty capability cache
+    pub fn new() -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Retrieves cached capabilities for a runtime ID
+    pub fn get(&self, runtime_id: &str) -> Option<RuntimeCapabilities> {
+        let entries = self.entries.lock().ok()?;
+        entries.get(runtime_id).cloned()
+    }
+
+    /// Returns cached capabilities, running `probe` only on a cache miss
+    ///
+    /// # Arguments
+    /// * `runtime` - The runtime whose capabilities are requested
+    /// * `probe` - Probe function invoked when nothing is cached yet
+    pub async fn get_or_probe<'a, F, Fut>(
+        &self,
+        runtime: &'a Runtime,
+        probe: F,
+    ) -> RuntimeCapabilities
+    where
+        F: FnOnce(&'a Runtime) -> Fut,
+        Fut: Future<Output = RuntimeCapabilities>,
+    {
+        if let Some(cached) = self.get(&runtime.id) {
+            return cached;
+        }
+
+        let capabilities = probe(runtime).await;
+
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(runtime.id.clone(), capabilities.clone());
+        }
+
+        capabilities
+    }
+
+    /// Removes all cached capabilities
+    pub fn clear_all(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.clear();
+        }
+    }
+}
+
+impl Default for CapabilityCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Resolves capabilities for a newly selected runtime and reports them
+///
+/// Probes only when the runtime ID isn't cached yet, but always calls `emit`
+/// so the frontend receives the capabilities of the current selection.
+///
+/// # Arguments
+/// * `cache` - Capability cache to consult and populate
+/// * `runtime` - The selected runtime
+/// * `probe` - Probe function invoked on a cache miss
+/// * `emit` - Callback publishing the capabilities (e.g. a Tauri event)
+pub async fn refresh_capabilities<'a, F, Fut, E>(
+    cache: &CapabilityCache,
+    runtime: &'a Runtime,
+    probe: F,
+    emit: E,
+) -> Result<RuntimeCapabilities, String>
+where
+    F: FnOnce(&'a Runtime) -> Fut,
+    Fut: Future<Output = RuntimeCapabilities>,
+    E: FnOnce(&RuntimeCapabilities) -> Result<(), String>,
+{
+    let capabilities = cache.get_or_probe(runtime, probe).await;
+    emit(&capabilities)?;
+    Ok(capabilities)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn test_selection_probes_once_and_emits() {
+        let cache = CapabilityCache::new();
+        let runtime = Runtime::for_test("/nonexistent/docker").with_id("docker-test");
+        let probes = AtomicUsize::new(0);
+        let emitted = AtomicUsize::new(0);
+
+        let probe = |r: &Runtime| {
+            probes.fetch_add(1, Ordering::SeqCst);
+            let caps = RuntimeCapabilities {
+                runtime_id: r.id.clone(),
+                compose: true,
+                buildx: false,
+                scan: false,
+            };
+            async move { caps }
+        };
+
+        let caps = refresh_capabilities(&cache, &runtime, probe, |_| {
+            emitted.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(probes.load(Ordering::SeqCst), 1);
+        assert_eq!(emitted.load(Ordering::SeqCst), 1);
+        assert_eq!(caps.runtime_id, "docker-test");
+        assert!(caps.compose);
+    }
+
+    #[tokio::test]
+    async fn test_cached_capabilities_skip_probe() {
+        let cache = CapabilityCache::new();
+        let runtime = Runtime::for_test("/nonexistent/docker").with_id("docker-test");
+        let probes = AtomicUsize::new(0);
+
+        for _ in 0..2 {
+            cache
+                .get_or_probe(&runtime, |r: &Runtime| {
+                    probes.fetch_add(1, Ordering::SeqCst);
+                    let caps = RuntimeCapabilities {
+                        runtime_id: r.id.clone(),
+                        compose: false,
+                        buildx: false,
+                        scan: false,
+                    };
+                    async move { caps }
+                })
+                .await;
+        }
+
+        assert_eq!(probes.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_probe_invalid_path() {
+        let runtime = Runtime::for_test("/nonexistent/docker").with_id("docker-test");
+        let caps = probe_capabilities(&runtime).await;
+        assert!(!caps.compose);
+        assert!(!caps.buildx);
+        assert!(!caps.scan);
+    }
+
+    #[test]
+    fn test_runtime_supports_plugin_features() {
+        let runtime = Runtime::for_test("/nonexistent/docker").with_id("docker-test");
+        let caps = RuntimeCapabilities {
+            runtime_id: runtime.id.clone(),
+            compose: true,
+            buildx: false,
+            scan: true,
+        };
+
+        assert!(runtime_supports(&runtime, Some(&caps), Feature::Compose));
+        assert!(!runtime_supports(&runtime, Some(&caps), Feature::Buildx));
+        assert!(runtime_supports(&runtime, Some(&caps), Feature::Scan));
+        // Nothing probed yet
+        assert!(!runtime_supports(&runtime, None, Feature::Compose));
+    }
+
+    #[test]
+    fn test_runtime_supports_by_runtime_type() {
+        let docker = Runtime::for_test("/nonexistent/docker")
+            .with_id("docker-test")
+            .with_type(RuntimeType::Docker);
+        let mut podman = Runtime::for_test("/nonexistent/docker")
+            .with_id("podman-test")
+            .with_type(RuntimeType::Podman);
+        podman.version.major = 3;
+
+        assert!(!runtime_supports(&docker, None, Feature::Pods));
+        assert!(runtime_supports(&podman, None, Feature::Pods));
+
+        // Docker 24 supports `system df --format json`, Podman 3 doesn't
+        assert!(runtime_supports(&docker, None, Feature::SystemDfJson));
+        assert!(!runtime_supports(&podman, None, Feature::SystemDfJson));
+    }
+}
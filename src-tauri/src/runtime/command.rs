@@ -0,0 +1,82 @@
+//! CLI invocations targeting a runtime's daemon
+//!
+//! Every command that talks to the daemon is built here so a Docker runtime
+//! with a `host` (e.g. a remote engine over TCP) gets `-H <host>` ahead of
+//! its subcommand. Client-only calls such as `--version` or plugin probes
+//! don't need the daemon and keep using the executable directly.
+
+use std::process::Command;
+
+use crate::types::{Runtime, RuntimeType};
+
+/// Global CLI arguments selecting the runtime's daemon
+///
+/// # Returns
+/// `["-H", <host>]` for a Docker runtime with a host, empty otherwise
+pub fn host_args(runtime: &Runtime) -> Vec<String> {
+    match (&runtime.runtime_type, &runtime.host) {
+        (RuntimeType::Docker, Some(host)) if !host.trim().is_empty() => {
+            vec!["-H".to_string(), host.trim().to_string()]
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Creates a `Command` for the runtime executable aimed at its daemon
+///
+/// Callers append the subcommand and its arguments as usual.
+pub fn runtime_command(runtime: &Runtime) -> Command {
+    let mut command = Command::new(&runtime.path);
+    command.args(host_args(runtime));
+    command
+}
+
+/// Daemon address from `DOCKER_HOST`, if set and non-empty
+pub fn docker_host_from_env() -> Option<String> {
+    std::env::var("DOCKER_HOST")
+        .ok()
+        .map(|host| host.trim().to_string())
+        .filter(|host| !host.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_host_args() {
+        let remote = Runtime::for_test("echo")
+            .with_type(RuntimeType::Docker)
+            .with_host("tcp://10.0.0.5:2376");
+        assert_eq!(host_args(&remote), vec!["-H", "tcp://10.0.0.5:2376"]);
+
+        let local = Runtime::for_test("echo").with_type(RuntimeType::Docker);
+        assert!(host_args(&local).is_empty());
+        let blank = Runtime::for_test("echo")
+            .with_type(RuntimeType::Docker)
+            .with_host(" ");
+        assert!(host_args(&blank).is_empty());
+
+        // Podman has no `-H`; its connection comes from its own config
+        let podman = Runtime::for_test("echo")
+            .with_type(RuntimeType::Podman)
+            .with_host("tcp://10.0.0.5:2376");
+        assert!(host_args(&podman).is_empty());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_runtime_command_puts_host_first() {
+        let remote = Runtime::for_test("echo")
+            .with_type(RuntimeType::Docker)
+            .with_host("tcp://10.0.0.5:2376");
+        let output = runtime_command(&remote)
+            .args(["ps", "-q"])
+            .output()
+            .unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout).trim(),
+            "-H tcp://10.0.0.5:2376 ps -q"
+        );
+    }
+}
@@ -1,10 +1,124 @@
-use crate::runtime::{cache::DetectionCache, docker::detect_docker, podman::detect_podman};
-use crate::types::{DetectionResult, Runtime};
-use std::sync::Arc;
+use crate::process::cancel::CancellationToken;
+use crate::runtime::version::parse_minimum;
+use crate::runtime::{
+    cache::DetectionCache, docker::detect_docker, nerdctl::detect_nerdctl, podman::detect_podman,
+};
+use crate::types::{DetectionError, DetectionResult, RuntimePreferences, RuntimeType, Version};
+use chrono::Utc;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+
+/// Upper bound for a combined Docker + Podman + nerdctl detection (10 seconds)
+///
+/// Each detector also has its own `detection_timeout`, but that is only
+/// checked between steps; this deadline is shared by all probes and cuts
+/// off whichever one is still running when it passes.
+const OVERALL_DETECTION_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Waits for a spawned probe until `deadline`, aborting it if it is late
+///
+/// # Returns
+/// - `Ok(DetectionResult)` if the probe finished in time
+/// - `Err(String)` describing the timeout or task failure
+async fn await_with_deadline(
+    mut probe: JoinHandle<DetectionResult>,
+    deadline: Instant,
+) -> Result<DetectionResult, String> {
+    match tokio::time::timeout_at(deadline, &mut probe).await {
+        Ok(Ok(result)) => Ok(result),
+        Ok(Err(e)) => Err(format!("Detection task failed: {}", e)),
+        Err(_) => {
+            probe.abort();
+            Err("Detection timed out before the overall deadline".to_string())
+        }
+    }
+}
+
+/// Executable a probe looks up on PATH
+fn executable_name(runtime_type: &RuntimeType) -> &'static str {
+    match runtime_type {
+        RuntimeType::Docker => "docker",
+        RuntimeType::Podman => "podman",
+        RuntimeType::Nerdctl => "nerdctl",
+    }
+}
+
+/// Builds an empty detection result carrying a single error
+///
+/// A probe that never finished didn't report which installation it was
+/// checking, so the error names the executable it looks up instead.
+fn failed_detection(runtime_type: RuntimeType, started: Instant, error: String) -> DetectionResult {
+    DetectionResult {
+        runtimes: Vec::new(),
+        detected_at: Utc::now(),
+        duration: started.elapsed().as_millis() as u64,
+        errors: vec![DetectionError {
+            path: executable_name(&runtime_type).to_string(),
+            runtime: runtime_type,
+            error,
+        }],
+    }
+}
+
+/// Combines the per-runtime results of one detection run
+///
+/// Runtimes and errors are concatenated in order and probe durations
+/// summed, so failures such as an unparseable version reach the caller.
+fn merge_results(results: impl IntoIterator<Item = DetectionResult>) -> DetectionResult {
+    let mut merged = DetectionResult {
+        runtimes: Vec::new(),
+        detected_at: Utc::now(),
+        duration: 0,
+        errors: Vec::new(),
+    };
+    for result in results {
+        merged.runtimes.extend(result.runtimes);
+        merged.errors.extend(result.errors);
+        merged.duration += result.duration;
+    }
+    merged
+}
+
+/// Configured minimum versions below which a detected runtime gets a warning
+///
+/// `None` keeps the built-in minimum for that runtime.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct VersionMinimums {
+    docker: Option<Version>,
+    podman: Option<Version>,
+}
+
+impl VersionMinimums {
+    fn from_preferences(prefs: &RuntimePreferences) -> Self {
+        Self {
+            docker: parse_minimum(prefs.min_docker_version.as_deref()),
+            podman: parse_minimum(prefs.min_podman_version.as_deref()),
+        }
+    }
+}
+
+/// Future of a single runtime probe
+type ProbeFuture = Pin<Box<dyn Future<Output = DetectionResult> + Send>>;
+
+/// Starts the probe of one runtime type with its timeout in milliseconds
+type Probe = fn(RuntimeType, u64, VersionMinimums) -> ProbeFuture;
+
+/// Probes the system for installations of `runtime_type`
+fn run_probe(runtime_type: RuntimeType, timeout: u64, minimums: VersionMinimums) -> ProbeFuture {
+    match runtime_type {
+        RuntimeType::Docker => Box::pin(detect_docker(timeout, minimums.docker)),
+        RuntimeType::Podman => Box::pin(detect_podman(timeout, minimums.podman)),
+        RuntimeType::Nerdctl => Box::pin(detect_nerdctl(timeout)),
+    }
+}
 
 /// Runtime detector with caching capabilities
 ///
-/// Coordinates detection of Docker and Podman runtimes on the system.
+/// Coordinates detection of Docker, Podman and nerdctl runtimes on the system.
 /// Implements caching to avoid repeated expensive detection operations.
 ///
 /// # Features
@@ -15,90 +129,236 @@ use std::sync::Arc;
 pub struct RuntimeDetector {
     cache: Arc<DetectionCache>,
     detection_timeout: u64,
+    /// Read by every probe, so preference changes apply without a restart
+    minimums: Mutex<VersionMinimums>,
+    /// Starts each probe; `run_probe` except in tests
+    probe: Probe,
 }
 
 impl RuntimeDetector {
     /// Creates a new RuntimeDetector with specified cache and timeout settings
     ///
     /// # Arguments
-    /// * `cache_ttl` - Time-to-live for cached detection results in milliseconds (e.g., 60000 for 60 seconds)
+    /// * `cache_ttl` - Time-to-live for cached detection results in seconds (e.g., 60)
     /// * `detection_timeout` - Maximum time allowed for a single detection operation in milliseconds (e.g., 500)
     ///
     /// # Example
     /// ```
     /// use harbor_master::runtime::detector::RuntimeDetector;
     ///
-    /// let detector = RuntimeDetector::new(60_000, 500);
+    /// let detector = RuntimeDetector::new(60, 500);
     /// ```
     pub fn new(cache_ttl: u64, detection_timeout: u64) -> Self {
+        Self::with_cache(Arc::new(DetectionCache::new(cache_ttl)), detection_timeout)
+    }
+
+    /// Creates a detector backed by an existing cache
+    ///
+    /// Lets callers share a cache between detectors or seed it up front.
+    ///
+    /// # Arguments
+    /// * `cache` - Cache consulted before probing each runtime
+    /// * `detection_timeout` - Maximum time allowed for a single detection operation in milliseconds
+    pub fn with_cache(cache: Arc<DetectionCache>, detection_timeout: u64) -> Self {
         Self {
-            cache: Arc::new(DetectionCache::new(cache_ttl)),
+            cache,
             detection_timeout,
+            minimums: Mutex::new(VersionMinimums::default()),
+            probe: run_probe,
         }
     }
 
-    /// Detects Docker installations on the system with caching
+    /// Creates a detector whose probe timeout comes from the user's preferences
     ///
-    /// Checks cache first, performs detection if cache miss.
-    /// Detection includes PATH scanning, platform-specific locations, and WSL2 support.
+    /// Slow remote or WSL daemons may need more than the default 500ms;
+    /// `detection_timeout_ms` lets users raise it. `min_docker_version` and
+    /// `min_podman_version` decide which detected versions get a warning.
+    ///
+    /// # Arguments
+    /// * `cache_ttl` - Time-to-live for cached detection results
+    /// * `prefs` - Preferences providing the timeout and version minimums
+    pub fn from_preferences(cache_ttl: u64, prefs: &RuntimePreferences) -> Self {
+        Self {
+            minimums: Mutex::new(VersionMinimums::from_preferences(prefs)),
+            ..Self::new(cache_ttl, prefs.detection_timeout_ms)
+        }
+    }
+
+    /// Applies changed `min_docker_version` / `min_podman_version` preferences
+    ///
+    /// Cached results carry a `version_warning` computed against the old
+    /// minimums, so the cache is cleared when they change.
     ///
     /// # Returns
-    /// DetectionResult containing found Docker runtimes, errors, and detection metadata
-    pub async fn detect_docker(&self) -> DetectionResult {
-        // Check cache first
-        if let Some(cached) = self.cache.get(&crate::types::RuntimeType::Docker) {
+    /// `true` if the minimums changed and the cache was cleared
+    pub fn update_minimums(&self, prefs: &RuntimePreferences) -> bool {
+        let updated = VersionMinimums::from_preferences(prefs);
+        let changed = match self.minimums.lock() {
+            Ok(mut minimums) if *minimums != updated => {
+                *minimums = updated;
+                true
+            }
+            _ => false,
+        };
+        if changed {
+            self.clear_all_caches();
+        }
+        changed
+    }
+
+    /// Snapshot of the configured minimums handed to the next probe
+    fn minimums(&self) -> VersionMinimums {
+        self.minimums
+            .lock()
+            .map(|minimums| minimums.clone())
+            .unwrap_or_default()
+    }
+
+    /// Cache consulted before probing, e.g. to persist or restore it
+    pub fn cache(&self) -> &DetectionCache {
+        &self.cache
+    }
+
+    /// Timeout in milliseconds handed to each runtime probe
+    pub fn detection_timeout(&self) -> u64 {
+        self.detection_timeout
+    }
+
+    /// Spawns the probe for a runtime type
+    fn spawn_probe(&self, runtime_type: &RuntimeType) -> JoinHandle<DetectionResult> {
+        tokio::spawn((self.probe)(
+            runtime_type.clone(),
+            self.detection_timeout(),
+            self.minimums(),
+        ))
+    }
+
+    /// Detects a single runtime type, giving up at the shared `deadline`
+    ///
+    /// Only results of probes that finished in time are cached, so a timed
+    /// out runtime is probed again on the next detection.
+    async fn detect_before(&self, runtime_type: RuntimeType, deadline: Instant) -> DetectionResult {
+        if let Some(cached) = self.cache.get(&runtime_type) {
             return cached;
         }
 
-        // Perform detection
-        let result = detect_docker(self.detection_timeout).await;
+        let started = Instant::now();
+        match await_with_deadline(self.spawn_probe(&runtime_type), deadline).await {
+            Ok(result) => {
+                self.cache.set(runtime_type, result.clone());
+                result
+            }
+            Err(error) => failed_detection(runtime_type, started, error),
+        }
+    }
+
+    /// Detects all container runtimes (Docker, Podman and nerdctl) in parallel
+    ///
+    /// Runs each detector concurrently using tokio::join! for better performance.
+    /// All probes share one overall deadline, so a slow runtime can't stretch the
+    /// combined detection; a runtime that misses it reports a timeout error.
+    ///
+    /// # Returns
+    /// `DetectionResult` with the runtimes and errors of all probes and
+    /// their summed durations
+    pub async fn detect_all(&self) -> DetectionResult {
+        let deadline = Instant::now() + OVERALL_DETECTION_TIMEOUT;
+        let (docker_result, podman_result, nerdctl_result) = tokio::join!(
+            self.detect_before(RuntimeType::Docker, deadline),
+            self.detect_before(RuntimeType::Podman, deadline),
+            self.detect_before(RuntimeType::Nerdctl, deadline)
+        );
+
+        merge_results([docker_result, podman_result, nerdctl_result])
+    }
 
-        // Cache the result
-        self.cache
-            .set(crate::types::RuntimeType::Docker, result.clone());
+    /// Clears all caches and runs a fresh detection of every runtime
+    ///
+    /// Like `detect_all`, per-runtime errors such as missed deadlines are
+    /// included in the result.
+    ///
+    /// # Returns
+    /// Fresh `DetectionResult` combining Docker, Podman and nerdctl
+    pub async fn refresh(&self) -> DetectionResult {
+        self.clear_all_caches();
 
-        result
+        self.detect_all().await
     }
 
-    /// Detects Podman installations on the system with caching
+    /// Detects a single runtime type, aborting when `token` is cancelled
     ///
-    /// Checks cache first, performs detection if cache miss.
-    /// Detection includes PATH scanning, platform-specific locations, and rootful/rootless mode detection.
+    /// The probe runs on its own task so a hung runtime binary cannot keep
+    /// the caller waiting after cancellation; the aborted probe's result is
+    /// discarded and never cached. The shared `deadline` applies as in
+    /// `detect_all`.
     ///
     /// # Returns
-    /// DetectionResult containing found Podman runtimes, mode information, errors, and detection metadata
-    pub async fn detect_podman(&self) -> DetectionResult {
-        // Check cache first
-        if let Some(cached) = self.cache.get(&crate::types::RuntimeType::Podman) {
-            return cached;
+    /// - `Some(DetectionResult)` from cache, a completed probe, or a timeout error
+    /// - `None` if cancelled before the probe finished
+    async fn detect_cancellable(
+        &self,
+        runtime_type: RuntimeType,
+        token: &CancellationToken,
+        deadline: Instant,
+    ) -> Option<DetectionResult> {
+        if let Some(cached) = self.cache.get(&runtime_type) {
+            return Some(cached);
         }
 
-        // Perform detection
-        let result = detect_podman(self.detection_timeout).await;
+        if token.is_cancelled() {
+            return None;
+        }
 
-        // Cache the result
-        self.cache
-            .set(crate::types::RuntimeType::Podman, result.clone());
+        let started = Instant::now();
+        let probe = self.spawn_probe(&runtime_type);
+        let abort = probe.abort_handle();
 
-        result
+        tokio::select! {
+            _ = token.cancelled() => {
+                abort.abort();
+                None
+            }
+            result = await_with_deadline(probe, deadline) => match result {
+                Ok(result) => {
+                    self.cache.set(runtime_type, result.clone());
+                    Some(result)
+                }
+                Err(error) => Some(failed_detection(runtime_type, started, error)),
+            }
+        }
     }
 
-    /// Detects all container runtimes (Docker and Podman) in parallel
+    /// Detects all container runtimes like `detect_all`, but can be cancelled
     ///
-    /// Runs Docker and Podman detection concurrently using tokio::join! for better performance.
-    /// Each detection uses its own cache and timeout settings.
+    /// The token is checked before each runtime probe and raced against
+    /// in-flight probes, which share the same overall deadline.
+    ///
+    /// # Arguments
+    /// * `token` - Cancellation token shared with whoever may abort detection
     ///
     /// # Returns
-    /// Vector of all detected runtimes (Docker and Podman combined)
-    pub async fn detect_all(&self) -> Vec<Runtime> {
-        let (docker_result, podman_result) =
-            tokio::join!(self.detect_docker(), self.detect_podman());
+    /// - `Some(DetectionResult)` merged as in `detect_all`
+    /// - `None` if detection was cancelled
+    pub async fn detect_all_cancellable(
+        &self,
+        token: &CancellationToken,
+    ) -> Option<DetectionResult> {
+        let deadline = Instant::now() + OVERALL_DETECTION_TIMEOUT;
+        let (docker_result, podman_result, nerdctl_result) = tokio::join!(
+            self.detect_cancellable(RuntimeType::Docker, token, deadline),
+            self.detect_cancellable(RuntimeType::Podman, token, deadline),
+            self.detect_cancellable(RuntimeType::Nerdctl, token, deadline)
+        );
 
-        let mut all_runtimes = Vec::new();
-        all_runtimes.extend(docker_result.runtimes);
-        all_runtimes.extend(podman_result.runtimes);
+        if token.is_cancelled() {
+            return None;
+        }
 
-        all_runtimes
+        Some(merge_results([
+            docker_result?,
+            podman_result?,
+            nerdctl_result?,
+        ]))
     }
 
     /// Clears the cache for a specific runtime type
@@ -123,23 +383,112 @@ impl RuntimeDetector {
 mod tests {
     use super::*;
 
+    /// Probe that finishes at once for Docker and hangs for every other runtime
+    fn docker_only_probe(
+        runtime_type: RuntimeType,
+        _timeout: u64,
+        _minimums: VersionMinimums,
+    ) -> ProbeFuture {
+        Box::pin(async move {
+            if runtime_type != RuntimeType::Docker {
+                tokio::time::sleep(Duration::from_secs(30)).await;
+            }
+            completed_detection("probed")
+        })
+    }
+
     #[tokio::test]
     async fn test_detector_caching() {
-        let detector = RuntimeDetector::new(60, 500);
+        let mut detector = RuntimeDetector::new(60_000, 500);
+        detector.probe = docker_only_probe;
+        let deadline = Instant::now() + OVERALL_DETECTION_TIMEOUT;
 
-        // First call should detect
-        let result1 = detector.detect_docker().await;
+        // First call should probe
+        let result1 = detector.detect_before(RuntimeType::Docker, deadline).await;
+        assert!(detector.cache.get(&RuntimeType::Docker).is_some());
 
         // Second call should use cache (should be very fast)
         let start = std::time::Instant::now();
-        let result2 = detector.detect_docker().await;
+        let result2 = detector.detect_before(RuntimeType::Docker, deadline).await;
         let elapsed = start.elapsed();
 
         // Cached result should be instant (<10ms)
         assert!(elapsed.as_millis() < 10);
 
         // Results should be the same
-        assert_eq!(result1.runtimes.len(), result2.runtimes.len());
+        assert_eq!(result1.errors[0].path, result2.errors[0].path);
+    }
+
+    /// Probe reporting the timeout it was started with as its error
+    fn timeout_echo_probe(
+        runtime_type: RuntimeType,
+        timeout: u64,
+        _minimums: VersionMinimums,
+    ) -> ProbeFuture {
+        Box::pin(async move {
+            let mut result = completed_detection("echo");
+            result.errors[0].runtime = runtime_type;
+            result.errors[0].error = timeout.to_string();
+            result
+        })
+    }
+
+    #[tokio::test]
+    async fn test_configured_timeout_reaches_probes() {
+        let prefs = RuntimePreferences {
+            detection_timeout_ms: 2_000,
+            ..RuntimePreferences::default()
+        };
+        let mut detector = RuntimeDetector::from_preferences(60, &prefs);
+        detector.probe = timeout_echo_probe;
+
+        let result = detector.detect_all().await;
+        let timeouts: Vec<&str> = result.errors.iter().map(|e| e.error.as_str()).collect();
+        assert_eq!(timeouts, vec!["2000", "2000", "2000"]);
+
+        let mut default = RuntimeDetector::from_preferences(60, &RuntimePreferences::default());
+        default.probe = timeout_echo_probe;
+        let result = default.detect_all().await;
+        assert!(result.errors.iter().all(|e| e.error == "500"));
+    }
+
+    #[test]
+    fn test_configured_minimums_reach_probes() {
+        let prefs = RuntimePreferences {
+            min_docker_version: Some("24.0.0".to_string()),
+            min_podman_version: Some("not a version".to_string()),
+            ..RuntimePreferences::default()
+        };
+        let detector = RuntimeDetector::from_preferences(60, &prefs);
+        assert_eq!(
+            detector.minimums().docker.map(|v| v.full).as_deref(),
+            Some("24.0.0")
+        );
+        // Unparseable minimums keep the built-in default
+        assert!(detector.minimums().podman.is_none());
+    }
+
+    #[test]
+    fn test_update_minimums_clears_cache_only_on_change() {
+        let detector = RuntimeDetector::new(60_000, 500);
+        detector
+            .cache
+            .set(RuntimeType::Docker, completed_detection("cached"));
+
+        // Unchanged minimums keep the cache
+        assert!(!detector.update_minimums(&RuntimePreferences::default()));
+        assert!(detector.cache.get(&RuntimeType::Docker).is_some());
+
+        let prefs = RuntimePreferences {
+            min_docker_version: Some("25.0.0".to_string()),
+            ..RuntimePreferences::default()
+        };
+        assert!(detector.update_minimums(&prefs));
+        assert!(detector.cache.get(&RuntimeType::Docker).is_none());
+        assert_eq!(
+            detector.minimums().docker.map(|v| v.full).as_deref(),
+            Some("25.0.0")
+        );
     }
 
     #[tokio::test]
@@ -147,9 +496,103 @@ mod tests {
         let detector = RuntimeDetector::new(60, 500);
 
         // Should detect both Docker and Podman (returns empty vec if neither installed)
-        let all_runtimes = detector.detect_all().await;
+        let result = detector.detect_all().await;
 
         // Result should be valid
-        assert!(all_runtimes.is_empty() || !all_runtimes.is_empty());
+        assert!(result.runtimes.is_empty() || !result.runtimes.is_empty());
+    }
+
+    fn completed_detection(path: &str) -> DetectionResult {
+        DetectionResult {
+            runtimes: Vec::new(),
+            detected_at: Utc::now(),
+            duration: 0,
+            errors: vec![DetectionError {
+                runtime: RuntimeType::Docker,
+                path: path.to_string(),
+                error: "marker".to_string(),
+            }],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_shared_deadline_cuts_off_slow_detector() {
+        let mut detector = RuntimeDetector::new(60_000, 500);
+        detector.probe = docker_only_probe;
+        let started = Instant::now();
+        let deadline = started + Duration::from_millis(200);
+
+        let (docker, podman, nerdctl) = tokio::join!(
+            detector.detect_before(RuntimeType::Docker, deadline),
+            detector.detect_before(RuntimeType::Podman, deadline),
+            detector.detect_before(RuntimeType::Nerdctl, deadline)
+        );
+
+        // All probes were bounded by the one shared deadline
+        assert!(started.elapsed() < Duration::from_secs(2));
+
+        assert_eq!(docker.errors[0].path, "probed");
+        for (result, path) in [(podman, "podman"), (nerdctl, "nerdctl")] {
+            assert!(result.runtimes.is_empty());
+            assert_eq!(result.errors[0].path, path);
+            assert!(result.errors[0].error.contains("timed out"));
+        }
+
+        // Only the probe that finished in time is cached
+        assert!(detector.cache.get(&RuntimeType::Docker).is_some());
+        assert!(detector.cache.get(&RuntimeType::Podman).is_none());
+        assert!(detector.cache.get(&RuntimeType::Nerdctl).is_none());
+    }
+
+    #[test]
+    fn test_merge_results_sums_durations_and_keeps_errors() {
+        let mut docker = completed_detection("/usr/bin/docker");
+        docker.duration = 120;
+        let mut podman = completed_detection("/usr/bin/podman");
+        podman.duration = 80;
+
+        let mut nerdctl = completed_detection("/usr/bin/nerdctl");
+        nerdctl.duration = 30;
+
+        let merged = merge_results([docker, podman, nerdctl]);
+        assert_eq!(merged.duration, 230);
+        let paths: Vec<&str> = merged.errors.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec!["/usr/bin/docker", "/usr/bin/podman", "/usr/bin/nerdctl"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_refresh_clears_cache_and_detects_fresh() {
+        let detector = RuntimeDetector::new(60_000, 500);
+        detector
+            .cache
+            .set(RuntimeType::Docker, completed_detection("stale"));
+        assert!(detector.cache.get(&RuntimeType::Docker).is_some());
+
+        let result = detector.refresh().await;
+
+        assert!(!result.errors.iter().any(|e| e.path == "stale"));
+        let cached = detector.cache.get(&RuntimeType::Docker);
+        assert!(cached.is_none_or(|c| !c.errors.iter().any(|e| e.path == "stale")));
+    }
+
+    #[tokio::test]
+    async fn test_detect_all_cancelled_before_start() {
+        let detector = RuntimeDetector::new(60, 500);
+        let token = CancellationToken::new();
+        token.cancel();
+
+        assert!(detector.detect_all_cancellable(&token).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_detect_all_cancellable_completes() {
+        let detector = RuntimeDetector::new(60, 500);
+        let token = CancellationToken::new();
+
+        let result = detector.detect_all_cancellable(&token).await;
+        assert!(result.is_some());
     }
 }
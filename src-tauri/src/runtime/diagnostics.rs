@@ -0,0 +1,326 @@
+//! One-shot runtime diagnostics
+//!
+//! `verify_runtime` runs every check a support request usually needs
+//! (executable, daemon, version, socket) and reports each one with a
+//! human-readable message instead of stopping at the first failure.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+use tokio::time::timeout;
+
+use crate::config::preferences::load_preferences;
+#[cfg(unix)]
+use crate::runtime::command::docker_host_from_env;
+use crate::runtime::status::check_status;
+use crate::runtime::version::{
+    parse_minimum, parse_version, validate_docker_version, validate_nerdctl_version,
+    validate_podman_version, MIN_DOCKER_VERSION, MIN_NERDCTL_VERSION, MIN_PODMAN_VERSION,
+};
+use crate::types::{Runtime, RuntimePreferences, RuntimeStatus, RuntimeType, Version};
+
+/// Maximum time to wait for `--version` (3 seconds)
+const VERSION_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Result of a single diagnostic check
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiagnosticCheck {
+    pub passed: bool,
+    pub message: String,
+}
+
+impl DiagnosticCheck {
+    fn pass(message: impl Into<String>) -> Self {
+        Self {
+            passed: true,
+            message: message.into(),
+        }
+    }
+
+    fn fail(message: impl Into<String>) -> Self {
+        Self {
+            passed: false,
+            message: message.into(),
+        }
+    }
+}
+
+/// Outcome of every diagnostic check for a runtime
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuntimeDiagnostics {
+    #[serde(rename = "runtimeId")]
+    pub runtime_id: String,
+    /// Whether every check passed
+    pub passed: bool,
+    /// Executable exists and has execute permissions
+    pub executable: DiagnosticCheck,
+    /// Daemon (or Podman service) answers `info`
+    pub daemon: DiagnosticCheck,
+    /// `--version` parses and meets the minimum supported version
+    pub version: DiagnosticCheck,
+    /// API socket exists at the expected path
+    pub socket: DiagnosticCheck,
+    /// Socket path that was checked, if one applies on this platform
+    #[serde(rename = "socketPath")]
+    pub socket_path: Option<String>,
+}
+
+/// Checks that the runtime executable exists and can be executed
+fn check_executable(path: &str) -> DiagnosticCheck {
+    let path = Path::new(path);
+
+    let metadata = match std::fs::metadata(path) {
+        Ok(metadata) => metadata,
+        Err(e) => {
+            return DiagnosticCheck::fail(format!(
+                "Executable not found at {}: {}",
+                path.display(),
+                e
+            ))
+        }
+    };
+
+    if !metadata.is_file() {
+        return DiagnosticCheck::fail(format!("{} is not a file", path.display()));
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if metadata.permissions().mode() & 0o111 == 0 {
+            return DiagnosticCheck::fail(format!(
+                "{} is not executable (missing execute permission)",
+                path.display()
+            ));
+        }
+    }
+
+    DiagnosticCheck::pass(format!("Executable found at {}", path.display()))
+}
+
+/// Checks that the daemon responds
+async fn check_daemon(runtime: &Runtime) -> DiagnosticCheck {
+    match check_status(runtime).await {
+        RuntimeStatus::Running => DiagnosticCheck::pass("Daemon is reachable"),
+        RuntimeStatus::Stopped => {
+            DiagnosticCheck::fail("Daemon is not running or the CLI could not connect to it")
+        }
+        RuntimeStatus::Error => {
+            DiagnosticCheck::fail("Permission denied while connecting to the daemon")
+        }
+        RuntimeStatus::Unknown => DiagnosticCheck::fail("Daemon did not respond in time"),
+    }
+}
+
+/// Minimum version for a runtime type, honoring configured minimums
+///
+/// An absent or unparseable `min_docker_version` / `min_podman_version`
+/// falls back to the built-in minimum, as detection does.
+fn required_minimum(runtime_type: &RuntimeType, prefs: &RuntimePreferences) -> Version {
+    match runtime_type {
+        RuntimeType::Docker => {
+            parse_minimum(prefs.min_docker_version.as_deref()).unwrap_or(MIN_DOCKER_VERSION)
+        }
+        RuntimeType::Podman => {
+            parse_minimum(prefs.min_podman_version.as_deref()).unwrap_or(MIN_PODMAN_VERSION)
+        }
+        RuntimeType::Nerdctl => MIN_NERDCTL_VERSION,
+    }
+}
+
+/// Checks that `--version` parses and meets the minimum supported version
+async fn check_version(runtime: &Runtime) -> DiagnosticCheck {
+    let path_buf = PathBuf::from(&runtime.path);
+
+    let result = timeout(VERSION_TIMEOUT, async {
+        tokio::task::spawn_blocking(move || Command::new(&path_buf).arg("--version").output()).await
+    })
+    .await;
+
+    let output = match result {
+        Ok(Ok(Ok(output))) if output.status.success() => output,
+        Ok(Ok(Ok(_))) => return DiagnosticCheck::fail("Version command exited with an error"),
+        Ok(Ok(Err(e))) => {
+            return DiagnosticCheck::fail(format!("Failed to execute version command: {}", e))
+        }
+        Ok(Err(e)) => return DiagnosticCheck::fail(format!("Version task failed: {}", e)),
+        Err(_) => return DiagnosticCheck::fail("Version command timed out"),
+    };
+
+    let version_str = String::from_utf8_lossy(&output.stdout);
+    let version = match parse_version(version_str.trim()) {
+        Ok(version) => version,
+        Err(e) => return DiagnosticCheck::fail(format!("Could not parse version: {}", e)),
+    };
+
+    let prefs = load_preferences().unwrap_or_default();
+    let minimum = required_minimum(&runtime.runtime_type, &prefs);
+    let supported = match runtime.runtime_type {
+        RuntimeType::Docker => validate_docker_version(&version, Some(&minimum)),
+        RuntimeType::Podman => validate_podman_version(&version, Some(&minimum)),
+        RuntimeType::Nerdctl => validate_nerdctl_version(&version),
+    };
+
+    if supported {
+        DiagnosticCheck::pass(format!("Version {} is supported", version.full))
+    } else {
+        DiagnosticCheck::fail(format!(
+            "Version {} is older than the minimum supported {}.{}.{}",
+            version.full, minimum.major, minimum.minor, minimum.patch
+        ))
+    }
+}
+
+/// Resolves the API socket path the runtime is expected to use
+///
+/// # Platform Behavior
+/// - **Docker**: the runtime's `host` (or `DOCKER_HOST`) when it is a
+///   `unix://` URL, `None` for other hosts such as `tcp://` since there is
+///   no local socket to check, else `/var/run/docker.sock`
+/// - **Podman**: `$XDG_RUNTIME_DIR/podman/podman.sock` when rootless, else
+///   `/run/podman/podman.sock`
+/// - **nerdctl**: containerd's `/run/containerd/containerd.sock`
+/// - **Windows**: named pipes are not checked, so `None`
+#[cfg(unix)]
+fn expected_socket_path(runtime: &Runtime) -> Option<PathBuf> {
+    use crate::types::PodmanMode;
+
+    match runtime.runtime_type {
+        RuntimeType::Docker => {
+            let host = runtime
+                .host
+                .as_deref()
+                .map(str::trim)
+                .filter(|host| !host.is_empty())
+                .map(String::from)
+                .or_else(docker_host_from_env);
+            match host {
+                Some(host) => host.strip_prefix("unix://").map(PathBuf::from),
+                None => Some(PathBuf::from("/var/run/docker.sock")),
+            }
+        }
+        RuntimeType::Podman => {
+            let runtime_dir = std::env::var("XDG_RUNTIME_DIR").ok();
+            match (runtime.mode, runtime_dir) {
+                (Some(PodmanMode::Rootful), _) | (_, None) => {
+                    Some(PathBuf::from("/run/podman/podman.sock"))
+                }
+                (_, Some(dir)) => Some(PathBuf::from(dir).join("podman/podman.sock")),
+            }
+        }
+        RuntimeType::Nerdctl => Some(PathBuf::from("/run/containerd/containerd.sock")),
+    }
+}
+
+#[cfg(not(unix))]
+fn expected_socket_path(_runtime: &Runtime) -> Option<PathBuf> {
+    None
+}
+
+/// Checks that the expected API socket exists
+fn check_socket(socket_path: Option<&Path>) -> DiagnosticCheck {
+    match socket_path {
+        Some(path) if path.exists() => {
+            DiagnosticCheck::pass(format!("Socket found at {}", path.display()))
+        }
+        Some(path) => DiagnosticCheck::fail(format!("Socket not found at {}", path.display())),
+        None => DiagnosticCheck::pass("Socket check not applicable for this runtime"),
+    }
+}
+
+/// Runs every diagnostic check for a runtime
+///
+/// # Arguments
+/// * `runtime` - The runtime to diagnose
+///
+/// # Returns
+/// `RuntimeDiagnostics` with one entry per check; checks never short-circuit
+pub async fn verify_runtime(runtime: &Runtime) -> RuntimeDiagnostics {
+    let executable = check_executable(&runtime.path);
+    let (daemon, version) = tokio::join!(check_daemon(runtime), check_version(runtime));
+
+    let socket_path = expected_socket_path(runtime);
+    let socket = check_socket(socket_path.as_deref());
+
+    RuntimeDiagnostics {
+        runtime_id: runtime.id.clone(),
+        passed: executable.passed && daemon.passed && version.passed && socket.passed,
+        executable,
+        daemon,
+        version,
+        socket,
+        socket_path: socket_path.map(|p| p.display().to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_verify_runtime_invalid_path() {
+        let runtime = Runtime::for_test("/nonexistent/docker").with_id("docker-test");
+        let diagnostics = verify_runtime(&runtime).await;
+
+        assert_eq!(diagnostics.runtime_id, "docker-test");
+        assert!(!diagnostics.passed);
+        assert!(!diagnostics.executable.passed);
+        assert!(diagnostics
+            .executable
+            .message
+            .contains("Executable not found at /nonexistent/docker"));
+        assert!(!diagnostics.daemon.passed);
+        assert!(!diagnostics.version.passed);
+        assert!(diagnostics
+            .version
+            .message
+            .starts_with("Failed to execute version command"));
+    }
+
+    #[test]
+    fn test_required_minimum_honors_preferences() {
+        let prefs = RuntimePreferences {
+            min_docker_version: Some("24.0.0".to_string()),
+            min_podman_version: Some("not a version".to_string()),
+            ..RuntimePreferences::default()
+        };
+
+        let docker = required_minimum(&RuntimeType::Docker, &prefs);
+        assert_eq!((docker.major, docker.minor, docker.patch), (24, 0, 0));
+        assert_eq!(
+            required_minimum(&RuntimeType::Podman, &prefs),
+            MIN_PODMAN_VERSION
+        );
+        assert_eq!(
+            required_minimum(&RuntimeType::Docker, &RuntimePreferences::default()),
+            MIN_DOCKER_VERSION
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_expected_socket_path_uses_runtime_host() {
+        let mut runtime = Runtime::for_test("/usr/bin/docker").with_id("docker-test");
+
+        runtime.host = Some("tcp://10.0.0.5:2376".to_string());
+        assert_eq!(expected_socket_path(&runtime), None);
+        assert!(check_socket(expected_socket_path(&runtime).as_deref()).passed);
+
+        runtime.host = Some("unix:///home/dev/.docker/run/docker.sock".to_string());
+        assert_eq!(
+            expected_socket_path(&runtime),
+            Some(PathBuf::from("/home/dev/.docker/run/docker.sock"))
+        );
+    }
+
+    #[test]
+    fn test_check_socket_missing() {
+        let check = check_socket(Some(Path::new("/nonexistent/docker.sock")));
+        assert!(!check.passed);
+        assert_eq!(
+            check.message,
+            "Socket not found at /nonexistent/docker.sock"
+        );
+    }
+}
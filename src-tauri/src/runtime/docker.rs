@@ -10,8 +10,18 @@ use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::time::{Duration, Instant};
 
+use crate::runtime::command::docker_host_from_env;
+use crate::runtime::search::{
+    common_bin_dirs, find_executable, verify_executable, SEARCH_PARALLELISM,
+};
 use crate::runtime::version::{parse_version, validate_docker_version};
-use crate::types::{DetectionError, DetectionResult, Runtime, RuntimeStatus, RuntimeType};
+use crate::types::{DetectionError, DetectionResult, Runtime, RuntimeStatus, RuntimeType, Version};
+
+/// Executable names to look for in platform-specific directories
+#[cfg(target_os = "windows")]
+const EXECUTABLE_NAMES: &[&str] = &["docker.exe", "docker"];
+#[cfg(not(target_os = "windows"))]
+const EXECUTABLE_NAMES: &[&str] = &["docker"];
 
 /// Returns platform-specific Docker installation paths
 ///
@@ -20,7 +30,7 @@ use crate::types::{DetectionError, DetectionResult, Runtime, RuntimeStatus, Runt
 /// - **macOS**: /usr/local/bin, Homebrew, Docker.app
 /// - **Linux**: /usr/bin, /usr/local/bin, snap
 fn get_platform_paths() -> Vec<PathBuf> {
-    let mut paths = vec![];
+    let mut paths = common_bin_dirs();
 
     #[cfg(target_os = "windows")]
     {
@@ -34,8 +44,6 @@ fn get_platform_paths() -> Vec<PathBuf> {
 
     #[cfg(target_os = "macos")]
     {
-        paths.push(PathBuf::from("/usr/local/bin"));
-        paths.push(PathBuf::from("/opt/homebrew/bin"));
         paths.push(PathBuf::from(
             "/Applications/Docker.app/Contents/Resources/bin",
         ));
@@ -43,8 +51,6 @@ fn get_platform_paths() -> Vec<PathBuf> {
 
     #[cfg(target_os = "linux")]
     {
-        paths.push(PathBuf::from("/usr/bin"));
-        paths.push(PathBuf::from("/usr/local/bin"));
         paths.push(PathBuf::from("/snap/bin"));
     }
 
@@ -66,32 +72,8 @@ fn find_docker_executable() -> Option<PathBuf> {
         return Some(path);
     }
 
-    // Try platform-specific paths
-    for path in get_platform_paths() {
-        if path.is_file() && path.file_name().unwrap_or_default() == "docker"
-            || path.file_name().unwrap_or_default() == "docker.exe"
-        {
-            return Some(path);
-        }
-
-        // Check if path is a directory, look for docker inside it
-        if path.is_dir() {
-            let docker_path = path.join("docker");
-            if docker_path.is_file() {
-                return Some(docker_path);
-            }
-
-            #[cfg(target_os = "windows")]
-            {
-                let docker_exe = path.join("docker.exe");
-                if docker_exe.is_file() {
-                    return Some(docker_exe);
-                }
-            }
-        }
-    }
-
-    None
+    // Probe platform-specific paths with a small worker pool
+    find_executable(&get_platform_paths(), EXECUTABLE_NAMES, SEARCH_PARALLELISM)
 }
 
 /// Detects Docker Desktop running in Windows when inside WSL2
@@ -126,39 +108,6 @@ fn detect_wsl_docker() -> Option<PathBuf> {
     None
 }
 
-/// Verifies that the executable has proper execute permissions
-///
-/// # Platform Behavior
-/// - **Unix**: Checks execute bits (0o111) in file permissions
-/// - **Windows**: Validates file exists and is not a directory
-///
-/// # Arguments
-/// * `path` - Path to the executable to verify
-///
-/// # Returns
-/// `true` if executable has proper permissions, `false` otherwise
-fn verify_executable(path: &Path) -> bool {
-    #[cfg(unix)]
-    {
-        use std::os::unix::fs::PermissionsExt;
-        if let Ok(metadata) = std::fs::metadata(path) {
-            let permissions = metadata.permissions();
-            permissions.mode() & 0o111 != 0 // Check execute bits
-        } else {
-            false
-        }
-    }
-
-    #[cfg(windows)]
-    {
-        // On Windows, just check if file exists and is not a directory
-        path.is_file()
-    }
-
-    #[cfg(not(any(unix, windows)))]
-    false
-}
-
 /// Retrieves the Docker version string
 ///
 /// Executes `docker --version` command and parses the output.
@@ -180,7 +129,7 @@ fn get_docker_version(docker_path: &PathBuf) -> Result<String, Box<dyn Error>> {
     Ok(version_str.trim().to_string())
 }
 
-/// Checks if the Docker daemon is currently running
+/// Retrieves `docker info` output when the daemon is running
 ///
 /// Executes `docker info` command to verify daemon connectivity.
 ///
@@ -188,13 +137,87 @@ fn get_docker_version(docker_path: &PathBuf) -> Result<String, Box<dyn Error>> {
 /// * `docker_path` - Path to the Docker executable
 ///
 /// # Returns
-/// `true` if daemon is running and responsive, `false` otherwise
-fn check_docker_running(docker_path: &PathBuf) -> bool {
-    let output = Command::new(docker_path).arg("info").output();
+/// - `Some(String)` with the info output if the daemon is running and responsive
+/// - `None` otherwise
+fn get_docker_info(docker_path: &PathBuf) -> Option<String> {
+    let output = Command::new(docker_path).arg("info").output().ok()?;
+
+    if output.status.success() {
+        Some(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        None
+    }
+}
 
-    match output {
-        Ok(out) => out.status.success(),
-        Err(_) => false,
+/// Determines which daemon the Docker CLI is currently pointed at
+///
+/// `DOCKER_HOST` takes precedence, then `DOCKER_CONTEXT`, then the context
+/// reported by `docker context show`.
+///
+/// # Arguments
+/// * `docker_path` - Path to the Docker executable
+///
+/// # Returns
+/// - `Some(String)` with the host URL or context name
+/// - `None` if it cannot be determined
+fn get_docker_target(docker_path: &PathBuf) -> Option<String> {
+    for var in ["DOCKER_HOST", "DOCKER_CONTEXT"] {
+        if let Some(value) = std::env::var(var).ok().filter(|v| !v.trim().is_empty()) {
+            return Some(value.trim().to_string());
+        }
+    }
+
+    let output = Command::new(docker_path)
+        .args(["context", "show"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let context = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!context.is_empty()).then_some(context)
+}
+
+/// Builds the runtime ID for a Docker executable and daemon target
+///
+/// The `default` context (or an unknown target) keeps the plain
+/// `docker-<path>` form, so IDs saved before targets were tracked still
+/// match a local installation; any other target is appended as
+/// `docker-<path>-<target>`.
+pub fn docker_runtime_id(path: &str, target: Option<&str>) -> String {
+    match target {
+        Some(target) if target != "default" => format!("docker-{}-{}", path, target),
+        _ => format!("docker-{}", path),
+    }
+}
+
+/// Classifies a Docker installation as Docker Desktop or Docker Engine
+///
+/// Docker Desktop is recognized by its install location (`Docker.app` on
+/// macOS, `Docker Desktop` / `Docker\Docker` on Windows) or by the
+/// `desktop` client plugin listed in `docker info`.
+///
+/// # Arguments
+/// * `docker_path` - Path to the Docker executable
+/// * `info` - `docker info` output, if the daemon was reachable
+///
+/// # Returns
+/// `"desktop"` or `"engine"`
+fn classify_docker_edition(docker_path: &Path, info: Option<&str>) -> &'static str {
+    let path = docker_path.to_string_lossy();
+    let desktop_path = ["Docker.app", "Docker Desktop", "Docker\\Docker"]
+        .iter()
+        .any(|marker| path.contains(marker));
+
+    let desktop_plugin = info.is_some_and(|info| {
+        info.lines()
+            .any(|line| line.trim_start().starts_with("desktop:"))
+    });
+
+    if desktop_path || desktop_plugin {
+        "desktop"
+    } else {
+        "engine"
     }
 }
 
@@ -209,6 +232,7 @@ fn check_docker_running(docker_path: &PathBuf) -> bool {
 ///
 /// # Arguments
 /// * `timeout_ms` - Maximum time in milliseconds before detection aborts
+/// * `minimum` - Configured minimum version below which `version_warning` is set
 ///
 /// # Returns
 /// `DetectionResult` containing:
@@ -222,11 +246,11 @@ fn check_docker_running(docker_path: &PathBuf) -> bool {
 ///
 /// #[tokio::main]
 /// async fn main() {
-///     let result = detect_docker(5000).await;
+///     let result = detect_docker(5000, None).await;
 ///     println!("Found {} Docker runtime(s)", result.runtimes.len());
 /// }
 /// ```
-pub async fn detect_docker(timeout_ms: u64) -> DetectionResult {
+pub async fn detect_docker(timeout_ms: u64, minimum: Option<Version>) -> DetectionResult {
     let start = Instant::now();
     let timeout = Duration::from_millis(timeout_ms);
 
@@ -268,20 +292,25 @@ pub async fn detect_docker(timeout_ms: u64) -> DetectionResult {
                         let is_wsl =
                             cfg!(target_os = "linux") && path.to_string_lossy().contains(".exe");
 
-                        let status = if check_docker_running(&path) {
+                        let info = get_docker_info(&path);
+                        let status = if info.is_some() {
                             RuntimeStatus::Running
                         } else {
                             RuntimeStatus::Stopped
                         };
+                        let edition = classify_docker_edition(&path, info.as_deref());
 
-                        let version_warning = if !validate_docker_version(&version) {
-                            Some(true)
-                        } else {
-                            None
-                        };
+                        let version_warning =
+                            if !validate_docker_version(&version, minimum.as_ref()) {
+                                Some(true)
+                            } else {
+                                None
+                            };
+
+                        let target = get_docker_target(&path);
 
                         runtimes.push(Runtime {
-                            id: format!("docker-{}", path.to_string_lossy()),
+                            id: docker_runtime_id(&path.to_string_lossy(), target.as_deref()),
                             runtime_type: RuntimeType::Docker,
                             path: path.to_string_lossy().to_string(),
                             version,
@@ -292,6 +321,9 @@ pub async fn detect_docker(timeout_ms: u64) -> DetectionResult {
                             is_wsl: if is_wsl { Some(true) } else { None },
                             error: None,
                             version_warning,
+                            edition: Some(edition.to_string()),
+                            host: docker_host_from_env(),
+                            machine_running: None,
                         });
                     }
                     Err(e) => {
@@ -416,7 +448,7 @@ mod tests {
             patch: 0,
             full: "20.10.0".to_string(),
         };
-        assert!(validate_docker_version(&valid));
+        assert!(validate_docker_version(&valid, None));
 
         // Exact minimum version 20.10.0
         let exact_min = Version {
@@ -425,7 +457,7 @@ mod tests {
             patch: 0,
             full: "20.10.0".to_string(),
         };
-        assert!(validate_docker_version(&exact_min));
+        assert!(validate_docker_version(&exact_min, None));
     }
 
     #[test]
@@ -436,7 +468,7 @@ mod tests {
             patch: 9,
             full: "19.2.9".to_string(),
         };
-        assert!(!validate_docker_version(&too_old));
+        assert!(!validate_docker_version(&too_old, None));
 
         let very_old = Version {
             major: 18,
@@ -444,18 +476,7 @@ mod tests {
             patch: 0,
             full: "18.0.0".to_string(),
         };
-        assert!(!validate_docker_version(&very_old));
-    }
-
-    #[test]
-    fn test_verify_executable_permissions() {
-        // Test with a known executable (the current binary)
-        let current_exe = std::env::current_exe().unwrap();
-        assert!(verify_executable(&current_exe));
-
-        // Test with a non-existent path
-        let fake_path = PathBuf::from("/nonexistent/path/to/binary");
-        assert!(!verify_executable(&fake_path));
+        assert!(!validate_docker_version(&very_old, None));
     }
 
     #[cfg(target_os = "linux")]
@@ -468,16 +489,56 @@ mod tests {
         let _ = result;
     }
 
+    #[test]
+    fn test_docker_runtime_id_includes_target() {
+        let path = "/usr/bin/docker";
+        let local = docker_runtime_id(path, Some("default"));
+        let remote = docker_runtime_id(path, Some("staging"));
+        let tcp = docker_runtime_id(path, Some("tcp://10.0.0.5:2376"));
+
+        assert_eq!(local, "docker-/usr/bin/docker");
+        assert_eq!(docker_runtime_id(path, None), local);
+        assert_eq!(remote, "docker-/usr/bin/docker-staging");
+        assert_ne!(remote, tcp);
+        assert_ne!(local, tcp);
+    }
+
+    #[test]
+    fn test_classify_docker_edition_desktop_path() {
+        let path = Path::new("/Applications/Docker.app/Contents/Resources/bin/docker");
+        assert_eq!(classify_docker_edition(path, None), "desktop");
+
+        let path = Path::new("C:\\Program Files\\Docker\\Docker\\resources\\bin\\docker.exe");
+        assert_eq!(classify_docker_edition(path, None), "desktop");
+    }
+
+    #[test]
+    fn test_classify_docker_edition_engine_path() {
+        assert_eq!(
+            classify_docker_edition(Path::new("/usr/bin/docker"), None),
+            "engine"
+        );
+    }
+
+    #[test]
+    fn test_classify_docker_edition_desktop_plugin() {
+        let info = "Client:\n Plugins:\n  buildx: Docker Buildx (Docker Inc.)\n  desktop: Docker Desktop commands (Beta) (Docker Inc.)\n";
+        assert_eq!(
+            classify_docker_edition(Path::new("/usr/local/bin/docker"), Some(info)),
+            "desktop"
+        );
+    }
+
     #[tokio::test]
     async fn test_detect_docker_timeout() {
-        let result = detect_docker(500).await;
+        let result = detect_docker(500, None).await;
         // Should complete within reasonable time, allowing for extremely slow CI runners
         assert!(result.duration <= 15000); // Allow up to 15 seconds for extremely slow CI environments
     }
 
     #[tokio::test]
     async fn test_detect_docker_structure() {
-        let result = detect_docker(500).await;
+        let result = detect_docker(500, None).await;
 
         // Verify result structure is valid
         // Duration varies based on system speed and may exceed timeout on slow CI runners
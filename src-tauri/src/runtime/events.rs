@@ -0,0 +1,168 @@
+//! Daemon lifecycle events
+//!
+//! Watches `docker events --filter type=daemon` so the UI learns about daemon
+//! reloads and restarts as they happen instead of waiting for the next poll.
+//! Each event is emitted as `daemon-event`. Podman has no long-running daemon
+//! and never emits these events, so watching it is a no-op.
+
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader};
+use std::process::Stdio;
+use tauri::{AppHandle, Emitter};
+
+use crate::process::tracker::PROCESS_TRACKER;
+use crate::runtime::command::runtime_command;
+use crate::types::{Runtime, RuntimeType};
+
+/// A daemon event emitted to the frontend
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DaemonEvent {
+    #[serde(rename = "runtimeId")]
+    pub runtime_id: String,
+    /// Event action, e.g. `reload` or `init`
+    pub action: String,
+    /// Unix timestamp (seconds) reported by the daemon
+    pub time: i64,
+}
+
+/// One line of `events --format '{{json .}}'`, limited to what we read
+#[derive(Debug, Deserialize)]
+struct RawEvent {
+    #[serde(rename = "Type")]
+    event_type: String,
+    #[serde(rename = "Action")]
+    action: String,
+    #[serde(default)]
+    time: i64,
+}
+
+/// Builds the `events` arguments for watching daemon events
+fn build_events_args() -> Vec<String> {
+    [
+        "events",
+        "--filter",
+        "type=daemon",
+        "--format",
+        "{{json .}}",
+    ]
+    .iter()
+    .map(|arg| arg.to_string())
+    .collect()
+}
+
+/// Parses one JSON event line into a daemon event
+///
+/// # Returns
+/// - `Some(DaemonEvent)` for a daemon event
+/// - `None` for blank lines, malformed JSON or non-daemon events
+pub fn parse_daemon_event(runtime_id: &str, line: &str) -> Option<DaemonEvent> {
+    let raw: RawEvent = serde_json::from_str(line.trim()).ok()?;
+    if raw.event_type != "daemon" {
+        return None;
+    }
+
+    Some(DaemonEvent {
+        runtime_id: runtime_id.to_string(),
+        action: raw.action,
+        time: raw.time,
+    })
+}
+
+/// Watches daemon events until the stream ends or the watcher is cancelled
+///
+/// Runs for as long as the events stream stays open; the child is tracked so
+/// `cancel_all_operations` and the shutdown hook can stop it.
+///
+/// # Arguments
+/// * `app` - App handle used to emit `daemon-event`
+/// * `runtime` - The runtime to watch
+/// * `on_event` - Called on the watcher thread for every event, after emitting
+///
+/// # Returns
+/// - `Ok(())` when the stream ends, is cancelled, or the runtime has no daemon events
+/// - `Err(String)` if the events command could not be started
+pub async fn watch_daemon_events<F>(
+    app: AppHandle,
+    runtime: &Runtime,
+    on_event: F,
+) -> Result<(), String>
+where
+    F: Fn(&DaemonEvent) + Send + 'static,
+{
+    if runtime.runtime_type != RuntimeType::Docker {
+        return Ok(());
+    }
+
+    let runtime = runtime.clone();
+    let runtime_id = runtime.id.clone();
+    let args = build_events_args();
+
+    tokio::task::spawn_blocking(move || {
+        let mut child = runtime_command(&runtime)
+            .args(&args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| format!("Failed to watch daemon events: {}", e))?;
+
+        let stdout = child.stdout.take();
+        let handle = PROCESS_TRACKER.track(child);
+
+        if let Some(stdout) = stdout {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                let Some(event) = parse_daemon_event(&runtime_id, &line) else {
+                    continue;
+                };
+
+                if let Err(e) = app.emit("daemon-event", &event) {
+                    eprintln!("Failed to emit daemon event: {}", e);
+                }
+                on_event(&event);
+            }
+        }
+
+        // A daemon that rejects the filter (or a cancelled watcher) simply ends
+        // the stream; neither is worth surfacing as an error
+        PROCESS_TRACKER.release(handle);
+        Ok(())
+    })
+    .await
+    .map_err(|e| format!("Daemon events task failed: {}", e))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_daemon_reload_event() {
+        let line = r#"{"Type":"daemon","Action":"reload","Actor":{"ID":"7ZQX:ABCD","Attributes":{"name":"docker-desktop"}},"scope":"local","time":1700000000,"timeNano":1700000000123456789}"#;
+        let event = parse_daemon_event("docker-1", line).unwrap();
+
+        assert_eq!(event.runtime_id, "docker-1");
+        assert_eq!(event.action, "reload");
+        assert_eq!(event.time, 1700000000);
+    }
+
+    #[test]
+    fn test_parse_daemon_event_ignores_other_lines() {
+        let container = r#"{"Type":"container","Action":"start","time":1700000000}"#;
+        assert!(parse_daemon_event("docker-1", container).is_none());
+        assert!(parse_daemon_event("docker-1", "").is_none());
+        assert!(parse_daemon_event("docker-1", "not json").is_none());
+    }
+
+    #[test]
+    fn test_build_events_args() {
+        assert_eq!(
+            build_events_args(),
+            vec![
+                "events",
+                "--filter",
+                "type=daemon",
+                "--format",
+                "{{json .}}"
+            ]
+        );
+    }
+}
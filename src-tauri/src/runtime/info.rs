@@ -0,0 +1,538 @@
+//! Runtime `info` querying and parsing
+//!
+//! This module runs `docker info --format json` (or the Podman equivalent)
+//! and extracts registry configuration from the resulting document. Runtimes
+//! without JSON support fall back to parsing the human-readable output.
+
+use serde_json::{Map, Value};
+use std::time::Duration;
+use tokio::time::timeout;
+
+use crate::runtime::command::runtime_command;
+use crate::types::Runtime;
+
+/// Maximum time to wait for an `info` command (5 seconds)
+const INFO_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Runs `<runtime> info <args...>` and returns its stdout
+///
+/// # Arguments
+/// * `runtime` - The runtime to query
+/// * `args` - Extra arguments after `info`, e.g. `["--format", "json"]`
+///
+/// # Returns
+/// - `Ok(String)` with the command's stdout
+/// - `Err(String)` if the command fails or times out
+async fn run_info(runtime: &Runtime, args: &'static [&'static str]) -> Result<String, String> {
+    let runtime = runtime.clone();
+
+    let result = timeout(INFO_TIMEOUT, async {
+        tokio::task::spawn_blocking(move || {
+            runtime_command(&runtime).arg("info").args(args).output()
+        })
+        .await
+    })
+    .await;
+
+    let output = match result {
+        Ok(Ok(Ok(output))) => output,
+        Ok(Ok(Err(e))) => return Err(format!("Failed to execute info: {}", e)),
+        Ok(Err(e)) => return Err(format!("Info task failed: {}", e)),
+        Err(_) => return Err("Info command timed out".to_string()),
+    };
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Info command failed: {}", stderr.trim()));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Runs `<runtime> info --format json` and parses the output
+///
+/// # Arguments
+/// * `runtime` - The runtime to query
+///
+/// # Returns
+/// - `Ok(Value)` with the parsed info document
+/// - `Err(String)` if the command fails, times out, or emits invalid JSON
+pub async fn fetch_info_json(runtime: &Runtime) -> Result<Value, String> {
+    let stdout = run_info(runtime, &["--format", "json"]).await?;
+    serde_json::from_str(stdout.trim()).map_err(|e| format!("Failed to parse info output: {}", e))
+}
+
+/// Splits a text info line into key and value
+///
+/// Keys are separated by `": "` or a trailing `":"`, so values such as
+/// `https://index.docker.io/v1/` and list items such as `localhost:5000`
+/// are not split on their inner colons.
+fn split_key_value(content: &str) -> Option<(&str, &str)> {
+    if let Some((key, value)) = content.split_once(": ") {
+        return Some((key.trim(), value.trim()));
+    }
+    content.strip_suffix(':').map(|key| (key.trim(), ""))
+}
+
+/// Parses lines at or below `min_indent` into a JSON value
+///
+/// Sections made only of bare lines (or YAML `- item` lines) become arrays;
+/// everything else becomes an object. Children of a key that also has a
+/// value (`Containers: 5` followed by `Running: 2`) are flattened into the
+/// parent as `"Containers Running"`.
+fn parse_text_block(lines: &[(usize, &str)], pos: &mut usize, min_indent: usize) -> Value {
+    let mut map = Map::new();
+    let mut items = Vec::new();
+
+    while *pos < lines.len() {
+        let (indent, content) = lines[*pos];
+        if indent < min_indent {
+            break;
+        }
+        *pos += 1;
+
+        if let Some(warning) = content.strip_prefix("WARNING:") {
+            let warnings = map
+                .entry("Warnings")
+                .or_insert_with(|| Value::Array(Vec::new()));
+            if let Value::Array(list) = warnings {
+                list.push(Value::String(warning.trim().to_string()));
+            }
+            continue;
+        }
+
+        if let Some(item) = content.strip_prefix("- ") {
+            items.push(Value::String(item.trim().to_string()));
+            continue;
+        }
+
+        let Some((key, value)) = split_key_value(content) else {
+            items.push(Value::String(content.to_string()));
+            continue;
+        };
+
+        let next_indent = lines.get(*pos).map(|(i, _)| *i).unwrap_or(0);
+        let nested = *pos < lines.len() && next_indent > indent;
+
+        if value.is_empty() {
+            let child = if nested {
+                parse_text_block(lines, pos, next_indent)
+            } else {
+                // YAML sequences may sit at the same indent as their key
+                let mut list = Vec::new();
+                while let Some(item) = lines
+                    .get(*pos)
+                    .filter(|(i, _)| *i == indent)
+                    .and_then(|(_, c)| c.strip_prefix("- "))
+                {
+                    list.push(Value::String(item.trim().to_string()));
+                    *pos += 1;
+                }
+
+                if list.is_empty() {
+                    Value::String(String::new())
+                } else {
+                    Value::Array(list)
+                }
+            };
+            map.insert(key.to_string(), child);
+        } else {
+            map.insert(key.to_string(), Value::String(value.to_string()));
+            if nested {
+                if let Value::Object(children) = parse_text_block(lines, pos, next_indent) {
+                    for (child_key, child_value) in children {
+                        map.insert(format!("{} {}", key, child_key), child_value);
+                    }
+                }
+            }
+        }
+    }
+
+    if map.is_empty() && !items.is_empty() {
+        Value::Array(items)
+    } else {
+        if !items.is_empty() {
+            map.insert("Items".to_string(), Value::Array(items));
+        }
+        Value::Object(map)
+    }
+}
+
+/// Parses human-readable `info` output into a JSON value
+///
+/// Nesting follows indentation, e.g. `Server:` followed by indented
+/// `Containers: 5` becomes `{"Server": {"Containers": "5"}}`. Values are
+/// kept as strings because the text form carries no type information.
+///
+/// # Arguments
+/// * `text` - Output of `<runtime> info` without `--format`
+pub fn parse_info_text(text: &str) -> Value {
+    let lines: Vec<(usize, &str)> = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| (line.len() - line.trim_start().len(), line.trim()))
+        .collect();
+
+    let mut pos = 0;
+    parse_text_block(&lines, &mut pos, 0)
+}
+
+/// Retrieves the full runtime info document for display
+///
+/// Tries `info --format json` first and falls back to parsing the text
+/// output for runtimes that don't support JSON formatting.
+///
+/// # Arguments
+/// * `runtime` - The runtime to query
+///
+/// # Returns
+/// - `Ok(Value)` with the info document
+/// - `Err(String)` if neither form could be retrieved
+pub async fn runtime_info(runtime: &Runtime) -> Result<Value, String> {
+    if let Ok(info) = fetch_info_json(runtime).await {
+        if info.is_object() {
+            return Ok(info);
+        }
+    }
+
+    let text = run_info(runtime, &[]).await?;
+    Ok(parse_info_text(&text))
+}
+
+/// Extracts insecure registries from an info document
+///
+/// Handles both layouts:
+/// - **Docker**: `RegistryConfig.IndexConfigs` entries with `Secure: false`
+///   plus the `RegistryConfig.InsecureRegistryCIDRs` list
+/// - **Podman**: `registries.<name>.Insecure: true`
+///
+/// # Arguments
+/// * `info` - Parsed `info --format json` document
+///
+/// # Returns
+/// Sorted, de-duplicated list of insecure registry hosts and CIDRs
+pub fn parse_insecure_registries(info: &Value) -> Vec<String> {
+    let mut registries = Vec::new();
+
+    let registry_config = &info["RegistryConfig"];
+    if let Some(index_configs) = registry_config["IndexConfigs"].as_object() {
+        for (name, config) in index_configs {
+            if config["Secure"].as_bool() == Some(false) {
+                registries.push(name.clone());
+            }
+        }
+    }
+    if let Some(cidrs) = registry_config["InsecureRegistryCIDRs"].as_array() {
+        registries.extend(cidrs.iter().filter_map(|c| c.as_str()).map(String::from));
+    }
+
+    if let Some(podman_registries) = info["registries"].as_object() {
+        for (name, config) in podman_registries {
+            if config["Insecure"].as_bool() == Some(true) {
+                registries.push(name.clone());
+            }
+        }
+    }
+
+    registries.sort();
+    registries.dedup();
+    registries
+}
+
+/// Lists the registries the runtime is configured to reach without TLS verification
+///
+/// # Arguments
+/// * `runtime` - The runtime to query
+///
+/// # Returns
+/// - `Ok(Vec<String>)` of insecure registries (may be empty)
+/// - `Err(String)` if the info document could not be retrieved
+pub async fn get_insecure_registries(runtime: &Runtime) -> Result<Vec<String>, String> {
+    let info = fetch_info_json(runtime).await?;
+    Ok(parse_insecure_registries(&info))
+}
+
+/// Extracts registry mirrors from an info document
+///
+/// Handles both layouts:
+/// - **Docker**: the `RegistryConfig.Mirrors` list of URLs
+/// - **Podman**: `registries.<name>.Mirrors[].Location` from `registries.conf`
+///
+/// # Arguments
+/// * `info` - Parsed `info --format json` document
+///
+/// # Returns
+/// Mirrors in configuration order, de-duplicated
+pub fn parse_registry_mirrors(info: &Value) -> Vec<String> {
+    let mut mirrors: Vec<String> = Vec::new();
+    let mut push = |mirror: &str| {
+        let mirror = mirror.trim();
+        if !mirror.is_empty() && !mirrors.iter().any(|m| m == mirror) {
+            mirrors.push(mirror.to_string());
+        }
+    };
+
+    if let Some(docker_mirrors) = info["RegistryConfig"]["Mirrors"].as_array() {
+        docker_mirrors
+            .iter()
+            .filter_map(|m| m.as_str())
+            .for_each(&mut push);
+    }
+
+    if let Some(podman_registries) = info["registries"].as_object() {
+        for config in podman_registries.values() {
+            if let Some(registry_mirrors) = config["Mirrors"].as_array() {
+                registry_mirrors
+                    .iter()
+                    .filter_map(|m| m["Location"].as_str())
+                    .for_each(&mut push);
+            }
+        }
+    }
+
+    mirrors
+}
+
+/// Lists the registry mirrors pulls are routed through
+///
+/// # Arguments
+/// * `runtime` - The runtime to query
+///
+/// # Returns
+/// - `Ok(Vec<String>)` of mirrors (may be empty)
+/// - `Err(String)` if the info document could not be retrieved
+pub async fn get_registry_mirrors(runtime: &Runtime) -> Result<Vec<String>, String> {
+    let info = fetch_info_json(runtime).await?;
+    Ok(parse_registry_mirrors(&info))
+}
+
+/// Extracts daemon advisories from an info document
+///
+/// Collects the `Warnings` and `ServerErrors` arrays. Docker prefixes each
+/// JSON warning with `WARNING: `, which is stripped so JSON and text
+/// documents yield the same strings.
+///
+/// # Arguments
+/// * `info` - Info document from `info --format json` or `parse_info_text`
+///
+/// # Returns
+/// Warnings followed by server errors, de-duplicated in order
+pub fn parse_runtime_warnings(info: &Value) -> Vec<String> {
+    let mut warnings: Vec<String> = Vec::new();
+
+    for key in ["Warnings", "ServerErrors"] {
+        let Some(entries) = info[key].as_array() else {
+            continue;
+        };
+        for entry in entries.iter().filter_map(|e| e.as_str()) {
+            let warning = entry.trim();
+            let warning = warning.strip_prefix("WARNING:").unwrap_or(warning).trim();
+            if !warning.is_empty() && !warnings.iter().any(|w| w == warning) {
+                warnings.push(warning.to_string());
+            }
+        }
+    }
+
+    warnings
+}
+
+/// Lists the warnings the daemon reports about its own configuration
+///
+/// E.g. missing swap limit support or disabled bridge-nf-call, which limit
+/// what containers can do. Falls back to the text `WARNING:` lines for
+/// runtimes without JSON info.
+///
+/// # Arguments
+/// * `runtime` - The runtime to query
+///
+/// # Returns
+/// - `Ok(Vec<String>)` of advisories (may be empty)
+/// - `Err(String)` if the info document could not be retrieved
+pub async fn get_runtime_warnings(runtime: &Runtime) -> Result<Vec<String>, String> {
+    let info = runtime_info(runtime).await?;
+    Ok(parse_runtime_warnings(&info))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCKER_INFO_FIXTURE: &str = r#"{
+        "ID": "abc",
+        "RegistryConfig": {
+            "AllowNondistributableArtifactsCIDRs": null,
+            "InsecureRegistryCIDRs": ["127.0.0.0/8"],
+            "IndexConfigs": {
+                "docker.io": {
+                    "Name": "docker.io",
+                    "Mirrors": [],
+                    "Secure": true,
+                    "Official": true
+                },
+                "registry.local:5000": {
+                    "Name": "registry.local:5000",
+                    "Mirrors": [],
+                    "Secure": false,
+                    "Official": false
+                }
+            },
+            "Mirrors": ["https://mirror.gcr.io/"]
+        }
+    }"#;
+
+    #[test]
+    fn test_parse_insecure_registries_docker() {
+        let info: Value = serde_json::from_str(DOCKER_INFO_FIXTURE).unwrap();
+        let registries = parse_insecure_registries(&info);
+        assert_eq!(registries, vec!["127.0.0.0/8", "registry.local:5000"]);
+    }
+
+    #[test]
+    fn test_parse_insecure_registries_podman() {
+        let info: Value = serde_json::from_str(
+            r#"{"registries": {
+                "search": ["docker.io"],
+                "registry.local:5000": {"Prefix": "registry.local:5000", "Insecure": true, "Blocked": false}
+            }}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parse_insecure_registries(&info),
+            vec!["registry.local:5000"]
+        );
+    }
+
+    const DOCKER_INFO_TEXT_FIXTURE: &str = "Client:
+ Version:    24.0.7
+ Context:    default
+ Plugins:
+  buildx: Docker Buildx (Docker Inc.)
+    Version:  v0.11.2
+
+Server:
+ Containers: 5
+  Running: 2
+  Stopped: 3
+ Server Version: 24.0.7
+ Insecure Registries:
+  registry.local:5000
+  127.0.0.0/8
+ Registry: https://index.docker.io/v1/
+ Live Restore Enabled: false
+
+WARNING: No swap limit support
+";
+
+    #[test]
+    fn test_parse_info_json_fixture() {
+        let info: Value = serde_json::from_str(DOCKER_INFO_FIXTURE).unwrap();
+        assert!(info.is_object());
+        assert_eq!(info["ID"], "abc");
+    }
+
+    #[test]
+    fn test_parse_info_text_fixture() {
+        let info = parse_info_text(DOCKER_INFO_TEXT_FIXTURE);
+
+        assert_eq!(info["Client"]["Version"], "24.0.7");
+        assert_eq!(
+            info["Client"]["Plugins"]["buildx"],
+            "Docker Buildx (Docker Inc.)"
+        );
+        assert_eq!(info["Client"]["Plugins"]["buildx Version"], "v0.11.2");
+        assert_eq!(info["Server"]["Containers"], "5");
+        assert_eq!(info["Server"]["Containers Running"], "2");
+        assert_eq!(info["Server"]["Server Version"], "24.0.7");
+        assert_eq!(
+            info["Server"]["Insecure Registries"],
+            serde_json::json!(["registry.local:5000", "127.0.0.0/8"])
+        );
+        assert_eq!(info["Server"]["Registry"], "https://index.docker.io/v1/");
+        assert_eq!(
+            info["Warnings"],
+            serde_json::json!(["No swap limit support"])
+        );
+    }
+
+    #[test]
+    fn test_parse_info_text_yaml_lists() {
+        let info = parse_info_text(
+            "host:\n  arch: amd64\n  cgroupControllers:\n  - cpu\n  - memory\n  hostname: box\n",
+        );
+        assert_eq!(info["host"]["arch"], "amd64");
+        assert_eq!(
+            info["host"]["cgroupControllers"],
+            serde_json::json!(["cpu", "memory"])
+        );
+        assert_eq!(info["host"]["hostname"], "box");
+    }
+
+    #[test]
+    fn test_parse_insecure_registries_empty() {
+        let info: Value = serde_json::from_str(r#"{"ID": "abc"}"#).unwrap();
+        assert!(parse_insecure_registries(&info).is_empty());
+    }
+
+    #[test]
+    fn test_parse_runtime_warnings_json() {
+        let info: Value = serde_json::from_str(
+            r#"{
+                "ID": "abc",
+                "Warnings": [
+                    "WARNING: No swap limit support",
+                    "WARNING: bridge-nf-call-iptables is disabled"
+                ],
+                "ServerErrors": null
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            parse_runtime_warnings(&info),
+            vec![
+                "No swap limit support",
+                "bridge-nf-call-iptables is disabled"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_runtime_warnings_text_and_server_errors() {
+        let text = parse_info_text(DOCKER_INFO_TEXT_FIXTURE);
+        assert_eq!(parse_runtime_warnings(&text), vec!["No swap limit support"]);
+
+        let info: Value =
+            serde_json::from_str(r#"{"ServerErrors": ["Cannot connect to the Docker daemon"]}"#)
+                .unwrap();
+        assert_eq!(
+            parse_runtime_warnings(&info),
+            vec!["Cannot connect to the Docker daemon"]
+        );
+    }
+
+    #[test]
+    fn test_parse_registry_mirrors_docker() {
+        let info: Value = serde_json::from_str(DOCKER_INFO_FIXTURE).unwrap();
+        assert_eq!(
+            parse_registry_mirrors(&info),
+            vec!["https://mirror.gcr.io/"]
+        );
+    }
+
+    #[test]
+    fn test_parse_registry_mirrors_podman() {
+        let info: Value = serde_json::from_str(
+            r#"{"registries": {
+                "search": ["docker.io"],
+                "docker.io": {
+                    "Prefix": "docker.io",
+                    "Location": "docker.io",
+                    "Mirrors": [{"Location": "mirror.example.com", "Insecure": false}]
+                }
+            }}"#,
+        )
+        .unwrap();
+        assert_eq!(parse_registry_mirrors(&info), vec!["mirror.example.com"]);
+        assert!(parse_registry_mirrors(&serde_json::json!({})).is_empty());
+    }
+}
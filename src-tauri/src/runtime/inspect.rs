@@ -0,0 +1,199 @@
+//! Raw `inspect` output for any resource kind
+//!
+//! The typed inspect helpers only model the fields the UI uses. `inspect_raw`
+//! hands back the runtime's JSON untouched so advanced views can show fields
+//! we don't model yet. `resource_exists` runs the same command but only
+//! looks at its exit status, which is much cheaper than listing every
+//! resource of a kind just to find one.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::process::Stdio;
+
+use crate::runtime::command::runtime_command;
+use crate::types::Runtime;
+
+/// Kind of resource to inspect
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ResourceKind {
+    Container,
+    Image,
+    Volume,
+    Network,
+}
+
+impl ResourceKind {
+    /// CLI management command for this kind, e.g. `container`
+    pub fn subcommand(&self) -> &'static str {
+        match self {
+            ResourceKind::Container => "container",
+            ResourceKind::Image => "image",
+            ResourceKind::Volume => "volume",
+            ResourceKind::Network => "network",
+        }
+    }
+}
+
+/// Rejects an ID that is empty or would be parsed as a CLI flag
+///
+/// Every command taking a resource ID as a positional argument checks it
+/// first, so an ID like `--privileged` can't change what the command does.
+pub fn validate_resource_id(kind: ResourceKind, id: &str) -> Result<(), String> {
+    if id.trim().is_empty() || id.starts_with('-') {
+        return Err(format!("Invalid {} ID: {}", kind.subcommand(), id));
+    }
+    Ok(())
+}
+
+/// `validate_resource_id` for a container ID or name
+pub fn validate_container_id(id: &str) -> Result<(), String> {
+    validate_resource_id(ResourceKind::Container, id)
+}
+
+/// Builds the `<kind> inspect <id>` arguments
+fn build_inspect_args(kind: ResourceKind, id: &str) -> Result<Vec<String>, String> {
+    validate_resource_id(kind, id)?;
+    Ok(vec![
+        kind.subcommand().to_string(),
+        "inspect".to_string(),
+        id.to_string(),
+    ])
+}
+
+/// Inspects a resource and returns the runtime's JSON as is
+///
+/// # Arguments
+/// * `runtime` - The runtime to query
+/// * `kind` - Container, image, volume or network
+/// * `id` - Name or ID of the resource
+///
+/// # Returns
+/// - `Ok(Value)` with the parsed output, normally a one-element array
+/// - `Err(String)` if the ID is invalid, the command fails or the output
+///   isn't JSON
+pub async fn inspect_raw(runtime: &Runtime, kind: ResourceKind, id: &str) -> Result<Value, String> {
+    let args = build_inspect_args(kind, id)?;
+    let runtime = runtime.clone();
+
+    let output =
+        tokio::task::spawn_blocking(move || runtime_command(&runtime).args(&args).output())
+            .await
+            .map_err(|e| format!("Inspect task failed: {}", e))?
+            .map_err(|e| format!("Failed to execute inspect: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("inspect failed: {}", stderr.trim()));
+    }
+
+    serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("Failed to parse inspect output: {}", e))
+}
+
+/// Checks whether a resource exists
+///
+/// Runs `<runtime> <kind> inspect <id>` and only looks at the exit status;
+/// nothing is pulled or created.
+///
+/// # Arguments
+/// * `runtime` - The runtime to query
+/// * `kind` - Container, image, volume or network
+/// * `id` - Name, ID or (for images) reference of the resource
+///
+/// # Returns
+/// `true` if the resource exists, `false` otherwise (including for an
+/// invalid ID or when the runtime itself cannot be executed)
+pub async fn resource_exists(runtime: &Runtime, kind: ResourceKind, id: &str) -> bool {
+    let Ok(args) = build_inspect_args(kind, id) else {
+        return false;
+    };
+    let runtime = runtime.clone();
+
+    let result = tokio::task::spawn_blocking(move || {
+        runtime_command(&runtime)
+            .args(&args)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+    })
+    .await;
+
+    matches!(result, Ok(Ok(status)) if status.success())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kind_maps_to_subcommand() {
+        for (kind, subcommand) in [
+            (ResourceKind::Container, "container"),
+            (ResourceKind::Image, "image"),
+            (ResourceKind::Volume, "volume"),
+            (ResourceKind::Network, "network"),
+        ] {
+            assert_eq!(
+                build_inspect_args(kind, "web").unwrap(),
+                vec![subcommand, "inspect", "web"]
+            );
+            assert_eq!(
+                serde_json::to_value(kind).unwrap(),
+                serde_json::json!(subcommand)
+            );
+        }
+    }
+
+    #[test]
+    fn test_build_inspect_args_rejects_invalid_ids() {
+        assert!(build_inspect_args(ResourceKind::Image, "").is_err());
+        assert!(build_inspect_args(ResourceKind::Image, "--format").is_err());
+    }
+
+    #[test]
+    fn test_validate_container_id() {
+        assert!(validate_container_id("web").is_ok());
+        assert!(validate_container_id("a1b2-c3").is_ok());
+        assert_eq!(
+            validate_container_id("--privileged").unwrap_err(),
+            "Invalid container ID: --privileged"
+        );
+        assert!(validate_container_id(" ").is_err());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_resource_exists() {
+        // `true`/`false` exit zero/non-zero for any arguments, like inspect
+        // of an existing/missing resource
+        let present = Runtime::for_test("true");
+        let absent = Runtime::for_test("false");
+        let invalid = Runtime::for_test("/nonexistent/docker");
+
+        for kind in [
+            ResourceKind::Image,
+            ResourceKind::Volume,
+            ResourceKind::Network,
+        ] {
+            assert!(resource_exists(&present, kind, "data").await);
+            assert!(!resource_exists(&present, kind, "--format").await);
+            assert!(!resource_exists(&absent, kind, "data").await);
+            assert!(!resource_exists(&invalid, kind, "data").await);
+        }
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_inspect_raw_requires_json() {
+        // `echo` prints its arguments, which aren't JSON
+        let runtime = Runtime::for_test("echo");
+        let result = inspect_raw(&runtime, ResourceKind::Volume, "data").await;
+        assert!(result.unwrap_err().contains("Failed to parse"));
+
+        let runtime = Runtime::for_test("false");
+        assert!(inspect_raw(&runtime, ResourceKind::Volume, "data")
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,49 @@
+//! JSON output shared by Docker and Podman
+//!
+//! For the same `--format json` subcommand Docker prints one JSON object per
+//! line while Podman prints a single array. `parse_json_rows` accepts both.
+
+use serde_json::Value;
+
+/// Parses CLI output that is either a JSON array or one JSON object per line
+///
+/// # Arguments
+/// * `output` - Raw stdout of the command
+/// * `what` - Command name used in the error, e.g. `system df`
+///
+/// # Returns
+/// - `Ok(Vec<Value>)` with one value per row; empty output gives no rows
+/// - `Err(String)` if the array or a line isn't JSON
+pub fn parse_json_rows(output: &str, what: &str) -> Result<Vec<Value>, String> {
+    let trimmed = output.trim();
+    if trimmed.starts_with('[') {
+        return serde_json::from_str(trimmed)
+            .map_err(|e| format!("Failed to parse {}: {}", what, e));
+    }
+
+    trimmed
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line).map_err(|e| format!("Failed to parse {}: {}", what, e))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_json_rows_array_and_lines() {
+        let array = parse_json_rows(r#"[{"a": 1}, {"a": 2}]"#, "test").unwrap();
+        let lines = parse_json_rows("{\"a\": 1}\n\n{\"a\": 2}\n", "test").unwrap();
+
+        assert_eq!(array, lines);
+        assert_eq!(array.len(), 2);
+        assert!(parse_json_rows("", "test").unwrap().is_empty());
+        assert!(parse_json_rows("{\"a\": 1}\nnot json", "test")
+            .unwrap_err()
+            .starts_with("Failed to parse test:"));
+    }
+}
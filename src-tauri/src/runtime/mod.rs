@@ -1,9 +1,20 @@
 // Runtime detection logic
 // To be implemented in later phases
 
+pub mod buildkit;
 pub mod cache;
+pub mod capabilities;
+pub mod command;
 pub mod detector;
+pub mod diagnostics;
 pub mod docker;
+pub mod events;
+pub mod info;
+pub mod inspect;
+pub mod json;
+pub mod nerdctl;
 pub mod podman;
+pub mod search;
 pub mod status;
+pub mod system;
 pub mod version;
@@ -0,0 +1,201 @@
+//! nerdctl runtime detection
+//!
+//! nerdctl is a Docker-compatible CLI for containerd. Its `ps`, `inspect`,
+//! `logs` and image subcommands accept the same arguments as Docker's, so the
+//! container and image modules work unchanged once `runtime.path` points at
+//! it. Detection mirrors the Docker detector: PATH and platform lookup,
+//! `nerdctl --version`, then `nerdctl info` for daemon status.
+
+use chrono::Utc;
+use std::error::Error;
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use crate::runtime::search::{
+    common_bin_dirs, find_executable, verify_executable, SEARCH_PARALLELISM,
+};
+use crate::runtime::version::{parse_version, validate_nerdctl_version};
+use crate::types::{DetectionError, DetectionResult, Runtime, RuntimeStatus, RuntimeType};
+
+/// Executable names to look for in platform-specific directories
+#[cfg(target_os = "windows")]
+const EXECUTABLE_NAMES: &[&str] = &["nerdctl.exe", "nerdctl"];
+#[cfg(not(target_os = "windows"))]
+const EXECUTABLE_NAMES: &[&str] = &["nerdctl"];
+
+/// Returns platform-specific nerdctl installation paths
+///
+/// # Platform Paths
+/// - **macOS**: /usr/local/bin, Homebrew (e.g. via Lima or Rancher Desktop)
+/// - **Linux**: /usr/bin, /usr/local/bin, nerdctl-full's /opt/nerdctl/bin
+fn get_platform_paths() -> Vec<PathBuf> {
+    let mut paths = common_bin_dirs();
+    if cfg!(target_os = "linux") {
+        paths.push(PathBuf::from("/opt/nerdctl/bin"));
+    }
+    paths
+}
+
+/// Locates the nerdctl executable in PATH or platform-specific directories
+///
+/// # Returns
+/// - `Some(PathBuf)` if nerdctl is found
+/// - `None` if not found
+fn find_nerdctl_executable() -> Option<PathBuf> {
+    if let Ok(path) = which::which("nerdctl") {
+        return Some(path);
+    }
+
+    find_executable(&get_platform_paths(), EXECUTABLE_NAMES, SEARCH_PARALLELISM)
+}
+
+/// Retrieves nerdctl version string, e.g. `nerdctl version 1.7.6`
+///
+/// # Returns
+/// - `Ok(String)` containing the version output
+/// - `Err` if the command fails
+fn get_nerdctl_version(nerdctl_path: &PathBuf) -> Result<String, Box<dyn Error>> {
+    let output = Command::new(nerdctl_path).arg("--version").output()?;
+
+    if !output.status.success() {
+        return Err("nerdctl version command failed".into());
+    }
+
+    let version_str = String::from_utf8(output.stdout)?;
+    Ok(version_str.trim().to_string())
+}
+
+/// Checks if containerd is reachable through `nerdctl info`
+fn check_nerdctl_running(nerdctl_path: &PathBuf) -> bool {
+    Command::new(nerdctl_path)
+        .arg("info")
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
+
+/// Detects a nerdctl installation with timeout protection
+///
+/// # Arguments
+/// * `timeout_ms` - Maximum time in milliseconds before detection aborts
+///
+/// # Returns
+/// `DetectionResult` with the found runtime, detection errors and duration
+pub async fn detect_nerdctl(timeout_ms: u64) -> DetectionResult {
+    let start = Instant::now();
+    let timeout = Duration::from_millis(timeout_ms);
+
+    let mut runtimes = Vec::new();
+    let mut errors = Vec::new();
+
+    let nerdctl_path = tokio::task::spawn_blocking(find_nerdctl_executable)
+        .await
+        .unwrap_or(None);
+
+    if let Some(path) = nerdctl_path {
+        let path_str = path.to_string_lossy().to_string();
+
+        if start.elapsed() > timeout {
+            errors.push(DetectionError {
+                runtime: RuntimeType::Nerdctl,
+                path: path_str,
+                error: "Detection timeout exceeded".to_string(),
+            });
+        } else if !verify_executable(&path) {
+            errors.push(DetectionError {
+                runtime: RuntimeType::Nerdctl,
+                path: path_str,
+                error: "Executable lacks proper permissions".to_string(),
+            });
+        } else {
+            match get_nerdctl_version(&path) {
+                Ok(version_str) => match parse_version(&version_str) {
+                    Ok(version) => {
+                        let status = if check_nerdctl_running(&path) {
+                            RuntimeStatus::Running
+                        } else {
+                            RuntimeStatus::Stopped
+                        };
+
+                        let version_warning = if !validate_nerdctl_version(&version) {
+                            Some(true)
+                        } else {
+                            None
+                        };
+
+                        runtimes.push(Runtime {
+                            id: format!("nerdctl-{}", path_str),
+                            runtime_type: RuntimeType::Nerdctl,
+                            path: path_str,
+                            version,
+                            status,
+                            last_checked: Utc::now(),
+                            detected_at: Utc::now(),
+                            mode: None,
+                            is_wsl: None,
+                            error: None,
+                            version_warning,
+                            edition: None,
+                            host: None,
+                            machine_running: None,
+                        });
+                    }
+                    Err(e) => {
+                        errors.push(DetectionError {
+                            runtime: RuntimeType::Nerdctl,
+                            path: path_str,
+                            error: format!("Failed to parse version: {}", e),
+                        });
+                    }
+                },
+                Err(e) => {
+                    errors.push(DetectionError {
+                        runtime: RuntimeType::Nerdctl,
+                        path: path_str,
+                        error: format!("Failed to get version: {}", e),
+                    });
+                }
+            }
+        }
+    }
+
+    DetectionResult {
+        runtimes,
+        detected_at: Utc::now(),
+        duration: start.elapsed().as_millis() as u64,
+        errors,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_platform_paths() {
+        let paths = get_platform_paths();
+        assert!(paths.starts_with(&common_bin_dirs()));
+
+        #[cfg(target_os = "linux")]
+        assert_eq!(paths.last(), Some(&PathBuf::from("/opt/nerdctl/bin")));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_get_nerdctl_version_output() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let script =
+            std::env::temp_dir().join(format!("harbor-master-nerdctl-{}.sh", std::process::id()));
+        std::fs::write(&script, "#!/bin/sh\necho 'nerdctl version 1.7.6'\n").unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let output = get_nerdctl_version(&script).unwrap();
+        std::fs::remove_file(&script).ok();
+        assert_eq!(output, "nerdctl version 1.7.6");
+        assert_eq!(parse_version(&output).unwrap().full, "1.7.6");
+
+        assert!(get_nerdctl_version(&PathBuf::from("false")).is_err());
+    }
+}
@@ -6,15 +6,24 @@
 
 use chrono::Utc;
 use std::error::Error;
-use std::path::{Path, PathBuf};
+use std::path::PathBuf;
 use std::process::Command;
 use std::time::{Duration, Instant};
 
+use crate::runtime::search::{
+    common_bin_dirs, find_executable, verify_executable, SEARCH_PARALLELISM,
+};
 use crate::runtime::version::{parse_version, validate_podman_version};
 use crate::types::{
-    DetectionError, DetectionResult, PodmanMode, Runtime, RuntimeStatus, RuntimeType,
+    DetectionError, DetectionResult, PodmanMode, Runtime, RuntimeStatus, RuntimeType, Version,
 };
 
+/// Executable names to look for in platform-specific directories
+#[cfg(target_os = "windows")]
+const EXECUTABLE_NAMES: &[&str] = &["podman.exe", "podman"];
+#[cfg(not(target_os = "windows"))]
+const EXECUTABLE_NAMES: &[&str] = &["podman"];
+
 /// Returns platform-specific Podman installation paths
 ///
 /// # Platform Paths
@@ -22,7 +31,7 @@ use crate::types::{
 /// - **macOS**: /usr/local/bin, Homebrew, /opt/podman
 /// - **Linux**: /usr/bin, /usr/local/bin, /usr/libexec/podman
 fn get_platform_paths() -> Vec<PathBuf> {
-    let mut paths = vec![];
+    let mut paths = common_bin_dirs();
 
     #[cfg(target_os = "windows")]
     {
@@ -32,15 +41,11 @@ fn get_platform_paths() -> Vec<PathBuf> {
 
     #[cfg(target_os = "macos")]
     {
-        paths.push(PathBuf::from("/usr/local/bin"));
-        paths.push(PathBuf::from("/opt/homebrew/bin"));
         paths.push(PathBuf::from("/opt/podman/bin"));
     }
 
     #[cfg(target_os = "linux")]
     {
-        paths.push(PathBuf::from("/usr/bin"));
-        paths.push(PathBuf::from("/usr/local/bin"));
         paths.push(PathBuf::from("/usr/libexec/podman"));
     }
 
@@ -62,65 +67,8 @@ fn find_podman_executable() -> Option<PathBuf> {
         return Some(path);
     }
 
-    // Try platform-specific paths
-    for path in get_platform_paths() {
-        if path.is_file() && path.file_name().unwrap_or_default() == "podman"
-            || path.file_name().unwrap_or_default() == "podman.exe"
-        {
-            return Some(path);
-        }
-
-        // Check if path is a directory, look for podman inside it
-        if path.is_dir() {
-            let podman_path = path.join("podman");
-            if podman_path.is_file() {
-                return Some(podman_path);
-            }
-
-            #[cfg(target_os = "windows")]
-            {
-                let podman_exe = path.join("podman.exe");
-                if podman_exe.is_file() {
-                    return Some(podman_exe);
-                }
-            }
-        }
-    }
-
-    None
-}
-
-/// Verifies that the executable has proper execute permissions
-///
-/// # Platform Behavior
-/// - **Unix**: Checks execute bits (0o111) in file permissions
-/// - **Windows**: Validates file exists and is not a directory
-///
-/// # Arguments
-/// * `path` - Path to the executable to verify
-///
-/// # Returns
-/// `true` if executable has proper permissions, `false` otherwise
-fn verify_executable(path: &Path) -> bool {
-    #[cfg(unix)]
-    {
-        use std::os::unix::fs::PermissionsExt;
-        if let Ok(metadata) = std::fs::metadata(path) {
-            let permissions = metadata.permissions();
-            permissions.mode() & 0o111 != 0 // Check execute bits
-        } else {
-            false
-        }
-    }
-
-    #[cfg(windows)]
-    {
-        // On Windows, just check if file exists and is not a directory
-        path.is_file()
-    }
-
-    #[cfg(not(any(unix, windows)))]
-    false
+    // Probe platform-specific paths with a small worker pool
+    find_executable(&get_platform_paths(), EXECUTABLE_NAMES, SEARCH_PARALLELISM)
 }
 
 /// Detects whether Podman is running in rootless or rootful mode
@@ -199,6 +147,57 @@ fn check_podman_running(podman_path: &PathBuf) -> bool {
     }
 }
 
+/// Whether Podman runs containers inside a `podman machine` VM on this platform
+pub fn machines_relevant() -> bool {
+    cfg!(any(target_os = "macos", target_os = "windows"))
+}
+
+/// Parses `podman machine list --format json` output
+///
+/// The default machine decides when one is marked; otherwise any running
+/// machine counts.
+///
+/// # Returns
+/// - `Some(true)` if the machine is running
+/// - `Some(false)` if machines exist but none is running
+/// - `None` if there are no machines or the output can't be parsed
+pub fn parse_machine_list(output: &str) -> Option<bool> {
+    let machines: Vec<serde_json::Value> = serde_json::from_str(output.trim()).ok()?;
+    if machines.is_empty() {
+        return None;
+    }
+
+    let is_running = |machine: &serde_json::Value| machine["Running"].as_bool() == Some(true);
+    match machines
+        .iter()
+        .find(|m| m["Default"].as_bool() == Some(true))
+    {
+        Some(default) => Some(is_running(default)),
+        None => Some(machines.iter().any(is_running)),
+    }
+}
+
+/// Detects whether a Podman machine exists and is running
+///
+/// Executes `podman machine list --format json`.
+///
+/// # Arguments
+/// * `podman_path` - Path to the Podman executable
+///
+/// # Returns
+/// See `parse_machine_list`; `None` also when the command fails
+pub fn detect_machine_status(podman_path: &Path) -> Option<bool> {
+    let output = Command::new(podman_path)
+        .args(["machine", "list", "--format", "json"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+    parse_machine_list(&String::from_utf8_lossy(&output.stdout))
+}
+
 /// Detects Podman installation on the system with timeout protection
 ///
 /// Performs comprehensive Podman detection including:
@@ -210,6 +209,7 @@ fn check_podman_running(podman_path: &PathBuf) -> bool {
 ///
 /// # Arguments
 /// * `timeout_ms` - Maximum time in milliseconds before detection aborts
+/// * `minimum` - Configured minimum version below which `version_warning` is set
 ///
 /// # Returns
 /// `DetectionResult` containing:
@@ -223,7 +223,7 @@ fn check_podman_running(podman_path: &PathBuf) -> bool {
 ///
 /// #[tokio::main]
 /// async fn main() {
-///     let result = detect_podman(5000).await;
+///     let result = detect_podman(5000, None).await;
 ///     for runtime in result.runtimes {
 ///         if let Some(mode) = runtime.mode {
 ///             println!("Found Podman in {:?} mode", mode);
@@ -231,7 +231,7 @@ fn check_podman_running(podman_path: &PathBuf) -> bool {
 ///     }
 /// }
 /// ```
-pub async fn detect_podman(timeout_ms: u64) -> DetectionResult {
+pub async fn detect_podman(timeout_ms: u64, minimum: Option<Version>) -> DetectionResult {
     let start = Instant::now();
     let timeout = Duration::from_millis(timeout_ms);
 
@@ -263,17 +263,25 @@ pub async fn detect_podman(timeout_ms: u64) -> DetectionResult {
                 Ok(version_str) => match parse_version(&version_str) {
                     Ok(version) => {
                         let mode = detect_rootless_mode(&path);
-                        let status = if check_podman_running(&path) {
-                            RuntimeStatus::Running
-                        } else {
-                            RuntimeStatus::Stopped
-                        };
-
-                        let version_warning = if !validate_podman_version(&version) {
-                            Some(true)
+                        let machine_running = if machines_relevant() {
+                            detect_machine_status(&path)
                         } else {
                             None
                         };
+                        // `podman info` can succeed while the machine is stopped
+                        let status =
+                            if machine_running != Some(false) && check_podman_running(&path) {
+                                RuntimeStatus::Running
+                            } else {
+                                RuntimeStatus::Stopped
+                            };
+
+                        let version_warning =
+                            if !validate_podman_version(&version, minimum.as_ref()) {
+                                Some(true)
+                            } else {
+                                None
+                            };
 
                         runtimes.push(Runtime {
                             id: format!("podman-{}", path.to_string_lossy()),
@@ -287,6 +295,9 @@ pub async fn detect_podman(timeout_ms: u64) -> DetectionResult {
                             is_wsl: None,
                             error: None,
                             version_warning,
+                            edition: None,
+                            host: None,
+                            machine_running,
                         });
                     }
                     Err(e) => {
@@ -343,6 +354,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_machine_list() {
+        let stopped = r#"[{"Name":"podman-machine-default","Default":true,"Running":false},
+                          {"Name":"dev","Default":false,"Running":true}]"#;
+        assert_eq!(parse_machine_list(stopped), Some(false));
+
+        let running = r#"[{"Name":"podman-machine-default*","Default":true,"Running":true}]"#;
+        assert_eq!(parse_machine_list(running), Some(true));
+
+        let no_default = r#"[{"Name":"a","Running":false},{"Name":"b","Running":true}]"#;
+        assert_eq!(parse_machine_list(no_default), Some(true));
+
+        assert_eq!(parse_machine_list("[]"), None);
+        assert_eq!(parse_machine_list("Error: not supported"), None);
+    }
+
     #[test]
     fn test_parse_version_valid() {
         let version_str = "podman version 4.5.1";
@@ -403,7 +430,7 @@ mod tests {
             patch: 0,
             full: "4.0.0".to_string(),
         };
-        assert!(validate_podman_version(&valid));
+        assert!(validate_podman_version(&valid, None));
 
         let exact_min = Version {
             major: 3,
@@ -411,7 +438,7 @@ mod tests {
             patch: 0,
             full: "3.0.0".to_string(),
         };
-        assert!(validate_podman_version(&exact_min));
+        assert!(validate_podman_version(&exact_min, None));
     }
 
     #[test]
@@ -422,7 +449,7 @@ mod tests {
             patch: 9,
             full: "2.9.9".to_string(),
         };
-        assert!(!validate_podman_version(&too_old));
+        assert!(!validate_podman_version(&too_old, None));
 
         let very_old = Version {
             major: 1,
@@ -430,7 +457,7 @@ mod tests {
             patch: 0,
             full: "1.0.0".to_string(),
         };
-        assert!(!validate_podman_version(&very_old));
+        assert!(!validate_podman_version(&very_old, None));
     }
 
     #[test]
@@ -448,14 +475,14 @@ mod tests {
 
     #[tokio::test]
     async fn test_detect_podman_timeout() {
-        let result = detect_podman(500).await;
+        let result = detect_podman(500, None).await;
         // Should complete within reasonable time, allowing for extremely slow CI runners
         assert!(result.duration <= 15000); // Allow up to 15 seconds for extremely slow CI environments
     }
 
     #[tokio::test]
     async fn test_detect_podman_structure() {
-        let result = detect_podman(500).await;
+        let result = detect_podman(500, None).await;
 
         // Verify result structure is valid
         // Duration varies based on system speed and may exceed timeout on slow CI runners
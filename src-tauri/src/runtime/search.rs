@@ -0,0 +1,232 @@
+//! Bounded-concurrency executable search
+//!
+//! Platform search paths are probed by a small pool of worker threads
+//! instead of one tight synchronous loop, so slow disks (network mounts,
+//! spun-down drives) don't serialize every `stat`. The pool size is capped
+//! to avoid thrashing. Candidates keep their priority: the earliest path in
+//! the list that contains a match wins, whichever worker finds it first.
+//!
+//! The directories and permission check shared by every runtime's detector
+//! live here too; each detector only adds its own install locations.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// Maximum number of candidate paths probed at the same time
+pub const SEARCH_PARALLELISM: usize = 4;
+
+/// Returns the system binary directories every runtime detector searches
+///
+/// # Platform Paths
+/// - **macOS**: /usr/local/bin, Homebrew
+/// - **Linux**: /usr/bin, /usr/local/bin
+/// - **Windows**: none; installers use their own Program Files directory
+pub fn common_bin_dirs() -> Vec<PathBuf> {
+    let mut paths = vec![];
+
+    #[cfg(target_os = "macos")]
+    {
+        paths.push(PathBuf::from("/usr/local/bin"));
+        paths.push(PathBuf::from("/opt/homebrew/bin"));
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        paths.push(PathBuf::from("/usr/bin"));
+        paths.push(PathBuf::from("/usr/local/bin"));
+    }
+
+    paths
+}
+
+/// Verifies that the executable has proper execute permissions
+///
+/// # Platform Behavior
+/// - **Unix**: Checks execute bits (0o111) in file permissions
+/// - **Windows**: Validates file exists and is not a directory
+///
+/// # Arguments
+/// * `path` - Path to the executable to verify
+///
+/// # Returns
+/// `true` if executable has proper permissions, `false` otherwise
+pub fn verify_executable(path: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if let Ok(metadata) = std::fs::metadata(path) {
+            let permissions = metadata.permissions();
+            permissions.mode() & 0o111 != 0 // Check execute bits
+        } else {
+            false
+        }
+    }
+
+    #[cfg(windows)]
+    {
+        // On Windows, just check if file exists and is not a directory
+        path.is_file()
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    false
+}
+
+/// Checks a single candidate for one of the executable names
+///
+/// A candidate may be the executable itself or a directory containing it.
+fn resolve_candidate(candidate: &Path, names: &[&str]) -> Option<PathBuf> {
+    if candidate.is_file() {
+        let file_name = candidate.file_name()?.to_str()?;
+        return names.contains(&file_name).then(|| candidate.to_path_buf());
+    }
+
+    if candidate.is_dir() {
+        return names
+            .iter()
+            .map(|name| candidate.join(name))
+            .find(|path| path.is_file());
+    }
+
+    None
+}
+
+/// Finds the first candidate that is (or contains) an executable named in `names`
+///
+/// # Arguments
+/// * `candidates` - Paths in priority order (files or directories)
+/// * `names` - Executable file names to look for, e.g. `["docker"]`
+/// * `parallelism` - Number of worker threads (clamped to at least 1)
+///
+/// # Returns
+/// - `Some(PathBuf)` for the highest-priority match
+/// - `None` if no candidate matches
+pub fn find_executable(
+    candidates: &[PathBuf],
+    names: &[&str],
+    parallelism: usize,
+) -> Option<PathBuf> {
+    let workers = parallelism.clamp(1, candidates.len().max(1));
+    let next = AtomicUsize::new(0);
+    // Index and path of the best match found so far
+    let best: Mutex<Option<(usize, PathBuf)>> = Mutex::new(None);
+
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::SeqCst);
+                if index >= candidates.len() {
+                    break;
+                }
+
+                // A higher-priority match already exists, nothing left to win
+                let beaten = best
+                    .lock()
+                    .map(|b| b.as_ref().is_some_and(|(i, _)| *i < index))
+                    .unwrap_or(false);
+                if beaten {
+                    break;
+                }
+
+                if let Some(found) = resolve_candidate(&candidates[index], names) {
+                    if let Ok(mut best) = best.lock() {
+                        if best.as_ref().is_none_or(|(i, _)| index < *i) {
+                            *best = Some((index, found));
+                        }
+                    }
+                }
+            });
+        }
+    });
+
+    best.into_inner().ok().flatten().map(|(_, path)| path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!(
+            "harbor-master-search-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(&root).unwrap();
+        root
+    }
+
+    #[test]
+    fn test_verify_executable_permissions() {
+        // Test with a known executable (the current binary)
+        let current_exe = std::env::current_exe().unwrap();
+        assert!(verify_executable(&current_exe));
+
+        // Test with a non-existent path
+        let fake_path = PathBuf::from("/nonexistent/path/to/binary");
+        assert!(!verify_executable(&fake_path));
+    }
+
+    #[test]
+    fn test_finds_planted_executable_among_decoys() {
+        let root = temp_root("planted");
+        let mut candidates = Vec::new();
+        for i in 0..8 {
+            let dir = root.join(format!("decoy-{}", i));
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(dir.join("not-docker"), "").unwrap();
+            candidates.push(dir);
+        }
+        candidates.insert(5, root.join("missing"));
+
+        let planted = root.join("decoy-6").join("docker");
+        std::fs::write(&planted, "").unwrap();
+
+        let found = find_executable(&candidates, &["docker"], SEARCH_PARALLELISM);
+        std::fs::remove_dir_all(&root).ok();
+
+        assert_eq!(found, Some(planted));
+    }
+
+    #[test]
+    fn test_earliest_candidate_wins() {
+        let root = temp_root("priority");
+        let first = root.join("first");
+        let second = root.join("second");
+        std::fs::create_dir_all(&first).unwrap();
+        std::fs::create_dir_all(&second).unwrap();
+        std::fs::write(first.join("podman"), "").unwrap();
+        std::fs::write(second.join("podman"), "").unwrap();
+
+        let candidates = vec![root.join("empty"), first.clone(), second];
+        let found = find_executable(&candidates, &["podman"], 3);
+        std::fs::remove_dir_all(&root).ok();
+
+        assert_eq!(found, Some(first.join("podman")));
+    }
+
+    #[test]
+    fn test_executable_name_alone_does_not_match() {
+        // A path merely named like the executable must also be a real file
+        let root = temp_root("named-dir");
+        let missing = root.join("missing").join("docker.exe");
+        let directory = root.join("docker.exe");
+        std::fs::create_dir_all(&directory).unwrap();
+
+        let candidates = vec![missing.clone(), directory.clone()];
+        let names = ["docker", "docker.exe"];
+        let found = find_executable(&candidates, &names, SEARCH_PARALLELISM);
+        let resolved_missing = resolve_candidate(&missing, &names);
+        std::fs::remove_dir_all(&root).ok();
+
+        assert!(found.is_none());
+        assert!(resolved_missing.is_none());
+    }
+
+    #[test]
+    fn test_no_candidates() {
+        assert!(find_executable(&[], &["docker"], SEARCH_PARALLELISM).is_none());
+    }
+}
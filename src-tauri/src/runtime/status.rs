@@ -4,11 +4,11 @@
 //! determining whether they are Running, Stopped, in an Error state, or Unknown.
 //! Uses timeouts to prevent hanging on unresponsive runtimes.
 
-use std::path::PathBuf;
-use std::process::Command;
 use std::time::Duration;
 use tokio::time::timeout;
 
+use crate::runtime::command::runtime_command;
+use crate::runtime::podman::{detect_machine_status, machines_relevant};
 use crate::types::{Runtime, RuntimeStatus};
 
 /// Maximum time to wait for a status check command (3 seconds)
@@ -19,18 +19,18 @@ const STATUS_CHECK_TIMEOUT: Duration = Duration::from_secs(3);
 /// Executes `docker info` with a 3-second timeout to determine daemon status.
 ///
 /// # Arguments
-/// * `path` - Path to the Docker executable
+/// * `runtime` - The Docker runtime, including its `host` if remote
 ///
 /// # Returns
 /// - `RuntimeStatus::Running` if daemon is accessible and responsive
 /// - `RuntimeStatus::Stopped` if daemon is not running or command fails
 /// - `RuntimeStatus::Error` if permission denied
 /// - `RuntimeStatus::Unknown` if timeout occurs
-async fn check_docker_status(path: &str) -> RuntimeStatus {
-    let path_buf = PathBuf::from(path);
+async fn check_docker_status(runtime: &Runtime) -> RuntimeStatus {
+    let runtime = runtime.clone();
 
     let result = timeout(STATUS_CHECK_TIMEOUT, async {
-        tokio::task::spawn_blocking(move || Command::new(&path_buf).arg("info").output()).await
+        tokio::task::spawn_blocking(move || runtime_command(&runtime).arg("info").output()).await
     })
     .await;
 
@@ -58,20 +58,55 @@ async fn check_docker_status(path: &str) -> RuntimeStatus {
 /// Checks if Podman is accessible and running
 ///
 /// Executes `podman info` with a 3-second timeout to verify accessibility.
+/// On macOS and Windows the `podman machine` VM is checked as well, since
+/// `podman info` can succeed while the machine is stopped.
 ///
 /// # Arguments
-/// * `path` - Path to the Podman executable
+/// * `runtime` - The Podman runtime
 ///
 /// # Returns
 /// - `RuntimeStatus::Running` if Podman is accessible and responsive
 /// - `RuntimeStatus::Stopped` if Podman service is not running or command fails
 /// - `RuntimeStatus::Error` if permission denied
 /// - `RuntimeStatus::Unknown` if timeout occurs
-async fn check_podman_status(path: &str) -> RuntimeStatus {
-    let path_buf = PathBuf::from(path);
+async fn check_podman_status(runtime: &Runtime) -> RuntimeStatus {
+    let info_status = check_podman_info(runtime).await;
+    if !machines_relevant() {
+        return info_status;
+    }
+
+    let path = std::path::PathBuf::from(&runtime.path);
+    let machine = timeout(STATUS_CHECK_TIMEOUT, async {
+        tokio::task::spawn_blocking(move || detect_machine_status(&path)).await
+    })
+    .await;
+
+    match machine {
+        Ok(Ok(machine_running)) => apply_machine_status(info_status, machine_running),
+        _ => info_status,
+    }
+}
+
+/// Overrides the `podman info` status with the machine state
+///
+/// A stopped machine means no containers can run, whatever `info` said.
+fn apply_machine_status(
+    info_status: RuntimeStatus,
+    machine_running: Option<bool>,
+) -> RuntimeStatus {
+    if machine_running == Some(false) {
+        RuntimeStatus::Stopped
+    } else {
+        info_status
+    }
+}
+
+/// Runs `podman info` with a timeout and maps the outcome to a status
+async fn check_podman_info(runtime: &Runtime) -> RuntimeStatus {
+    let runtime = runtime.clone();
 
     let result = timeout(STATUS_CHECK_TIMEOUT, async {
-        tokio::task::spawn_blocking(move || Command::new(&path_buf).arg("info").output()).await
+        tokio::task::spawn_blocking(move || runtime_command(&runtime).arg("info").output()).await
     })
     .await;
 
@@ -131,6 +166,9 @@ async fn check_podman_status(path: &str) -> RuntimeStatus {
 ///         is_wsl: None,
 ///         error: None,
 ///         version_warning: None,
+///         edition: None,
+///         host: None,
+///         machine_running: None,
 ///     };
 ///     
 ///     let status = check_status(&runtime).await;
@@ -143,41 +181,21 @@ async fn check_podman_status(path: &str) -> RuntimeStatus {
 /// ```
 pub async fn check_status(runtime: &Runtime) -> RuntimeStatus {
     match runtime.runtime_type {
-        crate::types::RuntimeType::Docker => check_docker_status(&runtime.path).await,
-        crate::types::RuntimeType::Podman => check_podman_status(&runtime.path).await,
+        // nerdctl reports a missing containerd through `info` just like Docker
+        crate::types::RuntimeType::Docker | crate::types::RuntimeType::Nerdctl => {
+            check_docker_status(runtime).await
+        }
+        crate::types::RuntimeType::Podman => check_podman_status(runtime).await,
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{RuntimeType, Version};
-    use chrono::Utc;
-
-    fn create_test_runtime(runtime_type: RuntimeType, path: &str) -> Runtime {
-        Runtime {
-            id: "test".to_string(),
-            runtime_type,
-            path: path.to_string(),
-            version: Version {
-                major: 24,
-                minor: 0,
-                patch: 7,
-                full: "24.0.7".to_string(),
-            },
-            status: RuntimeStatus::Unknown,
-            last_checked: Utc::now(),
-            detected_at: Utc::now(),
-            mode: None,
-            is_wsl: None,
-            error: None,
-            version_warning: None,
-        }
-    }
 
     #[tokio::test]
     async fn test_check_status_invalid_path() {
-        let runtime = create_test_runtime(RuntimeType::Docker, "/nonexistent/docker");
+        let runtime = Runtime::for_test("/nonexistent/docker").with_status(RuntimeStatus::Unknown);
         let status = check_status(&runtime).await;
         // Invalid path returns Stopped (not Error - we only use Error for permission issues)
         assert_eq!(status, RuntimeStatus::Stopped);
@@ -187,7 +205,7 @@ mod tests {
     async fn test_check_status_timeout() {
         // This test verifies timeout logic by using a non-existent path
         // The command will fail quickly, not timeout, so we just verify it completes
-        let runtime = create_test_runtime(RuntimeType::Docker, "/nonexistent/path");
+        let runtime = Runtime::for_test("/nonexistent/path").with_status(RuntimeStatus::Unknown);
         let start = std::time::Instant::now();
         let status = check_status(&runtime).await;
         let elapsed = start.elapsed();
@@ -196,4 +214,21 @@ mod tests {
         assert!(elapsed < STATUS_CHECK_TIMEOUT + Duration::from_millis(500));
         assert_eq!(status, RuntimeStatus::Stopped);
     }
+
+    #[test]
+    fn test_apply_machine_status() {
+        // `podman info` succeeding against a stopped machine is not Running
+        assert_eq!(
+            apply_machine_status(RuntimeStatus::Running, Some(false)),
+            RuntimeStatus::Stopped
+        );
+        assert_eq!(
+            apply_machine_status(RuntimeStatus::Running, Some(true)),
+            RuntimeStatus::Running
+        );
+        assert_eq!(
+            apply_machine_status(RuntimeStatus::Error, None),
+            RuntimeStatus::Error
+        );
+    }
 }
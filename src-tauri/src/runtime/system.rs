@@ -0,0 +1,170 @@
+//! Disk usage from `system df`
+//!
+//! Docker prints one JSON object per line with string fields, e.g.
+//! `{"Type":"Images","TotalCount":"5","Active":"2","Size":"2.4GB",
+//! "Reclaimable":"1.2GB (50%)"}`. Podman prints a JSON array, uses numeric
+//! counts (`Total`) and has no build cache. Missing sections and fields
+//! default to zero so both render the same overview.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::runtime::command::runtime_command;
+use crate::runtime::json::parse_json_rows;
+use crate::types::Runtime;
+
+/// Usage of one kind of resource
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UsageEntry {
+    pub total: u64,
+    pub active: u64,
+    /// Human-readable size as printed by the CLI, e.g. `2.4GB`
+    pub size: String,
+    /// Human-readable reclaimable size, e.g. `1.2GB (50%)`
+    pub reclaimable: String,
+}
+
+impl Default for UsageEntry {
+    fn default() -> Self {
+        Self {
+            total: 0,
+            active: 0,
+            size: "0B".to_string(),
+            reclaimable: "0B".to_string(),
+        }
+    }
+}
+
+/// Disk usage per resource kind
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DiskUsage {
+    pub images: UsageEntry,
+    pub containers: UsageEntry,
+    pub volumes: UsageEntry,
+    #[serde(rename = "buildCache")]
+    pub build_cache: UsageEntry,
+}
+
+/// Reads a count that may be a JSON number or a numeric string
+fn count_field(row: &Value, keys: &[&str]) -> u64 {
+    keys.iter()
+        .find_map(|key| match &row[key] {
+            Value::Number(n) => n.as_u64(),
+            Value::String(s) => s.trim().parse().ok(),
+            _ => None,
+        })
+        .unwrap_or(0)
+}
+
+/// Reads a size string, keeping the default when absent
+fn size_field(row: &Value, key: &str) -> String {
+    row[key]
+        .as_str()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .unwrap_or("0B")
+        .to_string()
+}
+
+/// Parses `system df --format json` output from Docker or Podman
+///
+/// # Returns
+/// - `Ok(DiskUsage)`; sections the runtime doesn't report stay zero
+/// - `Err(String)` if the output isn't JSON
+pub fn parse_disk_usage(output: &str) -> Result<DiskUsage, String> {
+    let rows = parse_json_rows(output, "system df")?;
+
+    let mut usage = DiskUsage::default();
+    for row in &rows {
+        let entry = UsageEntry {
+            total: count_field(row, &["TotalCount", "Total"]),
+            active: count_field(row, &["Active"]),
+            size: size_field(row, "Size"),
+            reclaimable: size_field(row, "Reclaimable"),
+        };
+        match row["Type"]
+            .as_str()
+            .unwrap_or_default()
+            .to_lowercase()
+            .as_str()
+        {
+            "images" => usage.images = entry,
+            "containers" => usage.containers = entry,
+            "local volumes" | "volumes" => usage.volumes = entry,
+            "build cache" => usage.build_cache = entry,
+            _ => {}
+        }
+    }
+    Ok(usage)
+}
+
+/// Reports disk usage of images, containers, volumes and build cache
+///
+/// # Arguments
+/// * `runtime` - The runtime to query
+///
+/// # Returns
+/// - `Ok(DiskUsage)` parsed from `system df --format json`
+/// - `Err(String)` if the command fails or its output can't be parsed
+pub async fn disk_usage(runtime: &Runtime) -> Result<DiskUsage, String> {
+    let runtime = runtime.clone();
+
+    let output = tokio::task::spawn_blocking(move || {
+        runtime_command(&runtime)
+            .args(["system", "df", "--format", "json"])
+            .output()
+    })
+    .await
+    .map_err(|e| format!("System df task failed: {}", e))?
+    .map_err(|e| format!("Failed to execute system df: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("system df failed: {}", stderr.trim()));
+    }
+
+    parse_disk_usage(&String::from_utf8_lossy(&output.stdout))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_docker_disk_usage() {
+        let output = r#"{"Active":"2","Reclaimable":"1.2GB (50%)","Size":"2.4GB","TotalCount":"5","Type":"Images"}
+{"Active":"1","Reclaimable":"0B (0%)","Size":"12kB","TotalCount":"3","Type":"Containers"}
+{"Active":"1","Reclaimable":"300MB (60%)","Size":"500MB","TotalCount":"2","Type":"Local Volumes"}
+{"Active":"0","Reclaimable":"1.1GB","Size":"1.1GB","TotalCount":"40","Type":"Build Cache"}
+"#;
+        let usage = parse_disk_usage(output).unwrap();
+
+        assert_eq!(
+            usage.images,
+            UsageEntry {
+                total: 5,
+                active: 2,
+                size: "2.4GB".to_string(),
+                reclaimable: "1.2GB (50%)".to_string(),
+            }
+        );
+        assert_eq!(usage.containers.total, 3);
+        assert_eq!(usage.volumes.reclaimable, "300MB (60%)");
+        assert_eq!(usage.build_cache.total, 40);
+    }
+
+    #[test]
+    fn test_parse_podman_disk_usage_defaults_missing_sections() {
+        let output = r#"[{"Type":"Images","Total":4,"Active":1,"Size":"1.5GB","Reclaimable":"900MB (60%)"},
+                         {"Type":"Containers","Total":2,"Active":2,"Size":"40kB","Reclaimable":"0B (0%)"},
+                         {"Type":"Local Volumes","Total":1,"Active":0}]"#;
+        let usage = parse_disk_usage(output).unwrap();
+
+        assert_eq!(usage.images.total, 4);
+        assert_eq!(usage.containers.active, 2);
+        assert_eq!(usage.volumes.size, "0B");
+        assert_eq!(usage.build_cache, UsageEntry::default());
+
+        assert!(parse_disk_usage("not json").is_err());
+    }
+}
@@ -1,7 +1,7 @@
 //! Version parsing and validation utilities
 //!
 //! This module provides functions to parse semantic version strings from
-//! Docker and Podman output, and validate versions against minimum requirements.
+//! Docker, Podman and nerdctl output, and validate versions against minimum requirements.
 
 use crate::types::Version;
 use regex::Regex;
@@ -12,6 +12,7 @@ use std::error::Error;
 /// Handles multiple output formats:
 /// - `"Docker version 24.0.7, build afdd53b"` → 24.0.7
 /// - `"podman version 4.8.0"` → 4.8.0
+/// - `"nerdctl version 1.7.6"` → 1.7.6
 /// - `"24.0.7"` → 24.0.7
 ///
 /// # Arguments
@@ -62,32 +63,77 @@ pub fn parse_version(version_str: &str) -> Result<Version, Box<dyn Error>> {
     }
 }
 
+/// Parses a configured minimum such as `"24.0.0"`
+///
+/// # Returns
+/// `None` for an absent or unparseable preference, so the built-in minimum applies
+pub fn parse_minimum(minimum: Option<&str>) -> Option<Version> {
+    minimum.and_then(|minimum| parse_version(minimum).ok())
+}
+
+/// Builds a version constant; `full` stays empty since ordering ignores it
+const fn minimum(major: u32, minor: u32, patch: u32) -> Version {
+    Version {
+        major,
+        minor,
+        patch,
+        full: String::new(),
+    }
+}
+
+/// Oldest supported Docker version
+pub const MIN_DOCKER_VERSION: Version = minimum(20, 10, 0);
+
+/// Oldest supported Podman version
+pub const MIN_PODMAN_VERSION: Version = minimum(3, 0, 0);
+
+/// Oldest supported nerdctl version
+pub const MIN_NERDCTL_VERSION: Version = minimum(1, 0, 0);
+
 /// Validates Docker version against minimum requirements
 ///
 /// Ensures Docker version is >= 20.10.0, which is the minimum supported version
-/// for modern container features and security updates.
+/// for modern container features and security updates, unless a stricter
+/// (or looser) minimum is configured.
 ///
 /// # Arguments
 /// * `version` - Parsed version to validate
+/// * `minimum` - Configured minimum from preferences, if any
 ///
 /// # Returns
 /// `true` if version meets minimum requirements, `false` otherwise
-pub fn validate_docker_version(version: &Version) -> bool {
-    version.major > 20 || (version.major == 20 && version.minor >= 10)
+pub fn validate_docker_version(version: &Version, minimum: Option<&Version>) -> bool {
+    *version >= *minimum.unwrap_or(&MIN_DOCKER_VERSION)
 }
 
 /// Validates Podman version against minimum requirements
 ///
 /// Ensures Podman version is >= 3.0.0, which provides stable API compatibility
-/// and essential container management features.
+/// and essential container management features, unless another minimum is
+/// configured.
+///
+/// # Arguments
+/// * `version` - Parsed version to validate
+/// * `minimum` - Configured minimum from preferences, if any
+///
+/// # Returns
+/// `true` if version meets minimum requirements, `false` otherwise
+pub fn validate_podman_version(version: &Version, minimum: Option<&Version>) -> bool {
+    *version >= *minimum.unwrap_or(&MIN_PODMAN_VERSION)
+}
+
+/// Validates nerdctl version against minimum requirements
+///
+/// Ensures nerdctl version is >= 1.0.0, the first release with a stable
+/// Docker-compatible CLI.
 ///
 /// # Arguments
 /// * `version` - Parsed version to validate
 ///
 /// # Returns
 /// `true` if version meets minimum requirements, `false` otherwise
-pub fn validate_podman_version(version: &Version) -> bool {
-    version.major >= 3
+pub fn validate_nerdctl_version(version: &Version) -> bool {
+    *version >= MIN_NERDCTL_VERSION
 }
 
 #[cfg(test)]
@@ -112,6 +158,18 @@ mod tests {
         assert_eq!(result.full, "4.8.0");
     }
 
+    #[test]
+    fn test_parse_nerdctl_version() {
+        let result = parse_version("nerdctl version 1.7.6").unwrap();
+        assert_eq!(result.major, 1);
+        assert_eq!(result.minor, 7);
+        assert_eq!(result.patch, 6);
+        assert_eq!(result.full, "1.7.6");
+
+        assert!(validate_nerdctl_version(&result));
+        assert!(!validate_nerdctl_version(&parse_version("0.23.0").unwrap()));
+    }
+
     #[test]
     fn test_parse_simple_version() {
         let result = parse_version("24.0.7").unwrap();
@@ -122,49 +180,108 @@ mod tests {
 
     #[test]
     fn test_validate_docker_version() {
-        assert!(validate_docker_version(&Version {
-            major: 24,
-            minor: 0,
-            patch: 7,
-            full: "24.0.7".to_string(),
-        }));
-
-        assert!(validate_docker_version(&Version {
-            major: 20,
-            minor: 10,
-            patch: 0,
-            full: "20.10.0".to_string(),
-        }));
-
-        assert!(!validate_docker_version(&Version {
-            major: 20,
-            minor: 9,
-            patch: 0,
-            full: "20.9.0".to_string(),
-        }));
+        assert!(validate_docker_version(
+            &Version {
+                major: 24,
+                minor: 0,
+                patch: 7,
+                full: "24.0.7".to_string(),
+            },
+            None
+        ));
+
+        assert!(validate_docker_version(
+            &Version {
+                major: 20,
+                minor: 10,
+                patch: 0,
+                full: "20.10.0".to_string(),
+            },
+            None
+        ));
+
+        assert!(!validate_docker_version(
+            &Version {
+                major: 20,
+                minor: 9,
+                patch: 0,
+                full: "20.9.0".to_string(),
+            },
+            None
+        ));
     }
 
     #[test]
     fn test_validate_podman_version() {
-        assert!(validate_podman_version(&Version {
-            major: 4,
-            minor: 8,
-            patch: 0,
-            full: "4.8.0".to_string(),
-        }));
-
-        assert!(validate_podman_version(&Version {
-            major: 3,
-            minor: 0,
-            patch: 0,
-            full: "3.0.0".to_string(),
-        }));
-
-        assert!(!validate_podman_version(&Version {
-            major: 2,
-            minor: 9,
-            patch: 0,
-            full: "2.9.0".to_string(),
-        }));
+        assert!(validate_podman_version(
+            &Version {
+                major: 4,
+                minor: 8,
+                patch: 0,
+                full: "4.8.0".to_string(),
+            },
+            None
+        ));
+
+        assert!(validate_podman_version(
+            &Version {
+                major: 3,
+                minor: 0,
+                patch: 0,
+                full: "3.0.0".to_string(),
+            },
+            None
+        ));
+
+        assert!(!validate_podman_version(
+            &Version {
+                major: 2,
+                minor: 9,
+                patch: 0,
+                full: "2.9.0".to_string(),
+            },
+            None
+        ));
+    }
+
+    #[test]
+    fn test_version_ordering() {
+        let current = parse_version("Docker version 24.0.7, build afdd53b").unwrap();
+        let old = parse_version("20.10.0").unwrap();
+        assert!(current > old);
+        assert!(old < current);
+        assert_eq!(old, MIN_DOCKER_VERSION);
+
+        // Patch alone decides when major and minor match
+        assert!(parse_version("20.10.1").unwrap() > old);
+        assert!(parse_version("20.9.99").unwrap() < old);
+
+        // `full` is ignored in ordering and equality
+        let rc = Version {
+            full: "24.0.7-rc1".to_string(),
+            ..current.clone()
+        };
+        assert_eq!(rc, current);
+        assert_eq!(rc.cmp(&current), std::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn test_custom_minimum_flags_otherwise_valid_version() {
+        let docker = parse_version("23.0.6").unwrap();
+        assert!(validate_docker_version(&docker, None));
+
+        let org_minimum = parse_minimum(Some("24.0.0"));
+        assert!(!validate_docker_version(&docker, org_minimum.as_ref()));
+        let current = parse_version("24.0.7").unwrap();
+        assert!(validate_docker_version(&current, org_minimum.as_ref()));
+
+        let podman = parse_version("4.3.1").unwrap();
+        assert!(validate_podman_version(&podman, None));
+        let podman_minimum = parse_minimum(Some("4.9.0"));
+        assert!(!validate_podman_version(&podman, podman_minimum.as_ref()));
+
+        // A garbled preference falls back to the built-in minimum
+        assert!(parse_minimum(Some("latest")).is_none());
+        assert!(parse_minimum(None).is_none());
     }
 }
@@ -1,11 +1,15 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+use crate::polling::crash_loop::{DEFAULT_CRASH_LOOP_THRESHOLD, DEFAULT_CRASH_LOOP_WINDOW};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "lowercase")]
 pub enum RuntimeType {
     Docker,
     Podman,
+    Nerdctl,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
@@ -24,6 +28,8 @@ pub enum PodmanMode {
     Rootless,
 }
 
+/// Semantic version; ordering and equality compare major, then minor,
+/// then patch, and ignore the `full` display string
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Version {
     pub major: u32,
@@ -32,6 +38,26 @@ pub struct Version {
     pub full: String,
 }
 
+impl PartialEq for Version {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Version {}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch).cmp(&(other.major, other.minor, other.patch))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Runtime {
     pub id: String,
@@ -52,6 +78,81 @@ pub struct Runtime {
     pub error: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "versionWarning")]
     pub version_warning: Option<bool>,
+    /// Docker only: "desktop" for Docker Desktop, "engine" for a bare Docker Engine
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edition: Option<String>,
+    /// Docker only: daemon address passed as `-H`, e.g. `tcp://10.0.0.5:2376`;
+    /// `None` uses the CLI's default socket or context
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    /// Podman on macOS/Windows only: whether the `podman machine` VM is
+    /// running; `None` where machines don't apply or none exists
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "machineRunning"
+    )]
+    pub machine_running: Option<bool>,
+}
+
+#[cfg(test)]
+impl Runtime {
+    /// Running Docker 24.0.7 runtime with ID `test` at `path`, for tests
+    ///
+    /// Chain the `with_*` methods to change the fields a test cares about.
+    pub(crate) fn for_test(path: &str) -> Self {
+        Self {
+            id: "test".to_string(),
+            runtime_type: RuntimeType::Docker,
+            path: path.to_string(),
+            version: Version {
+                major: 24,
+                minor: 0,
+                patch: 7,
+                full: "24.0.7".to_string(),
+            },
+            status: RuntimeStatus::Running,
+            last_checked: Utc::now(),
+            detected_at: Utc::now(),
+            mode: None,
+            is_wsl: None,
+            error: None,
+            version_warning: None,
+            edition: None,
+            host: None,
+            machine_running: None,
+        }
+    }
+
+    pub(crate) fn with_id(mut self, id: &str) -> Self {
+        self.id = id.to_string();
+        self
+    }
+
+    pub(crate) fn with_type(mut self, runtime_type: RuntimeType) -> Self {
+        self.runtime_type = runtime_type;
+        self
+    }
+
+    pub(crate) fn with_status(mut self, status: RuntimeStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub(crate) fn with_host(mut self, host: &str) -> Self {
+        self.host = Some(host.to_string());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlatformInfo {
+    pub os: String,
+    pub arch: String,
+    #[serde(rename = "isWsl")]
+    pub is_wsl: bool,
+    #[serde(rename = "kernelVersion")]
+    pub kernel_version: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -80,7 +181,7 @@ pub struct StatusUpdate {
     pub error: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct RuntimePreferences {
     #[serde(
         skip_serializing_if = "Option::is_none",
@@ -100,6 +201,94 @@ pub struct RuntimePreferences {
     pub detection_cache_ttl: u64, // seconds
     #[serde(rename = "statusPollInterval", alias = "status_poll_interval")]
     pub status_poll_interval: u64, // seconds
+    #[serde(
+        rename = "detectionTimeoutMs",
+        alias = "detection_timeout_ms",
+        default = "default_detection_timeout_ms"
+    )]
+    pub detection_timeout_ms: u64, // milliseconds
+    #[serde(
+        rename = "crashLoopThreshold",
+        alias = "crash_loop_threshold",
+        default = "default_crash_loop_threshold"
+    )]
+    pub crash_loop_threshold: u32,
+    #[serde(
+        rename = "crashLoopWindow",
+        alias = "crash_loop_window",
+        default = "default_crash_loop_window"
+    )]
+    pub crash_loop_window: u64, // seconds
+    #[serde(
+        rename = "windowWidth",
+        alias = "window_width",
+        default = "default_window_width"
+    )]
+    pub window_width: f64,
+    #[serde(
+        rename = "windowHeight",
+        alias = "window_height",
+        default = "default_window_height"
+    )]
+    pub window_height: f64,
+    /// Docker versions below this "major.minor.patch" get a version warning;
+    /// `None` keeps the built-in 20.10.0
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "minDockerVersion",
+        alias = "min_docker_version"
+    )]
+    pub min_docker_version: Option<String>,
+    /// Podman versions below this "major.minor.patch" get a version warning;
+    /// `None` keeps the built-in 3.0.0
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "minPodmanVersion",
+        alias = "min_podman_version"
+    )]
+    pub min_podman_version: Option<String>,
+    /// Record removals, prunes and kills in the audit log
+    #[serde(default, rename = "auditLogEnabled", alias = "audit_log_enabled")]
+    pub audit_log_enabled: bool,
+    /// Save detection results to disk so cold starts can skip the scan
+    #[serde(
+        rename = "persistDetectionCache",
+        alias = "persist_detection_cache",
+        default = "default_persist_detection_cache"
+    )]
+    pub persist_detection_cache: bool,
+}
+
+/// Detection results are persisted unless the user opts out
+fn default_persist_detection_cache() -> bool {
+    true
+}
+
+/// Per-runtime probe timeout used when the preference is missing
+fn default_detection_timeout_ms() -> u64 {
+    500
+}
+
+/// Restarts within the crash-loop window that trigger `container-crash-looping`
+fn default_crash_loop_threshold() -> u32 {
+    DEFAULT_CRASH_LOOP_THRESHOLD
+}
+
+/// Crash-loop window in seconds
+fn default_crash_loop_window() -> u64 {
+    DEFAULT_CRASH_LOOP_WINDOW.as_secs()
+}
+
+/// Window width from tauri.conf.json, used until the window is resized
+fn default_window_width() -> f64 {
+    1200.0
+}
+
+/// Window height from tauri.conf.json
+fn default_window_height() -> f64 {
+    800.0
 }
 
 impl Default for RuntimePreferences {
@@ -110,6 +299,35 @@ impl Default for RuntimePreferences {
             preferred_type: Some(RuntimeType::Docker),
             detection_cache_ttl: 60,
             status_poll_interval: 5,
+            detection_timeout_ms: default_detection_timeout_ms(),
+            crash_loop_threshold: default_crash_loop_threshold(),
+            crash_loop_window: default_crash_loop_window(),
+            window_width: default_window_width(),
+            window_height: default_window_height(),
+            min_docker_version: None,
+            min_podman_version: None,
+            audit_log_enabled: false,
+            persist_detection_cache: default_persist_detection_cache(),
         }
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RuntimeCapabilities {
+    #[serde(rename = "runtimeId")]
+    pub runtime_id: String,
+    pub compose: bool,
+    pub buildx: bool,
+    /// Image vulnerability scanning via the `scout` plugin
+    pub scan: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum Feature {
+    Compose,
+    Buildx,
+    Pods,
+    Scan,
+    SystemDfJson,
+}
@@ -5,7 +5,7 @@ import { useRuntimeStore } from './stores/runtimeStore';
 import { useRuntimeStatus } from './hooks/useRuntimeStatus';
 import { WelcomeScreen } from './components/WelcomeScreen';
 import { RuntimeSelector } from './components/RuntimeSelector';
-import { formatRelativeTime } from './utils/formatters';
+import { formatRelativeTime, formatRuntimeName } from './utils/formatters';
 import type { DetectionResult } from './types/runtime';
 
 // Lazy load non-critical components for better initial load performance
@@ -242,7 +242,8 @@ function App() {
                       <div className="flex items-center justify-between">
                         <div>
                           <h3 className="text-xl font-semibold text-blue-400">
-                            {runtime.type === 'docker' ? '🐳 Docker' : '🦭 Podman'}
+                            {runtime.type === 'docker' ? '🐳' : runtime.type === 'podman' ? '🦭' : '📦'}{' '}
+                            {formatRuntimeName(runtime.type)}
                           </h3>
                           <p className="text-sm text-gray-400 mt-1">
                             Version: {runtime.version.full}
@@ -1,5 +1,6 @@
 import { useState } from 'react';
 import type { Runtime } from '../types/runtime';
+import { formatRuntimeName } from '../utils/formatters';
 
 /**
  * Props for the RuntimeError component
@@ -54,7 +55,7 @@ export function RuntimeError({ runtime, onRetry, onSwitchRuntime, hasAlternative
   };
 
   const getErrorDetails = () => {
-    const runtimeName = runtime.type === 'docker' ? 'Docker' : 'Podman';
+    const runtimeName = formatRuntimeName(runtime.type);
     
     // Default error message and troubleshooting
     const details = {
@@ -66,9 +67,11 @@ export function RuntimeError({ runtime, onRetry, onSwitchRuntime, hasAlternative
         `Verify ${runtimeName} is properly installed`,
         `Check system permissions`,
       ],
-      docsLink: runtime.type === 'docker' 
-        ? 'https://docs.docker.com/config/daemon/'
-        : 'https://podman.io/getting-started/troubleshooting',
+      docsLink: {
+        docker: 'https://docs.docker.com/config/daemon/',
+        podman: 'https://podman.io/getting-started/troubleshooting',
+        nerdctl: 'https://github.com/containerd/nerdctl#readme',
+      }[runtime.type],
     };
 
     // Customize based on runtime error field if available
@@ -68,32 +68,10 @@ export function RuntimeSelector() {
   const autoSelectRuntime = async () => {
     if (runtimes.length === 0) return;
 
-    // Load preferences to check for last selected runtime
-    const prefs = await invoke<{ selectedRuntimeId?: string; preferredType?: string }>(
-      'get_runtime_preferences'
-    );
-
-    let selected: Runtime | null = null;
-
-    // 1. Try to use last selected runtime if it still exists
-    if (prefs.selectedRuntimeId) {
-      selected = runtimes.find((r) => r.id === prefs.selectedRuntimeId) || null;
-    }
-
-    // 2. If no previous selection, prefer running runtime
-    if (!selected) {
-      selected = runtimes.find((r) => r.status === 'running') || null;
-    }
-
-    // 3. Prefer Docker over Podman if both available
-    if (!selected && prefs.preferredType) {
-      selected = runtimes.find((r) => r.type === prefs.preferredType) || null;
-    }
-
-    // 4. Fall back to first detected runtime
-    if (!selected && runtimes.length > 0) {
-      selected = runtimes[0] || null;
-    }
+    // Backend applies: last selection, running runtime (tie-broken by
+    // preferredType), preferredType, then first detected runtime
+    const choice = await invoke<Runtime | null>('get_auto_selected_runtime');
+    const selected = choice ? runtimes.find((r) => r.id === choice.id) || null : null;
 
     if (selected) {
       handleSelect(selected);
@@ -113,6 +91,7 @@ export function RuntimeSelector() {
   };
 
   const getRuntimeIcon = (type: string) => {
+    if (type === 'nerdctl') return '📦';
     return type === 'docker' ? '🐳' : '🦭';
   };
 
@@ -9,7 +9,7 @@
  */
 
 /** Container runtime type: Docker or Podman */
-export type RuntimeType = 'docker' | 'podman';
+export type RuntimeType = 'docker' | 'podman' | 'nerdctl';
 
 /** Current operational status of a runtime */
 export type RuntimeStatus = 'running' | 'stopped' | 'error' | 'unknown';
@@ -37,7 +37,7 @@ export interface Version {
 export interface Runtime {
   /** Unique identifier for this runtime instance */
   id: string;
-  /** Type of runtime (Docker or Podman) */
+  /** Type of runtime (Docker, Podman or nerdctl) */
   type: RuntimeType;
   /** Filesystem path to the runtime executable */
   path: string;
@@ -57,6 +57,12 @@ export interface Runtime {
   error?: string;
   /** True if version is below minimum requirements */
   versionWarning?: boolean;
+  /** Docker Desktop or bare Docker Engine, only for Docker */
+  edition?: 'desktop' | 'engine';
+  /** Docker daemon address (e.g. tcp://host:2376) when not the default socket */
+  host?: string;
+  /** Whether the podman machine VM is running, only for Podman on macOS/Windows */
+  machineRunning?: boolean;
 }
 
 /**
@@ -113,6 +119,24 @@ export interface RuntimePreferences {
   detectionCacheTTL: number;
   /** Status polling interval in seconds */
   statusPollInterval: number;
+  /** Per-runtime detection probe timeout in milliseconds */
+  detectionTimeoutMs: number;
+  /** Container restarts within the window that count as a crash loop */
+  crashLoopThreshold: number;
+  /** Crash-loop window in seconds */
+  crashLoopWindow: number;
+  /** Last main window width in logical pixels */
+  windowWidth: number;
+  /** Last main window height in logical pixels */
+  windowHeight: number;
+  /** Docker versions below this "major.minor.patch" warn (default 20.10.0) */
+  minDockerVersion?: string;
+  /** Podman versions below this "major.minor.patch" warn (default 3.0.0) */
+  minPodmanVersion?: string;
+  /** Record removals, prunes and kills in the audit log */
+  auditLogEnabled: boolean;
+  /** Save detection results so cold starts can skip the scan */
+  persistDetectionCache: boolean;
 }
 
 /**
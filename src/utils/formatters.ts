@@ -1,12 +1,28 @@
 import type { Runtime, RuntimeType, Version } from '../types/runtime';
 
+/**
+ * Display name for a runtime type
+ * Examples: "Docker", "Podman", "nerdctl"
+ */
+export function formatRuntimeName(type: RuntimeType): string {
+  switch (type) {
+    case 'docker':
+      return 'Docker';
+    case 'podman':
+      return 'Podman';
+    case 'nerdctl':
+      return 'nerdctl';
+  }
+}
+
 /**
  * Format runtime name with version
  * Examples: "Docker 24.0.7", "Podman 4.8.0"
  */
 export function formatRuntimeVersion(runtime: Runtime): string {
-  const name = runtime.type === 'docker' ? 'Docker' : 'Podman';
-  return `${name} ${runtime.version.full}`;
+  const name = formatRuntimeName(runtime.type);
+  const label = `${name} ${runtime.version.full}`;
+  return runtime.host ? `${label} @ ${runtime.host}` : label;
 }
 
 /**
@@ -28,6 +44,9 @@ export function isVersionSupported(runtime: Runtime): boolean {
   } else if (type === 'podman') {
     // Podman >= 3.0.0
     return version.major >= 3;
+  } else if (type === 'nerdctl') {
+    // nerdctl >= 1.0.0
+    return version.major >= 1;
   }
   
   return true;
@@ -37,7 +56,14 @@ export function isVersionSupported(runtime: Runtime): boolean {
  * Get minimum required version string for runtime type
  */
 export function getMinimumVersion(type: RuntimeType): string {
-  return type === 'docker' ? '20.10.0' : '3.0.0';
+  switch (type) {
+    case 'docker':
+      return '20.10.0';
+    case 'podman':
+      return '3.0.0';
+    case 'nerdctl':
+      return '1.0.0';
+  }
 }
 
 /**